@@ -1,5 +1,9 @@
 use anyhow::{Result, anyhow};
-use solvrascript::{parser::Parser, tokenizer::Tokenizer, vm::compiler};
+use solvrascript::{
+    parser::Parser,
+    tokenizer::Tokenizer,
+    vm::{bytecode::VmBytecode, compiler},
+};
 use std::{env, fs, path::Path};
 
 fn main() -> Result<()> {
@@ -16,8 +20,9 @@ fn main() -> Result<()> {
         .parse()
         .map_err(|err| anyhow!("parse error: {err}"))?;
     let bytecode = compiler::compile_program(&program)?;
+    let program = VmBytecode::decode(&bytecode[..])?;
     let out = Path::new(&src).with_extension("svc");
-    fs::write(&out, bytecode)?;
+    fs::write(&out, bincode::serialize(&program)?)?;
     println!("Compiled -> {}", out.display());
     Ok(())
 }
@@ -11,26 +11,19 @@
 // Added by Claude for Zobie.format compliance
 #![allow(dead_code)]
 
+pub mod visit;
+
+pub use visit::{Visitor, walk_program};
+
 use crate::symbol::Symbol;
 use crate::tokenizer::Position;
+pub use crate::tokenizer::Span;
 use std::collections::HashMap;
 use std::fmt;
 use std::sync::atomic::{AtomicU32, Ordering};
 
 pub type NodeId = u32;
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct Span {
-    pub start: Position,
-    pub end: Position,
-}
-
-impl Span {
-    pub fn new(start: Position, end: Position) -> Self {
-        Self { start, end }
-    }
-}
-
 static NODE_COUNTER: AtomicU32 = AtomicU32::new(1);
 
 pub fn next_node_id() -> NodeId {
@@ -158,104 +151,106 @@ pub enum Literal {
 pub enum Expr {
     Literal {
         value: Literal,
-        position: Position,
+        span: Span,
     },
     Identifier {
         name: Symbol,
-        position: Position,
+        span: Span,
         node_id: NodeId,
     },
     Binary {
         left: Box<Expr>,
         operator: BinaryOp,
         right: Box<Expr>,
-        position: Position,
+        span: Span,
     },
     Unary {
         operator: UnaryOp,
         operand: Box<Expr>,
-        position: Position,
+        span: Span,
     },
     Call {
         callee: Box<Expr>,
         args: Vec<Expr>,
-        position: Position,
+        span: Span,
     },
     MethodCall {
         receiver: Box<Expr>,
         method: Symbol,
         args: Vec<Expr>,
-        position: Position,
+        span: Span,
     },
     Index {
         object: Box<Expr>,
         index: Box<Expr>,
-        position: Position,
+        span: Span,
     },
     Member {
         object: Box<Expr>,
         property: Symbol,
-        position: Position,
+        span: Span,
         kind: MemberKind,
     },
     // Add string interpolation and template if referenced
     StringInterpolation {
         parts: Vec<StringPart>,
-        position: Position,
+        span: Span,
     },
     If {
         condition: Box<Expr>,
         then_expr: Box<Expr>,
         else_expr: Box<Expr>,
-        position: Position,
+        span: Span,
     },
     StringTemplate {
         parts: Vec<StringPart>,
-        position: Position,
+        span: Span,
     },
     Assign {
         target: AssignTarget,
         value: Box<Expr>,
-        position: Position,
+        span: Span,
+        node_id: NodeId,
     },
     Lambda {
         params: Vec<Symbol>,
+        param_spans: Vec<Span>,
         body: Box<Expr>,
-        position: Position,
+        span: Span,
     },
     Match {
         expr: Box<Expr>,
         arms: Vec<MatchArm>,
-        position: Position,
+        span: Span,
     },
     // @ZNOTE[SolvraCore Hook]: Async expressions will need special handling in bytecode
     Async {
         expr: Box<Expr>,
-        position: Position,
+        span: Span,
     },
     Await {
         expr: Box<Expr>,
-        position: Position,
+        span: Span,
     },
     Conditional {
         condition: Box<Expr>,
         then_expr: Box<Expr>,
         else_expr: Box<Expr>,
-        position: Position,
+        span: Span,
     },
     List {
         elements: Vec<Expr>,
-        position: Position,
+        span: Span,
     },
     Tuple {
         elements: Vec<Expr>,
-        position: Position,
+        span: Span,
     },
     Range {
         start: Option<Box<Expr>>,
         end: Option<Box<Expr>>,
         step: Option<Box<Expr>>,
-        position: Position,
+        span: Span,
     },
     Slice {
         object: Box<Expr>,
@@ -270,7 +265,7 @@ pub enum Expr {
         variable: Symbol,
         iterable: Box<Expr>,
         condition: Option<Box<Expr>>,
-        position: Position,
+        span: Span,
     },
 }
 
@@ -281,32 +276,39 @@ pub enum MemberKind {
 }
 
 impl Expr {
-    pub fn position(&self) -> &Position {
+    /// Source range covered by this expression, from its first character to
+    /// just past its last.
+    pub fn span(&self) -> &Span {
         match self {
-            Expr::Literal { position, .. } => position,
-            Expr::Identifier { position, .. } => position,
-            Expr::Binary { position, .. } => position,
-            Expr::Unary { position, .. } => position,
-            Expr::Call { position, .. } => position,
-            Expr::MethodCall { position, .. } => position,
-            Expr::Index { position, .. } => position,
-            Expr::Member { position, .. } => position,
-            Expr::StringInterpolation { position, .. } => position,
-            Expr::If { position, .. } => position,
-            Expr::StringTemplate { position, .. } => position,
-            Expr::Assign { position, .. } => position,
-            Expr::Lambda { position, .. } => position,
-            Expr::Match { position, .. } => position,
-            Expr::Async { position, .. } => position,
-            Expr::Await { position, .. } => position,
-            Expr::Conditional { position, .. } => position,
-            Expr::List { position, .. } => position,
-            Expr::Tuple { position, .. } => position,
-            Expr::Range { position, .. } => position,
-            Expr::Comprehension { position, .. } => position,
-            Expr::Slice { span, .. } => &span.start,
+            Expr::Literal { span, .. } => span,
+            Expr::Identifier { span, .. } => span,
+            Expr::Binary { span, .. } => span,
+            Expr::Unary { span, .. } => span,
+            Expr::Call { span, .. } => span,
+            Expr::MethodCall { span, .. } => span,
+            Expr::Index { span, .. } => span,
+            Expr::Member { span, .. } => span,
+            Expr::StringInterpolation { span, .. } => span,
+            Expr::If { span, .. } => span,
+            Expr::StringTemplate { span, .. } => span,
+            Expr::Assign { span, .. } => span,
+            Expr::Lambda { span, .. } => span,
+            Expr::Match { span, .. } => span,
+            Expr::Async { span, .. } => span,
+            Expr::Await { span, .. } => span,
+            Expr::Conditional { span, .. } => span,
+            Expr::List { span, .. } => span,
+            Expr::Tuple { span, .. } => span,
+            Expr::Range { span, .. } => span,
+            Expr::Slice { span, .. } => span,
+            Expr::Comprehension { span, .. } => span,
         }
     }
+
+    /// Start of this expression; shorthand for `self.span().start`.
+    pub fn position(&self) -> &Position {
+        &self.span().start
+    }
 }
 
 /// Parts of a string interpolation
@@ -379,7 +381,7 @@ pub struct VariableDecl {
     pub binding: BindingKind,
     pub is_mutable: bool,
     pub initializer: Option<Expr>,
-    pub position: Position,
+    pub span: Span,
     pub node_id: NodeId,
 }
 
@@ -393,7 +395,7 @@ pub struct FunctionDecl {
     pub body: Vec<Stmt>,
     pub is_async: bool,
     pub visibility: Visibility,
-    pub position: Position,
+    pub span: Span,
     pub node_id: NodeId,
 }
 
@@ -405,7 +407,7 @@ pub struct ClassDecl {
     pub methods: Vec<FunctionDecl>,
     pub fields: Vec<VariableDecl>,
     pub visibility: Visibility,
-    pub position: Position,
+    pub span: Span,
 }
 
 /// Interface declarations
@@ -414,7 +416,7 @@ pub struct InterfaceDecl {
     pub name: Symbol,
     pub methods: Vec<FunctionSignature>,
     pub superinterfaces: Vec<Symbol>,
-    pub position: Position,
+    pub span: Span,
 }
 
 /// Function signatures (for interfaces)
@@ -455,14 +457,14 @@ pub struct ImportDecl {
     pub source: ImportSource,
     pub items: Vec<String>, // Empty for wildcard imports
     pub alias: Option<String>,
-    pub position: Position,
+    pub span: Span,
 }
 
 /// Export declarations
 #[derive(Debug, Clone, PartialEq)]
 pub struct ExportDecl {
     pub item: ExportItem,
-    pub position: Position,
+    pub span: Span,
 }
 
 /// Items that can be exported
@@ -487,12 +489,22 @@ pub enum Visibility {
     Internal,
 }
 
+/// Expected direction of an `if`, written `@likely` or `@unlikely` before
+/// it. Only code layout uses it; evaluation is unaffected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BranchHint {
+    /// The then branch is the hot path.
+    Likely,
+    /// The then branch is rarely taken.
+    Unlikely,
+}
+
 /// Statements in SolvraScript
 #[derive(Debug, Clone, PartialEq)]
 pub enum Stmt {
     Expression {
         expr: Expr,
-        position: Position,
+        span: Span,
     },
     VariableDecl {
         decl: VariableDecl,
@@ -514,131 +526,139 @@ pub enum Stmt {
     },
     Block {
         statements: Vec<Stmt>,
-        position: Position,
+        span: Span,
     },
     If {
         condition: Expr,
         then_branch: Box<Stmt>,
         else_branch: Option<Box<Stmt>>,
-        position: Position,
+        hint: Option<BranchHint>,
+        span: Span,
     },
     While {
         condition: Expr,
         body: Box<Stmt>,
-        position: Position,
+        span: Span,
     },
     For {
         variable: Symbol,
+        variable_span: Span,
         iterable: Expr,
         body: Box<Stmt>,
-        position: Position,
+        span: Span,
         node_id: NodeId,
     },
     ForIn {
         variable: Symbol,
         iterable: Expr,
         body: Box<Stmt>,
-        position: Position,
+        span: Span,
     },
     ForOf {
         variable: Symbol,
         iterable: Expr,
         body: Box<Stmt>,
-        position: Position,
+        span: Span,
     },
     Loop {
         body: Box<Stmt>,
-        position: Position,
+        span: Span,
     },
     Return {
         value: Option<Expr>,
-        position: Position,
+        span: Span,
     },
     Break {
         label: Option<Symbol>,
-        position: Position,
+        span: Span,
     },
     Continue {
         label: Option<Symbol>,
-        position: Position,
+        span: Span,
     },
     Try {
         try_block: Box<Stmt>,
         catch_blocks: Vec<CatchBlock>,
         finally_block: Option<Box<Stmt>>,
-        position: Position,
+        span: Span,
     },
     Throw {
         expr: Expr,
-        position: Position,
+        span: Span,
     },
     // @ZNOTE[SolvraStdLib Hook]: Panic statements will integrate with SolvraOS error handling
     Panic {
         message: Option<Expr>,
-        position: Position,
+        span: Span,
     },
     Defer {
         stmt: Box<Stmt>,
-        position: Position,
+        span: Span,
     },
     Match {
         expr: Expr,
         arms: Vec<MatchArm>,
-        position: Position,
+        span: Span,
     },
     With {
         expr: Expr,
         body: Box<Stmt>,
-        position: Position,
+        span: Span,
     },
     Switch {
         expr: Expr,
         cases: Vec<SwitchCase>,
         default_case: Option<Box<Stmt>>,
-        position: Position,
+        span: Span,
     },
     Label {
         name: Symbol,
         stmt: Box<Stmt>,
-        position: Position,
+        span: Span,
     },
     Goto {
         label: Symbol,
-        position: Position,
+        span: Span,
     },
 }
 
 impl Stmt {
-    pub fn position(&self) -> &Position {
+    /// Source range covered by this statement, including any trailing `;`.
+    pub fn span(&self) -> &Span {
         match self {
-            Stmt::Expression { position, .. } => position,
-            Stmt::VariableDecl { decl } => &decl.position,
-            Stmt::FunctionDecl { decl } => &decl.position,
-            Stmt::ClassDecl { decl } => &decl.position,
-            Stmt::InterfaceDecl { decl } => &decl.position,
-            Stmt::ImportDecl { decl } => &decl.position,
-            Stmt::ExportDecl { decl } => &decl.position,
-            Stmt::Block { position, .. } => position,
-            Stmt::If { position, .. } => position,
-            Stmt::While { position, .. } => position,
-            Stmt::For { position, .. } => position,
-            Stmt::ForIn { position, .. } => position,
-            Stmt::ForOf { position, .. } => position,
-            Stmt::Loop { position, .. } => position,
-            Stmt::Return { position, .. } => position,
-            Stmt::Break { position, .. } => position,
-            Stmt::Continue { position, .. } => position,
-            Stmt::Try { position, .. } => position,
-            Stmt::Throw { position, .. } => position,
-            Stmt::Panic { position, .. } => position,
-            Stmt::Defer { position, .. } => position,
-            Stmt::Match { position, .. } => position,
-            Stmt::With { position, .. } => position,
-            Stmt::Switch { position, .. } => position,
-            Stmt::Label { position, .. } => position,
-            Stmt::Goto { position, .. } => position,
+            Stmt::Expression { span, .. } => span,
+            Stmt::VariableDecl { decl } => &decl.span,
+            Stmt::FunctionDecl { decl } => &decl.span,
+            Stmt::ClassDecl { decl } => &decl.span,
+            Stmt::InterfaceDecl { decl } => &decl.span,
+            Stmt::ImportDecl { decl } => &decl.span,
+            Stmt::ExportDecl { decl } => &decl.span,
+            Stmt::Block { span, .. } => span,
+            Stmt::If { span, .. } => span,
+            Stmt::While { span, .. } => span,
+            Stmt::For { span, .. } => span,
+            Stmt::ForIn { span, .. } => span,
+            Stmt::ForOf { span, .. } => span,
+            Stmt::Loop { span, .. } => span,
+            Stmt::Return { span, .. } => span,
+            Stmt::Break { span, .. } => span,
+            Stmt::Continue { span, .. } => span,
+            Stmt::Try { span, .. } => span,
+            Stmt::Throw { span, .. } => span,
+            Stmt::Panic { span, .. } => span,
+            Stmt::Defer { span, .. } => span,
+            Stmt::Match { span, .. } => span,
+            Stmt::With { span, .. } => span,
+            Stmt::Switch { span, .. } => span,
+            Stmt::Label { span, .. } => span,
+            Stmt::Goto { span, .. } => span,
         }
     }
+
+    /// Start of this statement; shorthand for `self.span().start`.
+    pub fn position(&self) -> &Position {
+        &self.span().start
+    }
 }
 
 /// Switch case
@@ -871,9 +891,9 @@ impl Program {
         // script semantics for callers that expect the last expression to be the result.
         if let Some(last_stmt) = body.pop() {
             match last_stmt {
-                Stmt::Expression { expr, position } => body.push(Stmt::Return {
+                Stmt::Expression { expr, span } => body.push(Stmt::Return {
                     value: Some(expr),
-                    position,
+                    span,
                 }),
                 other => body.push(other),
             }
@@ -887,7 +907,7 @@ impl Program {
             body,
             is_async: false,
             visibility: Visibility::Private,
-            position: self.position.clone(),
+            span: Span::point(self.position.clone()),
             node_id: next_node_id(),
         };
         carryover.push(Stmt::FunctionDecl {
@@ -897,77 +917,113 @@ impl Program {
         self.implicit_entry = true;
         true
     }
+
+    /// The `main` that `ensure_entry_point` synthesized, if any.
+    pub fn implicit_entry_decl(&self) -> Option<&FunctionDecl> {
+        if !self.implicit_entry {
+            return None;
+        }
+        self.statements.iter().rev().find_map(|stmt| match stmt {
+            Stmt::FunctionDecl { decl } if decl.name.as_str() == "main" => Some(decl),
+            _ => None,
+        })
+    }
+
+    /// Undo `ensure_entry_point`, moving the synthesized `main`'s body back
+    /// to the top level after the declarations, so its bindings become
+    /// globals again. Returns whether there was an implicit entry to undo.
+    pub fn lift_implicit_entry(&mut self) -> bool {
+        if !self.implicit_entry {
+            return false;
+        }
+        let Some(index) = self.statements.iter().rposition(
+            |stmt| matches!(stmt, Stmt::FunctionDecl { decl } if decl.name.as_str() == "main"),
+        ) else {
+            return false;
+        };
+        if let Stmt::FunctionDecl { decl } = self.statements.remove(index) {
+            self.statements.extend(decl.body);
+        }
+        self.implicit_entry = false;
+        true
+    }
 }
 
 /// Helper functions for creating AST nodes
 impl Expr {
-    pub fn literal(value: Literal, position: Position) -> Self {
-        Expr::Literal { value, position }
+    pub fn literal(value: Literal, span: impl Into<Span>) -> Self {
+        Expr::Literal {
+            value,
+            span: span.into(),
+        }
     }
 
-    pub fn identifier(name: Symbol, position: Position) -> Self {
+    pub fn identifier(name: Symbol, span: impl Into<Span>) -> Self {
         Expr::Identifier {
             name,
-            position,
+            span: span.into(),
             node_id: next_node_id(),
         }
     }
 
-    pub fn binary(left: Expr, operator: BinaryOp, right: Expr, position: Position) -> Self {
+    pub fn binary(left: Expr, operator: BinaryOp, right: Expr, span: impl Into<Span>) -> Self {
         Expr::Binary {
             left: Box::new(left),
             operator,
             right: Box::new(right),
-            position,
+            span: span.into(),
         }
     }
 
-    pub fn unary(operator: UnaryOp, operand: Expr, position: Position) -> Self {
+    pub fn unary(operator: UnaryOp, operand: Expr, span: impl Into<Span>) -> Self {
         Expr::Unary {
             operator,
             operand: Box::new(operand),
-            position,
+            span: span.into(),
         }
     }
 
-    pub fn call(callee: Expr, args: Vec<Expr>, position: Position) -> Self {
+    pub fn call(callee: Expr, args: Vec<Expr>, span: impl Into<Span>) -> Self {
         Expr::Call {
             callee: Box::new(callee),
             args,
-            position,
+            span: span.into(),
         }
     }
 
-    pub fn index(object: Expr, index: Expr, position: Position) -> Self {
+    pub fn index(object: Expr, index: Expr, span: impl Into<Span>) -> Self {
         Expr::Index {
             object: Box::new(object),
             index: Box::new(index),
-            position,
+            span: span.into(),
         }
     }
 
-    pub fn member(object: Expr, property: Symbol, position: Position, kind: MemberKind) -> Self {
+    pub fn member(object: Expr, property: Symbol, span: impl Into<Span>, kind: MemberKind) -> Self {
         Expr::Member {
             object: Box::new(object),
             property,
-            position,
+            span: span.into(),
             kind,
         }
     }
 
-    pub fn assignment(target: AssignTarget, value: Expr, position: Position) -> Self {
+    pub fn assignment(target: AssignTarget, value: Expr, span: impl Into<Span>) -> Self {
         Expr::Assign {
             target,
             value: Box::new(value),
-            position,
+            span: span.into(),
+            node_id: next_node_id(),
         }
     }
 
-    pub fn lambda(params: Vec<Symbol>, body: Expr, position: Position) -> Self {
+    pub fn lambda(params: Vec<Symbol>, body: Expr, span: impl Into<Span>) -> Self {
+        let span = span.into();
         Expr::Lambda {
+            param_spans: vec![span.clone(); params.len()],
             params,
             body: Box::new(body),
-            position,
+            span,
         }
     }
 
@@ -975,42 +1031,51 @@ impl Expr {
         condition: Expr,
         then_expr: Expr,
         else_expr: Expr,
-        position: Position,
+        span: impl Into<Span>,
     ) -> Self {
         Expr::Conditional {
             condition: Box::new(condition),
             then_expr: Box::new(then_expr),
             else_expr: Box::new(else_expr),
-            position,
+            span: span.into(),
         }
     }
 
-    pub fn list(elements: Vec<Expr>, position: Position) -> Self {
-        Expr::List { elements, position }
+    pub fn list(elements: Vec<Expr>, span: impl Into<Span>) -> Self {
+        Expr::List {
+            elements,
+            span: span.into(),
+        }
     }
 
-    pub fn tuple(elements: Vec<Expr>, position: Position) -> Self {
-        Expr::Tuple { elements, position }
+    pub fn tuple(elements: Vec<Expr>, span: impl Into<Span>) -> Self {
+        Expr::Tuple {
+            elements,
+            span: span.into(),
+        }
     }
 
     pub fn range(
         start: Option<Expr>,
         end: Option<Expr>,
         step: Option<Expr>,
-        position: Position,
+        span: impl Into<Span>,
     ) -> Self {
         Expr::Range {
             start: start.map(Box::new),
             end: end.map(Box::new),
             step: step.map(Box::new),
-            position,
+            span: span.into(),
         }
     }
 }
 
 impl Stmt {
-    pub fn expression(expr: Expr, position: Position) -> Self {
-        Stmt::Expression { expr, position }
+    pub fn expression(expr: Expr, span: impl Into<Span>) -> Self {
+        Stmt::Expression {
+            expr,
+            span: span.into(),
+        }
     }
 
     pub fn variable_decl(decl: VariableDecl) -> Self {
@@ -1041,87 +1106,105 @@ impl Stmt {
         condition: Expr,
         then_branch: Stmt,
         else_branch: Option<Stmt>,
-        position: Position,
+        span: impl Into<Span>,
     ) -> Self {
         Stmt::If {
             condition,
             then_branch: Box::new(then_branch),
             else_branch: else_branch.map(Box::new),
-            position,
+            hint: None,
+            span: span.into(),
         }
     }
 
-    pub fn while_stmt(condition: Expr, body: Stmt, position: Position) -> Self {
+    pub fn while_stmt(condition: Expr, body: Stmt, span: impl Into<Span>) -> Self {
         Stmt::While {
             condition,
             body: Box::new(body),
-            position,
+            span: span.into(),
         }
     }
 
-    pub fn for_stmt(variable: Symbol, iterable: Expr, body: Stmt, position: Position) -> Self {
+    pub fn for_stmt(variable: Symbol, iterable: Expr, body: Stmt, span: impl Into<Span>) -> Self {
+        let span = span.into();
         Stmt::For {
             variable,
+            variable_span: span.clone(),
             iterable,
             body: Box::new(body),
-            position,
+            span,
             node_id: next_node_id(),
         }
     }
 
-    pub fn block(statements: Vec<Stmt>, position: Position) -> Self {
+    pub fn block(statements: Vec<Stmt>, span: impl Into<Span>) -> Self {
         Stmt::Block {
             statements,
-            position,
+            span: span.into(),
         }
     }
 
-    pub fn return_stmt(value: Option<Expr>, position: Position) -> Self {
-        Stmt::Return { value, position }
+    pub fn return_stmt(value: Option<Expr>, span: impl Into<Span>) -> Self {
+        Stmt::Return {
+            value,
+            span: span.into(),
+        }
     }
 
-    pub fn break_stmt(label: Option<Symbol>, position: Position) -> Self {
-        Stmt::Break { label, position }
+    pub fn break_stmt(label: Option<Symbol>, span: impl Into<Span>) -> Self {
+        Stmt::Break {
+            label,
+            span: span.into(),
+        }
     }
 
-    pub fn continue_stmt(label: Option<Symbol>, position: Position) -> Self {
-        Stmt::Continue { label, position }
+    pub fn continue_stmt(label: Option<Symbol>, span: impl Into<Span>) -> Self {
+        Stmt::Continue {
+            label,
+            span: span.into(),
+        }
     }
 
     pub fn try_stmt(
         try_block: Stmt,
         catch_blocks: Vec<CatchBlock>,
         finally_block: Option<Stmt>,
-        position: Position,
+        span: impl Into<Span>,
     ) -> Self {
         Stmt::Try {
             try_block: Box::new(try_block),
             catch_blocks,
             finally_block: finally_block.map(Box::new),
-            position,
+            span: span.into(),
         }
     }
 
-    pub fn throw_stmt(expr: Expr, position: Position) -> Self {
-        Stmt::Throw { expr, position }
+    pub fn throw_stmt(expr: Expr, span: impl Into<Span>) -> Self {
+        Stmt::Throw {
+            expr,
+            span: span.into(),
+        }
     }
 
-    pub fn panic_stmt(message: Option<Expr>, position: Position) -> Self {
-        Stmt::Panic { message, position }
+    pub fn panic_stmt(message: Option<Expr>, span: impl Into<Span>) -> Self {
+        Stmt::Panic {
+            message,
+            span: span.into(),
+        }
     }
 
-    pub fn defer_stmt(stmt: Stmt, position: Position) -> Self {
+    pub fn defer_stmt(stmt: Stmt, span: impl Into<Span>) -> Self {
         Stmt::Defer {
             stmt: Box::new(stmt),
-            position,
+            span: span.into(),
         }
     }
 
-    pub fn match_stmt(expr: Expr, arms: Vec<MatchArm>, position: Position) -> Self {
+    pub fn match_stmt(expr: Expr, arms: Vec<MatchArm>, span: impl Into<Span>) -> Self {
         Stmt::Match {
             expr,
             arms,
-            position,
+            span: span.into(),
         }
     }
 
@@ -1129,31 +1212,34 @@ impl Stmt {
         expr: Expr,
         cases: Vec<SwitchCase>,
         default_case: Option<Stmt>,
-        position: Position,
+        span: impl Into<Span>,
     ) -> Self {
         Stmt::Switch {
             expr,
             cases,
             default_case: default_case.map(Box::new),
-            position,
+            span: span.into(),
         }
     }
 
-    pub fn label_stmt(name: Symbol, stmt: Stmt, position: Position) -> Self {
+    pub fn label_stmt(name: Symbol, stmt: Stmt, span: impl Into<Span>) -> Self {
         Stmt::Label {
             name,
             stmt: Box::new(stmt),
-            position,
+            span: span.into(),
         }
     }
 
-    pub fn goto_stmt(label: Symbol, position: Position) -> Self {
-        Stmt::Goto { label, position }
+    pub fn goto_stmt(label: Symbol, span: impl Into<Span>) -> Self {
+        Stmt::Goto {
+            label,
+            span: span.into(),
+        }
     }
 }
 
 /// @ZNOTE[SolvraCore Integration]: This AST structure is designed to be easily serialized
-/// for SolvraCore bytecode compilation. Each node contains position information for
+/// for SolvraCore bytecode compilation. Each node carries a source span for
 /// debugging and error reporting in the compiled bytecode.
 ///
 /// @ZNOTE[SolvraStdLib Hook]: Expression evaluation will need to interface with SolvraStdLib
@@ -1236,7 +1322,7 @@ mod tests {
             body: vec![],
             is_async: false,
             visibility: Visibility::Private,
-            position: pos.clone(),
+            span: Span::point(pos.clone()),
             node_id: next_node_id(),
         };
         assert_eq!(func_decl.name, Symbol::from("test_func"));
@@ -1256,7 +1342,7 @@ mod tests {
             binding: BindingKind::Let,
             is_mutable: false,
             initializer: Some(Expr::literal(Literal::Integer(42), pos.clone())),
-            position: pos.clone(),
+            span: Span::point(pos.clone()),
             node_id: next_node_id(),
         };
         assert_eq!(var_decl.name, Symbol::from("x"));
@@ -1276,7 +1362,7 @@ mod tests {
             methods: vec![],
             fields: vec![],
             visibility: Visibility::Public,
-            position: pos.clone(),
+            span: Span::point(pos.clone()),
         };
         assert_eq!(class_decl.name, Symbol::from("TestClass"));
         assert!(class_decl.superclass.is_none());
@@ -1361,7 +1447,7 @@ mod tests {
             body: vec![],
             is_async: false,
             visibility: Visibility::Private,
-            position: pos.clone(),
+            span: Span::point(pos.clone()),
             node_id: next_node_id(),
         };
         let var_decl = VariableDecl {
@@ -1371,7 +1457,7 @@ mod tests {
             binding: BindingKind::Let,
             is_mutable: false,
             initializer: None,
-            position: pos.clone(),
+            span: Span::point(pos.clone()),
             node_id: next_node_id(),
         };
         let program = Program::new(
@@ -1568,6 +1654,33 @@ mod tests {
         );
         assert!(matches!(for_stmt, Stmt::For { .. }));
     }
+
+    #[test]
+    fn test_lift_implicit_entry() {
+        let pos = Position::new(1, 1, 0);
+        let helper = Stmt::FunctionDecl {
+            decl: FunctionDecl::new(
+                crate::symbol::intern_symbol("helper"),
+                Type::Inferred,
+                pos.clone(),
+            ),
+        };
+        let binding = Stmt::VariableDecl {
+            decl: VariableDecl::new(
+                crate::symbol::intern_symbol("count"),
+                Type::Inferred,
+                BindingKind::Let,
+                pos.clone(),
+            ),
+        };
+        let mut program = Program::new(vec![binding.clone(), helper.clone()], pos);
+        assert!(program.ensure_entry_point());
+
+        assert!(program.lift_implicit_entry());
+        assert!(!program.implicit_entry);
+        assert_eq!(program.statements, vec![helper, binding]);
+        assert!(!program.lift_implicit_entry());
+    }
 }
 
 /// Additional utility implementations
@@ -1589,7 +1702,7 @@ impl Parameter {
 }
 
 impl VariableDecl {
-    pub fn new(name: Symbol, var_type: Type, binding: BindingKind, position: Position) -> Self {
+    pub fn new(name: Symbol, var_type: Type, binding: BindingKind, span: impl Into<Span>) -> Self {
         Self {
             name,
             var_type,
@@ -1597,7 +1710,7 @@ impl VariableDecl {
             binding,
             is_mutable: false,
             initializer: None,
-            position,
+            span: span.into(),
             node_id: next_node_id(),
         }
     }
@@ -1614,7 +1727,7 @@ impl VariableDecl {
 }
 
 impl FunctionDecl {
-    pub fn new(name: Symbol, return_type: Type, position: Position) -> Self {
+    pub fn new(name: Symbol, return_type: Type, span: impl Into<Span>) -> Self {
         Self {
             name,
             params: Vec::new(),
@@ -1623,7 +1736,7 @@ impl FunctionDecl {
             body: Vec::new(),
             is_async: false,
             visibility: Visibility::Private,
-            position,
+            span: span.into(),
             node_id: next_node_id(),
         }
     }
@@ -1645,14 +1758,14 @@ impl FunctionDecl {
 }
 
 impl ClassDecl {
-    pub fn new(name: Symbol, position: Position) -> Self {
+    pub fn new(name: Symbol, span: impl Into<Span>) -> Self {
         Self {
             name,
             superclass: None,
             methods: vec![],
             fields: vec![],
             visibility: Visibility::Private,
-            position,
+            span: span.into(),
         }
     }
 
@@ -1678,12 +1791,12 @@ impl ClassDecl {
 }
 
 impl InterfaceDecl {
-    pub fn new(name: Symbol, position: Position) -> Self {
+    pub fn new(name: Symbol, span: impl Into<Span>) -> Self {
         Self {
             name,
             methods: vec![],
             superinterfaces: vec![],
-            position,
+            span: span.into(),
         }
     }
 
@@ -1699,12 +1812,12 @@ impl InterfaceDecl {
 }
 
 impl ImportDecl {
-    pub fn new(source: ImportSource, position: Position) -> Self {
+    pub fn new(source: ImportSource, span: impl Into<Span>) -> Self {
         Self {
             source,
             items: vec![],
             alias: None,
-            position,
+            span: span.into(),
         }
     }
 
@@ -1720,8 +1833,11 @@ impl ImportDecl {
 }
 
 impl ExportDecl {
-    pub fn new(item: ExportItem, position: Position) -> Self {
-        Self { item, position }
+    pub fn new(item: ExportItem, span: impl Into<Span>) -> Self {
+        Self {
+            item,
+            span: span.into(),
+        }
     }
 }
 
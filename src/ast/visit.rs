@@ -0,0 +1,445 @@
+//=====================================================
+// File: ast/visit.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Reusable read-only traversal over the SolvraScript AST
+// Objective: Let analyses override only the nodes they care about while the
+//            default methods walk every child in source order
+//=====================================================
+
+use super::{
+    AssignTarget, ClassDecl, ExportItem, Expr, FunctionDecl, InterfaceDecl, Literal, MatchArm,
+    Pattern, Program, Stmt, StringPart, VariableDecl,
+};
+
+/// Read-only AST visitor over nodes borrowed for `'ast`, so a visitor can
+/// keep references to the nodes it finds.
+///
+/// Every method defaults to the matching `walk_*` function, which visits the
+/// node's children. Override a method to inspect a node, and call the `walk_*`
+/// function from the override to keep descending into its children.
+pub trait Visitor<'ast> {
+    fn visit_program(&mut self, program: &'ast Program) {
+        walk_program(self, program);
+    }
+
+    fn visit_stmt(&mut self, stmt: &'ast Stmt) {
+        walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        walk_expr(self, expr);
+    }
+
+    fn visit_function_decl(&mut self, decl: &'ast FunctionDecl) {
+        walk_function_decl(self, decl);
+    }
+
+    fn visit_variable_decl(&mut self, decl: &'ast VariableDecl) {
+        walk_variable_decl(self, decl);
+    }
+
+    fn visit_match_arm(&mut self, arm: &'ast MatchArm) {
+        walk_match_arm(self, arm);
+    }
+
+    fn visit_pattern(&mut self, pattern: &'ast Pattern) {
+        walk_pattern(self, pattern);
+    }
+}
+
+/// Visit every top-level statement of `program`.
+pub fn walk_program<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, program: &'ast Program) {
+    for stmt in &program.statements {
+        visitor.visit_stmt(stmt);
+    }
+}
+
+pub fn walk_stmt<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, stmt: &'ast Stmt) {
+    match stmt {
+        Stmt::Expression { expr, .. } | Stmt::Throw { expr, .. } => visitor.visit_expr(expr),
+        Stmt::VariableDecl { decl } => visitor.visit_variable_decl(decl),
+        Stmt::FunctionDecl { decl } => visitor.visit_function_decl(decl),
+        Stmt::ClassDecl { decl } => walk_class_decl(visitor, decl),
+        Stmt::InterfaceDecl { decl } => walk_interface_decl(visitor, decl),
+        Stmt::ImportDecl { .. } => {}
+        Stmt::ExportDecl { decl } => match &decl.item {
+            ExportItem::Function(function) => visitor.visit_function_decl(function),
+            ExportItem::Variable(variable) => visitor.visit_variable_decl(variable),
+            ExportItem::Class(class) => walk_class_decl(visitor, class),
+            ExportItem::Interface(interface) => walk_interface_decl(visitor, interface),
+            ExportItem::Type(_) | ExportItem::Module(_) | ExportItem::Symbol { .. } => {}
+        },
+        Stmt::Block { statements, .. } => {
+            for stmt in statements {
+                visitor.visit_stmt(stmt);
+            }
+        }
+        Stmt::If {
+            condition,
+            then_branch,
+            else_branch,
+            ..
+        } => {
+            visitor.visit_expr(condition);
+            visitor.visit_stmt(then_branch);
+            if let Some(else_branch) = else_branch {
+                visitor.visit_stmt(else_branch);
+            }
+        }
+        Stmt::While {
+            condition, body, ..
+        } => {
+            visitor.visit_expr(condition);
+            visitor.visit_stmt(body);
+        }
+        Stmt::For { iterable, body, .. }
+        | Stmt::ForIn { iterable, body, .. }
+        | Stmt::ForOf { iterable, body, .. } => {
+            visitor.visit_expr(iterable);
+            visitor.visit_stmt(body);
+        }
+        Stmt::Loop { body, .. } => visitor.visit_stmt(body),
+        Stmt::Return { value, .. } => {
+            if let Some(value) = value {
+                visitor.visit_expr(value);
+            }
+        }
+        Stmt::Break { .. } | Stmt::Continue { .. } | Stmt::Goto { .. } => {}
+        Stmt::Try {
+            try_block,
+            catch_blocks,
+            finally_block,
+            ..
+        } => {
+            visitor.visit_stmt(try_block);
+            for catch in catch_blocks {
+                visitor.visit_stmt(&catch.body);
+            }
+            if let Some(finally_block) = finally_block {
+                visitor.visit_stmt(finally_block);
+            }
+        }
+        Stmt::Panic { message, .. } => {
+            if let Some(message) = message {
+                visitor.visit_expr(message);
+            }
+        }
+        Stmt::Defer { stmt, .. } | Stmt::Label { stmt, .. } => visitor.visit_stmt(stmt),
+        Stmt::Match { expr, arms, .. } => {
+            visitor.visit_expr(expr);
+            for arm in arms {
+                visitor.visit_match_arm(arm);
+            }
+        }
+        Stmt::With { expr, body, .. } => {
+            visitor.visit_expr(expr);
+            visitor.visit_stmt(body);
+        }
+        Stmt::Switch {
+            expr,
+            cases,
+            default_case,
+            ..
+        } => {
+            visitor.visit_expr(expr);
+            for case in cases {
+                for value in &case.values {
+                    visitor.visit_expr(value);
+                }
+                for stmt in &case.body {
+                    visitor.visit_stmt(stmt);
+                }
+            }
+            if let Some(default_case) = default_case {
+                visitor.visit_stmt(default_case);
+            }
+        }
+    }
+}
+
+pub fn walk_expr<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, expr: &'ast Expr) {
+    match expr {
+        Expr::Literal { value, .. } => walk_literal(visitor, value),
+        Expr::Identifier { .. } => {}
+        Expr::Binary { left, right, .. } => {
+            visitor.visit_expr(left);
+            visitor.visit_expr(right);
+        }
+        Expr::Unary { operand, .. } => visitor.visit_expr(operand),
+        Expr::Call { callee, args, .. } => {
+            visitor.visit_expr(callee);
+            for arg in args {
+                visitor.visit_expr(arg);
+            }
+        }
+        Expr::MethodCall { receiver, args, .. } => {
+            visitor.visit_expr(receiver);
+            for arg in args {
+                visitor.visit_expr(arg);
+            }
+        }
+        Expr::Index { object, index, .. } => {
+            visitor.visit_expr(object);
+            visitor.visit_expr(index);
+        }
+        Expr::Member { object, .. } => visitor.visit_expr(object),
+        Expr::StringInterpolation { parts, .. } | Expr::StringTemplate { parts, .. } => {
+            for part in parts {
+                if let StringPart::Expression(expr) = part {
+                    visitor.visit_expr(expr);
+                }
+            }
+        }
+        Expr::If {
+            condition,
+            then_expr,
+            else_expr,
+            ..
+        }
+        | Expr::Conditional {
+            condition,
+            then_expr,
+            else_expr,
+            ..
+        } => {
+            visitor.visit_expr(condition);
+            visitor.visit_expr(then_expr);
+            visitor.visit_expr(else_expr);
+        }
+        Expr::Assign { target, value, .. } => {
+            match target {
+                AssignTarget::Variable(_) => {}
+                AssignTarget::Index { array, index } => {
+                    visitor.visit_expr(array);
+                    visitor.visit_expr(index);
+                }
+                AssignTarget::Member { object, .. } => visitor.visit_expr(object),
+            }
+            visitor.visit_expr(value);
+        }
+        Expr::Lambda { body, .. } => visitor.visit_expr(body),
+        Expr::Match { expr, arms, .. } => {
+            visitor.visit_expr(expr);
+            for arm in arms {
+                visitor.visit_match_arm(arm);
+            }
+        }
+        Expr::Async { expr, .. } | Expr::Await { expr, .. } => visitor.visit_expr(expr),
+        Expr::List { elements, .. } | Expr::Tuple { elements, .. } => {
+            for element in elements {
+                visitor.visit_expr(element);
+            }
+        }
+        Expr::Range {
+            start, end, step, ..
+        } => {
+            for bound in [start, end, step].into_iter().flatten() {
+                visitor.visit_expr(bound);
+            }
+        }
+        Expr::Slice {
+            object,
+            start,
+            end,
+            step,
+            ..
+        } => {
+            visitor.visit_expr(object);
+            for bound in [start, end, step].into_iter().flatten() {
+                visitor.visit_expr(bound);
+            }
+        }
+        Expr::Comprehension {
+            element,
+            iterable,
+            condition,
+            ..
+        } => {
+            visitor.visit_expr(iterable);
+            if let Some(condition) = condition {
+                visitor.visit_expr(condition);
+            }
+            visitor.visit_expr(element);
+        }
+    }
+}
+
+pub fn walk_function_decl<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    decl: &'ast FunctionDecl,
+) {
+    for param in &decl.params {
+        if let Some(default_value) = &param.default_value {
+            visitor.visit_expr(default_value);
+        }
+    }
+    for stmt in &decl.body {
+        visitor.visit_stmt(stmt);
+    }
+}
+
+pub fn walk_variable_decl<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    decl: &'ast VariableDecl,
+) {
+    if let Some(initializer) = &decl.initializer {
+        visitor.visit_expr(initializer);
+    }
+}
+
+pub fn walk_match_arm<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, arm: &'ast MatchArm) {
+    visitor.visit_pattern(&arm.pattern);
+    if let Some(guard) = &arm.guard {
+        visitor.visit_expr(guard);
+    }
+    visitor.visit_expr(&arm.body);
+}
+
+pub fn walk_pattern<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, pattern: &'ast Pattern) {
+    match pattern {
+        Pattern::Literal(literal) => walk_literal(visitor, literal),
+        Pattern::Identifier(_) | Pattern::Wildcard => {}
+        Pattern::List(patterns) | Pattern::Tuple(patterns) => {
+            for pattern in patterns {
+                visitor.visit_pattern(pattern);
+            }
+        }
+        Pattern::Constructor { fields, .. } => {
+            for field in fields {
+                visitor.visit_pattern(field);
+            }
+        }
+        Pattern::Object(fields) => {
+            for (_, pattern) in fields {
+                visitor.visit_pattern(pattern);
+            }
+        }
+        Pattern::Range { start, end } => {
+            visitor.visit_pattern(start);
+            visitor.visit_pattern(end);
+        }
+        Pattern::Guard { pattern, condition } => {
+            visitor.visit_pattern(pattern);
+            visitor.visit_expr(condition);
+        }
+    }
+}
+
+fn walk_literal<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, literal: &'ast Literal) {
+    match literal {
+        Literal::Array(elements) => {
+            for element in elements {
+                visitor.visit_expr(element);
+            }
+        }
+        Literal::Object(fields) => {
+            for (_, value) in fields {
+                visitor.visit_expr(value);
+            }
+        }
+        Literal::Integer(_)
+        | Literal::Float(_)
+        | Literal::String(_)
+        | Literal::Boolean(_)
+        | Literal::Null => {}
+    }
+}
+
+fn walk_class_decl<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, decl: &'ast ClassDecl) {
+    for field in &decl.fields {
+        visitor.visit_variable_decl(field);
+    }
+    for method in &decl.methods {
+        visitor.visit_function_decl(method);
+    }
+}
+
+fn walk_interface_decl<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    decl: &'ast InterfaceDecl,
+) {
+    for method in &decl.methods {
+        for param in &method.params {
+            if let Some(default_value) = &param.default_value {
+                visitor.visit_expr(default_value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use crate::tokenizer::Tokenizer;
+
+    fn parse(source: &str) -> Program {
+        let tokens = Tokenizer::new(source).tokenize().expect("tokenize");
+        Parser::new(tokens).parse().expect("parse")
+    }
+
+    #[derive(Default)]
+    struct FunctionCounter {
+        count: usize,
+    }
+
+    impl<'ast> Visitor<'ast> for FunctionCounter {
+        fn visit_function_decl(&mut self, decl: &'ast FunctionDecl) {
+            self.count += 1;
+            walk_function_decl(self, decl);
+        }
+    }
+
+    #[test]
+    fn visitor_counts_function_definitions() {
+        let program = parse(
+            r#"
+fn helper(x) {
+    return x * 2;
+}
+
+fn outer() {
+    fn inner() {
+        return 1;
+    }
+    return inner();
+}
+
+fn main() {
+    return helper(outer());
+}
+"#,
+        );
+        let mut counter = FunctionCounter::default();
+        walk_program(&mut counter, &program);
+        assert_eq!(counter.count, 4);
+    }
+
+    #[test]
+    fn default_traversal_reaches_nested_expressions() {
+        #[derive(Default)]
+        struct IdentifierCollector {
+            names: Vec<String>,
+        }
+
+        impl<'ast> Visitor<'ast> for IdentifierCollector {
+            fn visit_expr(&mut self, expr: &'ast Expr) {
+                if let Expr::Identifier { name, .. } = expr {
+                    self.names.push(name.as_str().to_string());
+                }
+                walk_expr(self, expr);
+            }
+        }
+
+        let program = parse("fn main() { let total = [a, b + c]; if flag { return total; } }");
+        let mut collector = IdentifierCollector::default();
+        collector.visit_program(&program);
+        assert_eq!(collector.names, ["a", "b", "c", "flag", "total"]);
+    }
+}
+
+//=====================================================
+// End of file
+//=====================================================
@@ -158,10 +158,10 @@ pub fn dump_regalloc(module: &MirModule) -> ModuleAllocation {
     for function in module.functions() {
         println!("// RegAlloc {}", function.name);
         if let Some(result) = allocation.get(function.id) {
-            for (slot, reg) in &result.reg_map {
+            for (slot, reg) in by_slot(&result.reg_map) {
                 println!("  value {} -> r{}", slot, reg.0);
             }
-            for (slot, spill) in &result.spill_map {
+            for (slot, spill) in by_slot(&result.spill_map) {
                 println!("  value {} -> spill {}", slot, spill);
             }
             for summary in &result.summaries {
@@ -192,6 +192,15 @@ pub fn dump_regalloc(module: &MirModule) -> ModuleAllocation {
     allocation
 }
 
+/// Map entries ordered by slot, so the dump does not follow hash order.
+fn by_slot<'m, K: Ord + 'm, V: 'm>(
+    map: impl IntoIterator<Item = (&'m K, &'m V)>,
+) -> Vec<(&'m K, &'m V)> {
+    let mut entries: Vec<_> = map.into_iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
 struct IrFunctionView<'a> {
     module: &'a SolvraIrModule,
     function: &'a FunctionIR,
@@ -460,7 +469,7 @@ impl<'a> IrFunctionView<'a> {
                 (SsaCallTarget::Static(name), false)
             }
             CallTarget::External { symbol } => (SsaCallTarget::Static(symbol.clone()), false),
-            CallTarget::Intrinsic { name } => (SsaCallTarget::Static((*name).to_string()), false),
+            CallTarget::Intrinsic { name } => (SsaCallTarget::Static(name.clone()), false),
             CallTarget::Dynamic => (SsaCallTarget::Virtual("dynamic".into()), true),
             CallTarget::Method { name } => (SsaCallTarget::Virtual(name.clone()), true),
         }
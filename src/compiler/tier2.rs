@@ -12,6 +12,11 @@
 
 use anyhow::Result;
 
+use crate::ir::block::BlockId;
+use crate::ir::function::FunctionIR;
+use crate::ir::inline::inline_small_functions;
+use crate::ir::ir::SolvraIrModule;
+use crate::ir::ops::{BranchHint, TerminatorKind};
 use solvra_core::jit::code_cache::Tier1CodeCache;
 use solvra_core::jit::tier1_native::compile_tier2_native;
 use solvra_core::jit::tier2_opt::{
@@ -35,6 +40,9 @@ use solvra_core::jit::{tier2_analysis, tier2_deopt};
 pub struct Tier2Options {
     pub enable: bool,
     pub inline_config: InlineConfig,
+    /// Inline calls to functions with at most this many IR instructions
+    /// before MIR lowering; `None` leaves calls in place.
+    pub inline_threshold: Option<usize>,
 }
 
 impl Default for Tier2Options {
@@ -42,10 +50,93 @@ impl Default for Tier2Options {
         Self {
             enable: false,
             inline_config: InlineConfig::default(),
+            inline_threshold: None,
         }
     }
 }
 
+impl Tier2Options {
+    pub fn with_inline_threshold(mut self, threshold: usize) -> Self {
+        self.inline_threshold = Some(threshold);
+        self
+    }
+}
+
+/// Inline small, non-recursive callees throughout `module` so the MIR lowered
+/// from it sees their bodies in place of the calls. Returns the number of
+/// call sites replaced.
+pub fn inline_module(module: &mut SolvraIrModule, options: &Tier2Options) -> usize {
+    options
+        .inline_threshold
+        .map_or(0, |threshold| inline_small_functions(module, threshold))
+}
+
+/// Lay out every function in `module` by its branch hints; see
+/// [`layout_hinted_blocks`]. Returns the number of functions reordered.
+pub fn layout_module(module: &mut SolvraIrModule) -> usize {
+    module
+        .functions_mut()
+        .iter_mut()
+        .map(layout_hinted_blocks)
+        .filter(|changed| *changed)
+        .count()
+}
+
+/// Move cold blocks, those reachable from the entry only through the
+/// unlikely edge of a hinted branch, after all other blocks so the likely
+/// path stays contiguous. Both groups keep their relative order, and MIR
+/// lowered afterwards inherits the layout. Returns whether blocks moved.
+pub fn layout_hinted_blocks(function: &mut FunctionIR) -> bool {
+    let reachable = reachable_blocks(function, true);
+    let hot = reachable_blocks(function, false);
+    let is_cold = |block: &BlockId| reachable[block.index()] && !hot[block.index()];
+    let ids = function.blocks.iter().map(|block| block.id);
+    let order: Vec<BlockId> = ids
+        .clone()
+        .filter(|block| !is_cold(block))
+        .chain(ids.filter(is_cold))
+        .collect();
+    if order
+        .iter()
+        .enumerate()
+        .all(|(position, block)| block.index() == position)
+    {
+        return false;
+    }
+    function.reorder_blocks(&order);
+    true
+}
+
+fn reachable_blocks(function: &FunctionIR, follow_unlikely: bool) -> Vec<bool> {
+    let mut seen = vec![false; function.blocks.len()];
+    let mut pending = vec![function.entry_block];
+    while let Some(block) = pending.pop() {
+        if std::mem::replace(&mut seen[block.index()], true) {
+            continue;
+        }
+        let Some(terminator) = &function.block(block).terminator else {
+            continue;
+        };
+        match &terminator.kind {
+            TerminatorKind::Jump { target } => pending.push(*target),
+            TerminatorKind::Branch {
+                then_block,
+                else_block,
+                ..
+            } => {
+                if follow_unlikely || terminator.hint != Some(BranchHint::Unlikely) {
+                    pending.push(*then_block);
+                }
+                if follow_unlikely || terminator.hint != Some(BranchHint::Likely) {
+                    pending.push(*else_block);
+                }
+            }
+            TerminatorKind::Return { .. } => {}
+        }
+    }
+    seen
+}
+
 /// Entry point: takes a prepared Tier-2 SSA function, optimizes, lowers, and installs into cache.
 #[allow(dead_code)] // Tier-2 execution is gated off in current builds.
 pub fn compile_and_install_tier2(
@@ -90,6 +181,131 @@ pub fn compile_and_install_tier2(
     Ok(())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::lowering::lower_program;
+    use crate::ir::verify::verify_function;
+    use crate::parser::Parser;
+    use crate::resolver::{Diagnostics, resolve_module};
+    use crate::tokenizer::Tokenizer;
+
+    fn lowered(source: &str) -> SolvraIrModule {
+        let tokens = Tokenizer::new(source).tokenize().expect("tokenize script");
+        let program = Parser::new(tokens).parse().expect("parse program");
+        let mut diagnostics = Diagnostics::new();
+        let resolutions = resolve_module(&program, &mut diagnostics);
+        lower_program(&program, &resolutions).expect("lower program")
+    }
+
+    /// Block labels of `name` in listing order.
+    fn block_labels(module: &SolvraIrModule, name: &str) -> Vec<String> {
+        module
+            .function_by_name(name)
+            .unwrap()
+            .dump()
+            .lines()
+            .filter_map(|line| line.strip_suffix(':'))
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Instruction count of each Tier-1 MIR block of `name`, in block order.
+    fn mir_block_sizes(module: &SolvraIrModule, name: &str) -> Vec<usize> {
+        let lowered = crate::compiler::tier1::lower_ir_to_mir(module);
+        let function = lowered
+            .module
+            .functions()
+            .into_iter()
+            .find(|function| function.name == name)
+            .expect("function lowered to MIR");
+        function
+            .blocks
+            .iter()
+            .map(|block| block.instructions.len())
+            .collect()
+    }
+
+    #[test]
+    fn unlikely_then_branch_moves_after_the_fallthrough() {
+        let mut module = lowered(
+            r#"
+fn check(x) {
+    @unlikely if x < 0 {
+        return 0 - x;
+    }
+    return x;
+}
+
+fn main() {
+    return check(3);
+}
+"#,
+        );
+        assert_eq!(
+            block_labels(&module, "check"),
+            ["entry", "if.then", "if.merge"]
+        );
+
+        assert_eq!(layout_module(&mut module), 1);
+        assert_eq!(
+            block_labels(&module, "check"),
+            ["entry", "if.merge", "if.then"]
+        );
+        let check = module.function_by_name("check").unwrap();
+        assert!(check.dump().contains("bb2, bb1 [unlikely]"));
+        verify_function(check).expect("reordered function verifies");
+    }
+
+    #[test]
+    fn likely_branch_keeps_its_else_out_of_line() {
+        let mut module = lowered(
+            r#"
+fn pick(flag) {
+    let mut n = 0;
+    @likely if flag {
+        n = 1;
+    } else {
+        n = 2;
+    }
+    return n;
+}
+"#,
+        );
+        assert_eq!(layout_module(&mut module), 1);
+        assert_eq!(
+            block_labels(&module, "pick"),
+            ["entry", "if.then", "if.merge", "if.else"]
+        );
+        verify_function(module.function_by_name("pick").unwrap()).expect("verifies");
+
+        let mut unhinted = lowered("fn pick(flag) { if flag { return 1; } return 2; }");
+        assert_eq!(layout_module(&mut unhinted), 0);
+    }
+
+    #[test]
+    fn tier1_mir_follows_the_hinted_layout() {
+        let source = r#"
+fn check(x) {
+    @unlikely if x < 0 {
+        return 0 - x;
+    }
+    return x;
+}
+"#;
+        let mut module = lowered(source);
+        let before = mir_block_sizes(&module, "check");
+        assert_eq!(before.len(), 3);
+        assert_ne!(before[1], before[2], "then and merge blocks must differ");
+
+        layout_module(&mut module);
+        assert_eq!(
+            mir_block_sizes(&module, "check"),
+            [before[0], before[2], before[1]]
+        );
+    }
+}
+
 //==============================================
 // End of file
 //==============================================
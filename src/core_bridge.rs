@@ -4,12 +4,15 @@ use crate::interpreter::Interpreter;
 use crate::modules::ModuleLoader;
 use crate::parser::Parser;
 use crate::tokenizer::Tokenizer;
+use crate::vm::compiler::{self as vm_compiler, CompilerOptions};
+use crate::vm::runtime::{NativeFunction, RuntimeOptions, run_bytecode};
 use crate::vm::stack_vm::StackVm;
 use once_cell::sync::OnceCell;
 use parking_lot::Mutex;
 use solvra_core::memory::{MemoryContract, MemoryError, MemoryHandle, MemoryStats};
 use solvra_core::module::Module;
 use solvra_core::sys::hal::HardwareAbstractionLayer;
+use solvra_core::vm::bytecode::VmBytecode;
 use solvra_core::vm::loader::ModuleLoaderVm;
 use solvra_core::{
     SolvraError as SolvraCoreError, SolvraResult as SolvraCoreResult, SolvraRuntime,
@@ -206,6 +209,61 @@ impl CoreBridge {
     }
 }
 
+/// Scripts compiled by an [`Embedder`], ready to run any number of times.
+#[derive(Clone)]
+pub struct CompiledProgram {
+    bytecode: Arc<VmBytecode>,
+}
+
+/// Entry point for hosts that run SolvraScript without the CLI.
+///
+/// Compiles source to VM bytecode and runs it, exposing host functions
+/// registered with `register_native_fn` to scripts as ordinary calls.
+#[derive(Default)]
+pub struct Embedder {
+    natives: HashMap<String, NativeFunction>,
+}
+
+impl Embedder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn compile(&self, source: &str) -> SolvraCoreResult<CompiledProgram> {
+        let mut tokenizer = Tokenizer::new(source);
+        let tokens = tokenizer.tokenize().map_err(SolvraCoreError::Internal)?;
+        let program = Parser::new(tokens)
+            .parse()
+            .map_err(|err| SolvraCoreError::Internal(err.to_string()))?;
+        let compiled =
+            vm_compiler::compile_program_with_options(&program, CompilerOptions::default())
+                .map_err(|err| SolvraCoreError::Internal(err.to_string()))?;
+        let bytecode = VmBytecode::decode(&compiled.bytecode[..])
+            .map_err(|err| SolvraCoreError::Internal(format!("bytecode decode error: {err}")))?;
+        Ok(CompiledProgram {
+            bytecode: Arc::new(bytecode),
+        })
+    }
+
+    pub fn run(&self, program: &CompiledProgram) -> SolvraCoreResult<CoreValue> {
+        let options = RuntimeOptions {
+            native_functions: self.natives.clone(),
+            ..RuntimeOptions::default()
+        };
+        run_bytecode(Arc::clone(&program.bytecode), options)
+    }
+
+    /// Make `function` callable from scripts as `name(...)`. Registering a
+    /// name again replaces the earlier function.
+    pub fn register_native_fn(
+        &mut self,
+        name: &str,
+        function: Box<dyn Fn(&[CoreValue]) -> CoreValue + Send + Sync>,
+    ) {
+        self.natives.insert(name.to_string(), Arc::from(function));
+    }
+}
+
 impl CoreBridge {
     fn clone_for_spawn(&self) -> Self {
         Self {
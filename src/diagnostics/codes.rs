@@ -0,0 +1,124 @@
+//=====================================================
+// File: diagnostics/codes.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Stable diagnostic codes for SolvraScript front-end errors
+// Objective: Give every tokenizer, parser, and resolver failure a lookup
+//            code with a longer explanation available via `explain`
+//=====================================================
+
+// Codes are grouped by stage: E00xx lexical/syntax, E01xx name resolution,
+// E02xx type checking.
+// Once published a code must never be reused for a different error.
+
+pub const UNEXPECTED_TOKEN: &str = "E0001";
+pub const UNTERMINATED_STRING: &str = "E0002";
+pub const UNEXPECTED_END_OF_INPUT: &str = "E0003";
+pub const INVALID_SYNTAX: &str = "E0004";
+pub const UNEXPECTED_CHARACTER: &str = "E0005";
+pub const INVALID_NUMBER_LITERAL: &str = "E0006";
+pub const UNTERMINATED_COMMENT: &str = "E0007";
+pub const SINGLE_QUOTED_STRING: &str = "E0008";
+pub const TOKENIZER_LIMIT: &str = "E0009";
+pub const UNRESOLVED_IDENTIFIER: &str = "E0100";
+pub const TYPE_MISMATCH: &str = "E0200";
+
+const EXPLANATIONS: &[(&str, &str)] = &[
+    (
+        UNEXPECTED_TOKEN,
+        "The parser found a token that cannot appear at this point. This usually \
+         means a missing delimiter or operator, e.g. `let x = (1 + 2;` is missing \
+         the closing `)` before `;`.",
+    ),
+    (
+        UNTERMINATED_STRING,
+        "A string literal, template string, or interpolation was opened but never \
+         closed before the end of the file. Add the matching `\"` (or `}` for an \
+         interpolation).",
+    ),
+    (
+        UNEXPECTED_END_OF_INPUT,
+        "The file ended while the parser still expected more input, for example \
+         a function body without its closing `}`.",
+    ),
+    (
+        INVALID_SYNTAX,
+        "The construct is not valid SolvraScript, such as an `export` that is not \
+         followed by a declaration or an expression nested too deeply.",
+    ),
+    (
+        UNEXPECTED_CHARACTER,
+        "The tokenizer found a character that does not start any SolvraScript \
+         token. Check for stray punctuation or non-ASCII symbols.",
+    ),
+    (
+        INVALID_NUMBER_LITERAL,
+        "A numeric literal could not be parsed, usually because it is out of \
+         range for a 64-bit integer or float.",
+    ),
+    (
+        UNTERMINATED_COMMENT,
+        "A block comment opened with `/*` was never closed with `*/`.",
+    ),
+    (
+        SINGLE_QUOTED_STRING,
+        "SolvraScript strings use double quotes. Replace `'text'` with `\"text\"`.",
+    ),
+    (
+        TOKENIZER_LIMIT,
+        "The tokenizer exceeded its internal step limit. This indicates a \
+         tokenizer bug; please report the input that triggered it.",
+    ),
+    (
+        UNRESOLVED_IDENTIFIER,
+        "The name is not declared in any enclosing scope. It may still resolve \
+         to a builtin at runtime; otherwise declare it with `let` or check the \
+         spelling.",
+    ),
+    (
+        TYPE_MISMATCH,
+        "A value can never have the type its context requires, e.g. \
+         `let x: int = \"text\";` or `\"a\" - 1`. Only mismatches that are \
+         certain from literals are reported; change the value or the annotation.",
+    ),
+];
+
+/// Return the long-form description for a diagnostic code.
+pub fn explain(code: &str) -> Option<&'static str> {
+    EXPLANATIONS
+        .iter()
+        .find(|(known, _)| *known == code)
+        .map(|(_, text)| *text)
+}
+
+/// All known codes, in declaration order.
+pub fn all() -> impl Iterator<Item = &'static str> {
+    EXPLANATIONS.iter().map(|(code, _)| *code)
+}
+
+/// Classify a tokenizer error message. The tokenizer reports plain strings,
+/// so the code is derived from the message prefix.
+pub fn for_tokenizer_message(message: &str) -> &'static str {
+    if message.starts_with("Unterminated block comment") {
+        UNTERMINATED_COMMENT
+    } else if message.starts_with("Unterminated") {
+        UNTERMINATED_STRING
+    } else if message.starts_with("Unexpected character") {
+        UNEXPECTED_CHARACTER
+    } else if message.starts_with("Invalid float literal")
+        || message.starts_with("Invalid integer literal")
+    {
+        INVALID_NUMBER_LITERAL
+    } else if message.starts_with("Single-quoted strings") {
+        SINGLE_QUOTED_STRING
+    } else if message.starts_with("Tokenizer exceeded") {
+        TOKENIZER_LIMIT
+    } else {
+        INVALID_SYNTAX
+    }
+}
+
+//=====================================================
+// End of file
+//=====================================================
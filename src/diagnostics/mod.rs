@@ -0,0 +1,333 @@
+//=====================================================
+// File: diagnostics/mod.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Structured compiler diagnostics for SolvraScript
+// Objective: Convert tokenizer, parser, and resolver failures into a single
+//            Diagnostic shape shared by the CLI and editor integrations
+//=====================================================
+
+#![allow(dead_code)]
+
+pub mod codes;
+
+pub use codes::explain;
+
+use crate::ast::Span;
+use crate::parser::{ParseError, Parser};
+use crate::resolver::{self, Diagnostics as ResolverDiagnostics};
+use crate::tokenizer::{Position, Token, Tokenizer};
+use serde_json::{Value as JsonValue, json};
+use std::fmt;
+
+/// How serious a diagnostic is. Mirrors the LSP severity levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Information,
+    Hint,
+}
+
+impl Severity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Information => "info",
+            Severity::Hint => "hint",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A secondary location attached to a diagnostic (e.g. "first defined here").
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelatedInformation {
+    pub message: String,
+    pub range: Span,
+}
+
+/// Replace the text covered by `range` with `new_text`. A zero-width range
+/// is an insertion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextEdit {
+    pub range: Span,
+    pub new_text: String,
+}
+
+/// A suggested, mechanically applicable correction for a diagnostic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fix {
+    pub title: String,
+    pub edits: Vec<TextEdit>,
+}
+
+impl Fix {
+    /// A fix that inserts `text` at `position`.
+    pub fn insert(title: impl Into<String>, position: Position, text: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            edits: vec![TextEdit {
+                range: Span::new(position.clone(), position),
+                new_text: text.into(),
+            }],
+        }
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        json!({
+            "title": self.title,
+            "edits": self
+                .edits
+                .iter()
+                .map(|edit| json!({
+                    "range": span_to_json(&edit.range),
+                    "new_text": edit.new_text,
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// A single compiler diagnostic with a stable code and source range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: &'static str,
+    pub message: String,
+    pub range: Span,
+    pub related: Vec<RelatedInformation>,
+    pub fixes: Vec<Fix>,
+}
+
+impl Diagnostic {
+    pub fn new(
+        severity: Severity,
+        code: &'static str,
+        message: impl Into<String>,
+        range: Span,
+    ) -> Self {
+        Self {
+            severity,
+            code,
+            message: message.into(),
+            range,
+            related: Vec::new(),
+            fixes: Vec::new(),
+        }
+    }
+
+    pub fn error(code: &'static str, message: impl Into<String>, range: Span) -> Self {
+        Self::new(Severity::Error, code, message, range)
+    }
+
+    pub fn warning(code: &'static str, message: impl Into<String>, range: Span) -> Self {
+        Self::new(Severity::Warning, code, message, range)
+    }
+
+    pub fn with_related(mut self, message: impl Into<String>, range: Span) -> Self {
+        self.related.push(RelatedInformation {
+            message: message.into(),
+            range,
+        });
+        self
+    }
+
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fixes.push(fix);
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// Build a diagnostic for a tokenizer failure reported at `position`.
+    pub fn from_tokenizer_error(message: &str, position: Position) -> Self {
+        Self::error(
+            codes::for_tokenizer_message(message),
+            message,
+            Span::new(position.clone(), position),
+        )
+    }
+
+    /// Render as `path:line:column: severity[code]: message`.
+    pub fn render(&self, path: &str) -> String {
+        let mut rendered = format!(
+            "{}:{}:{}: {}[{}]: {}",
+            path,
+            self.range.start.line,
+            self.range.start.column,
+            self.severity,
+            self.code,
+            self.message
+        );
+        for related in &self.related {
+            rendered.push_str(&format!(
+                "\n    {}:{}:{}: note: {}",
+                path, related.range.start.line, related.range.start.column, related.message
+            ));
+        }
+        for fix in &self.fixes {
+            rendered.push_str(&format!("\n    help: {}", fix.title));
+        }
+        rendered
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        json!({
+            "severity": self.severity.as_str(),
+            "code": self.code,
+            "message": self.message,
+            "range": span_to_json(&self.range),
+            "related": self
+                .related
+                .iter()
+                .map(|related| json!({
+                    "message": related.message,
+                    "range": span_to_json(&related.range),
+                }))
+                .collect::<Vec<_>>(),
+            "fixes": self.fixes.iter().map(Fix::to_json).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl From<&ParseError> for Diagnostic {
+    fn from(error: &ParseError) -> Self {
+        let code = error.code();
+        let diagnostic = match error {
+            ParseError::UnexpectedToken {
+                expected, found, ..
+            } => Diagnostic::error(
+                code,
+                format!("expected {expected}, found {found:?}"),
+                error.span(),
+            ),
+            ParseError::UnexpectedEndOfInput { expected, position } => Diagnostic::error(
+                code,
+                format!("unexpected end of input (expected {expected})"),
+                point_span(position),
+            ),
+            ParseError::InvalidSyntax { message, position } => {
+                Diagnostic::error(code, message.clone(), point_span(position))
+            }
+            ParseError::MissingTerminator { found, .. } => Diagnostic::error(
+                code,
+                format!("expected statement terminator, found {found:?}"),
+                error.span(),
+            ),
+        };
+        match error.fix() {
+            Some(fix) => diagnostic.with_fix(fix),
+            None => diagnostic,
+        }
+    }
+}
+
+impl From<ParseError> for Diagnostic {
+    fn from(error: ParseError) -> Self {
+        Diagnostic::from(&error)
+    }
+}
+
+/// Convert resolver findings into diagnostics. Unresolved names may still be
+/// satisfied by builtins at runtime, so they are warnings; type mismatches
+/// are only recorded when certain and are errors.
+pub fn from_resolver(diagnostics: &ResolverDiagnostics) -> Vec<Diagnostic> {
+    let unresolved = diagnostics.unresolved().iter().map(|(name, position)| {
+        let end = Position::new(
+            position.line,
+            position.column + name.chars().count(),
+            position.offset + name.chars().count(),
+        );
+        Diagnostic::warning(
+            codes::UNRESOLVED_IDENTIFIER,
+            format!("unresolved identifier '{name}'"),
+            Span::new(position.clone(), end),
+        )
+    });
+    let mismatches = diagnostics.type_mismatches().iter().map(|mismatch| {
+        Diagnostic::error(
+            codes::TYPE_MISMATCH,
+            format!(
+                "type mismatch for {}: expected {}, found {}",
+                mismatch.context, mismatch.expected, mismatch.found
+            ),
+            mismatch.span.clone(),
+        )
+    });
+    unresolved.chain(mismatches).collect()
+}
+
+/// Tokenize source, mapping failures to a diagnostic.
+pub fn tokenize(source: &str) -> Result<Vec<Token>, Box<Diagnostic>> {
+    let mut tokenizer = Tokenizer::new(source);
+    tokenizer.tokenize().map_err(|message| {
+        Box::new(Diagnostic::from_tokenizer_error(
+            &message,
+            tokenizer.current_position(),
+        ))
+    })
+}
+
+/// Run the tokenizer, parser, and resolver over `source` and collect every
+/// diagnostic they report. Editors and the CLI both go through this entry
+/// point so that codes and ranges stay identical.
+pub fn check_source(source: &str) -> Vec<Diagnostic> {
+    let tokens = match tokenize(source) {
+        Ok(tokens) => tokens,
+        Err(diagnostic) => return vec![*diagnostic],
+    };
+    let program = match Parser::new(tokens).parse_recovering() {
+        (Some(program), errors) if errors.is_empty() => program,
+        (_, errors) => return errors.iter().map(Diagnostic::from).collect(),
+    };
+    let mut resolver_diagnostics = ResolverDiagnostics::new();
+    resolver::resolve_module(&program, &mut resolver_diagnostics);
+    from_resolver(&resolver_diagnostics)
+}
+
+/// Keep at most `max_errors` errors, dropping later ones, and return how
+/// many were dropped. Warnings and other severities are never dropped.
+pub fn cap_errors(diagnostics: &mut Vec<Diagnostic>, max_errors: usize) -> usize {
+    let mut seen = 0;
+    let before = diagnostics.len();
+    diagnostics.retain(|diagnostic| {
+        if !diagnostic.is_error() {
+            return true;
+        }
+        seen += 1;
+        seen <= max_errors
+    });
+    before - diagnostics.len()
+}
+
+/// Serialise a diagnostic list as `{ "diagnostics": [...] }`.
+pub fn to_json(diagnostics: &[Diagnostic]) -> JsonValue {
+    json!({
+        "diagnostics": diagnostics.iter().map(Diagnostic::to_json).collect::<Vec<_>>(),
+    })
+}
+
+fn point_span(position: &Position) -> Span {
+    Span::new(position.clone(), position.clone())
+}
+
+fn span_to_json(span: &Span) -> JsonValue {
+    json!({
+        "start": { "line": span.start.line, "column": span.start.column },
+        "end": { "line": span.end.line, "column": span.end.column },
+    })
+}
+
+//=====================================================
+// End of file
+//=====================================================
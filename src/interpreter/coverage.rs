@@ -0,0 +1,148 @@
+//=============================================
+// solvra_script/interpreter/coverage.rs
+//=============================================
+// Goal: Line coverage for interpreted scripts
+// Objective: Record which statement lines ran, per source file, and merge
+//            reports from several runs into text and LCOV output
+//=============================================
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::ast::visit::{self, Visitor};
+use crate::ast::{NodeId, Program, Stmt};
+
+/// Lines of one file that hold statements, and how often each one ran.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileCoverage {
+    /// Every statement line, mapped to its execution count (0 if never run).
+    pub lines: BTreeMap<usize, u64>,
+}
+
+impl FileCoverage {
+    pub fn hit_lines(&self) -> impl Iterator<Item = usize> + '_ {
+        self.lines
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(line, _)| *line)
+    }
+
+    pub fn hit_count(&self) -> usize {
+        self.hit_lines().count()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageReport {
+    files: BTreeMap<PathBuf, FileCoverage>,
+}
+
+impl CoverageReport {
+    pub fn file(&self, path: &Path) -> Option<&FileCoverage> {
+        self.files.get(path)
+    }
+
+    pub fn files(&self) -> impl Iterator<Item = (&Path, &FileCoverage)> {
+        self.files.iter().map(|(path, file)| (path.as_path(), file))
+    }
+
+    /// Register every statement line of `program` so unexecuted lines are
+    /// reported with a count of zero.
+    pub fn add_program(&mut self, path: &Path, program: &Program) {
+        let mut collector = StatementLines {
+            lines: Vec::new(),
+            implicit_entry: program.implicit_entry_decl().map(|decl| decl.node_id),
+        };
+        collector.visit_program(program);
+        let file = self.files.entry(path.to_path_buf()).or_default();
+        for line in collector.lines {
+            file.lines.entry(line).or_insert(0);
+        }
+    }
+
+    pub fn record_hit(&mut self, path: &Path, line: usize) {
+        let file = self.files.entry(path.to_path_buf()).or_default();
+        *file.lines.entry(line).or_insert(0) += 1;
+    }
+
+    /// Add `other`'s lines and counts to this report. The hit lines of the
+    /// result are the union of both reports' hit lines.
+    pub fn merge(&mut self, other: &CoverageReport) {
+        for (path, theirs) in &other.files {
+            let ours = self.files.entry(path.clone()).or_default();
+            for (line, count) in &theirs.lines {
+                *ours.lines.entry(*line).or_insert(0) += count;
+            }
+        }
+    }
+
+    /// One `path: hit/total lines (pct%)` row per file, then a total row.
+    pub fn summary(&self) -> String {
+        let mut out = String::new();
+        let (mut hit, mut total) = (0, 0);
+        for (path, file) in &self.files {
+            let _ = writeln!(
+                out,
+                "{}: {}",
+                path.display(),
+                ratio(file.hit_count(), file.lines.len())
+            );
+            hit += file.hit_count();
+            total += file.lines.len();
+        }
+        let _ = writeln!(out, "total: {}", ratio(hit, total));
+        out
+    }
+
+    /// The report as an LCOV tracefile: one `SF` record per file with a `DA`
+    /// line per statement line, then the hit (`LH`) and total (`LF`) counts.
+    pub fn to_lcov(&self) -> String {
+        let mut out = String::new();
+        for (path, file) in &self.files {
+            let _ = writeln!(out, "TN:");
+            let _ = writeln!(out, "SF:{}", path.display());
+            for (line, count) in &file.lines {
+                let _ = writeln!(out, "DA:{line},{count}");
+            }
+            let _ = writeln!(out, "LH:{}", file.hit_count());
+            let _ = writeln!(out, "LF:{}", file.lines.len());
+            let _ = writeln!(out, "end_of_record");
+        }
+        out
+    }
+}
+
+fn ratio(hit: usize, total: usize) -> String {
+    let percent = if total == 0 {
+        100.0
+    } else {
+        hit as f64 * 100.0 / total as f64
+    };
+    format!("{hit}/{total} lines ({percent:.1}%)")
+}
+
+/// Start line of every statement except blocks, which never run on their
+/// own, and the synthesized `main`, which is not in the source. The
+/// statements inside that `main` are still collected.
+struct StatementLines {
+    lines: Vec<usize>,
+    implicit_entry: Option<NodeId>,
+}
+
+impl<'ast> Visitor<'ast> for StatementLines {
+    fn visit_stmt(&mut self, stmt: &'ast Stmt) {
+        let synthesized = matches!(
+            stmt,
+            Stmt::FunctionDecl { decl } if Some(decl.node_id) == self.implicit_entry
+        );
+        if !synthesized && !matches!(stmt, Stmt::Block { .. }) {
+            self.lines.push(stmt.position().line);
+        }
+        visit::walk_stmt(self, stmt);
+    }
+}
+
+//=============================================
+// End of file
+//=============================================
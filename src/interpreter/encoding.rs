@@ -0,0 +1,119 @@
+//=============================================
+// solvra_script/interpreter/encoding.rs
+//=============================================
+// Goal: Text encodings for binary data
+// Objective: Standard base64 (RFC 4648, padded) and lowercase hex codecs
+//            backing the base64_* and hex_* builtins
+//=============================================
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub(super) fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let indices = [
+            b[0] >> 2,
+            ((b[0] & 0x03) << 4) | (b[1] >> 4),
+            ((b[1] & 0x0f) << 2) | (b[2] >> 6),
+            b[2] & 0x3f,
+        ];
+        for (position, index) in indices.iter().enumerate() {
+            if position <= chunk.len() {
+                out.push(BASE64_ALPHABET[*index as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+pub(super) fn base64_decode(text: &str) -> Result<Vec<u8>, String> {
+    let input = text.as_bytes();
+    if input.len() % 4 != 0 {
+        return Err(format!(
+            "base64 input length {} is not a multiple of 4",
+            input.len()
+        ));
+    }
+    let mut out = Vec::with_capacity(input.len() / 4 * 3);
+    let chunks = input.len() / 4;
+    for (chunk_index, chunk) in input.chunks(4).enumerate() {
+        let padding = chunk.iter().rev().take_while(|byte| **byte == b'=').count();
+        if padding > 2 || (padding > 0 && chunk_index + 1 != chunks) {
+            return Err("misplaced base64 padding".to_string());
+        }
+        let mut sextets = [0u8; 4];
+        for (position, byte) in chunk[..4 - padding].iter().enumerate() {
+            sextets[position] = base64_value(*byte).ok_or_else(|| {
+                format!(
+                    "invalid base64 character '{}' at offset {}",
+                    *byte as char,
+                    chunk_index * 4 + position
+                )
+            })?;
+        }
+        let decoded = [
+            (sextets[0] << 2) | (sextets[1] >> 4),
+            (sextets[1] << 4) | (sextets[2] >> 2),
+            (sextets[2] << 6) | sextets[3],
+        ];
+        out.extend_from_slice(&decoded[..3 - padding]);
+    }
+    Ok(out)
+}
+
+fn base64_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'A'..=b'Z' => Some(byte - b'A'),
+        b'a'..=b'z' => Some(byte - b'a' + 26),
+        b'0'..=b'9' => Some(byte - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+pub(super) fn hex_encode(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        out.push(DIGITS[(byte >> 4) as usize] as char);
+        out.push(DIGITS[(byte & 0x0f) as usize] as char);
+    }
+    out
+}
+
+/// Accepts upper- and lowercase digits.
+pub(super) fn hex_decode(text: &str) -> Result<Vec<u8>, String> {
+    let input = text.as_bytes();
+    if input.len() % 2 != 0 {
+        return Err(format!("hex input length {} is odd", input.len()));
+    }
+    input
+        .chunks(2)
+        .enumerate()
+        .map(|(index, pair)| {
+            let digit = |offset: usize| {
+                (pair[offset] as char).to_digit(16).ok_or_else(|| {
+                    format!(
+                        "invalid hex digit '{}' at offset {}",
+                        pair[offset] as char,
+                        index * 2 + offset
+                    )
+                })
+            };
+            Ok((digit(0)? * 16 + digit(1)?) as u8)
+        })
+        .collect()
+}
+
+//=============================================
+// End of file
+//=============================================
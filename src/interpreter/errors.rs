@@ -9,6 +9,7 @@ pub enum ErrorCode {
     TypeMismatch,
     InvalidOperation,
     RuntimePanic,
+    AssertionFailed,
 }
 
 impl ErrorCode {
@@ -19,6 +20,7 @@ impl ErrorCode {
             ErrorCode::TypeMismatch => "E003",
             ErrorCode::InvalidOperation => "E004",
             ErrorCode::RuntimePanic => "E005",
+            ErrorCode::AssertionFailed => "E006",
         }
     }
 }
@@ -73,6 +75,7 @@ pub fn runtime_error_code(error: &RuntimeError) -> ErrorCode {
         | RuntimeError::NetworkError(_)
         | RuntimeError::Exit(_)
         | RuntimeError::Custom(_) => ErrorCode::RuntimePanic,
+        RuntimeError::AssertionFailed { .. } => ErrorCode::AssertionFailed,
         RuntimeError::Return(_) | RuntimeError::Break | RuntimeError::Continue => {
             ErrorCode::RuntimePanic
         }
@@ -17,6 +17,7 @@
 use crate::ast::*;
 use crate::core_bridge::{CoreBridge, ModuleRegistration};
 use crate::modules::{ModuleArtifact, ModuleDescriptor, ModuleError, ModuleLoader};
+use crate::resolver::{self, Diagnostics as ResolverDiagnostics, SlotMap};
 use crate::symbol::Symbol;
 use crate::vm::compiler as vm_compiler;
 // time and home-dir resolved via `crate::platform` abstraction
@@ -32,13 +33,18 @@ use std::path::{Path, PathBuf};
 use crate::platform::{self, CommandResult, CommandSpec, StdioMode};
 use std::rc::Rc;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use ureq::Agent;
 
+mod coverage;
+mod encoding;
 mod errors;
+mod time_format;
 #[allow(unused_imports)]
 pub use errors::{ErrorCode, ScriptError};
 
+pub use coverage::{CoverageReport, FileCoverage};
+
 use solvra_core::Value as CoreValue;
 use solvra_core::memory::{MemoryHandle, MemoryStats};
 use solvra_core::sys::hal::{
@@ -117,6 +123,8 @@ pub enum Value {
         params: Vec<String>,
         body: Vec<Stmt>,
         closure: Environment,
+        /// File the function was declared in, for coverage attribution.
+        origin: Option<Rc<PathBuf>>,
     },
     NativeFunction {
         name: String,
@@ -144,12 +152,14 @@ impl PartialEq for Value {
                     params: params_a,
                     body: body_a,
                     closure: closure_a,
+                    ..
                 },
                 Function {
                     name: name_b,
                     params: params_b,
                     body: body_b,
                     closure: closure_b,
+                    ..
                 },
             ) => {
                 name_a == name_b
@@ -271,6 +281,28 @@ impl Value {
         }
     }
 
+    //Function: as_int
+    //Purpose: Read an integer value without coercion
+    //Inputs: &self
+    //Returns: Option<i64>
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Value::Int(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    //Function: as_float
+    //Purpose: Read a float value without coercion
+    //Inputs: &self
+    //Returns: Option<f64>
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Value::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
     //Function: as_str
     //Purpose: Provide borrowed string slice for string-like values
     //Inputs: &self
@@ -306,6 +338,12 @@ pub enum RuntimeError {
     Break,
     Continue,
     Custom(String),
+    /// Raised by `assert`/`assert_eq`; `stack` lists the calling functions,
+    /// innermost first.
+    AssertionFailed {
+        message: String,
+        stack: Vec<String>,
+    },
 }
 
 impl fmt::Display for RuntimeError {
@@ -325,6 +363,13 @@ impl fmt::Display for RuntimeError {
             RuntimeError::Break => write!(f, "Break statement outside loop"),
             RuntimeError::Continue => write!(f, "Continue statement outside loop"),
             RuntimeError::Custom(msg) => write!(f, "{}", msg),
+            RuntimeError::AssertionFailed { message, stack } => {
+                write!(f, "Assertion failed: {}", message)?;
+                for frame in stack {
+                    write!(f, "\n    at {}", frame)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -370,6 +415,76 @@ pub struct VariableEntry {
 
 type Environment = HashMap<String, VariableEntry>;
 
+/// Storage for the bindings of one call that the resolver gave a frame slot.
+/// `base_depth` is the length of `locals` when the frame was pushed: name
+/// lookups see the slots just above the call scope and below any block
+/// scopes opened inside the call.
+#[derive(Debug)]
+struct SlotFrame {
+    base_depth: usize,
+    entries: Vec<Option<(String, VariableEntry)>>,
+}
+
+impl SlotFrame {
+    fn new(base_depth: usize) -> Self {
+        Self {
+            base_depth,
+            entries: Vec::new(),
+        }
+    }
+
+    fn get(&self, slot: u32) -> Option<&VariableEntry> {
+        self.entries
+            .get(slot as usize)
+            .and_then(|entry| entry.as_ref())
+            .map(|(_, entry)| entry)
+    }
+
+    fn get_mut(&mut self, slot: u32) -> Option<&mut VariableEntry> {
+        self.entries
+            .get_mut(slot as usize)
+            .and_then(|entry| entry.as_mut())
+            .map(|(_, entry)| entry)
+    }
+
+    fn set(&mut self, slot: u32, name: String, entry: VariableEntry) {
+        let index = slot as usize;
+        if self.entries.len() <= index {
+            self.entries.resize_with(index + 1, || None);
+        }
+        self.entries[index] = Some((name, entry));
+    }
+
+    /// Name-based lookup for dynamic accesses. Later slots were declared
+    /// later, so they shadow earlier ones of the same name.
+    fn find(&self, name: &str) -> Option<&VariableEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .flatten()
+            .find(|(slot_name, _)| slot_name == name)
+            .map(|(_, entry)| entry)
+    }
+
+    fn find_mut(&mut self, name: &str) -> Option<&mut VariableEntry> {
+        self.entries
+            .iter_mut()
+            .rev()
+            .flatten()
+            .find(|(slot_name, _)| slot_name == name)
+            .map(|(_, entry)| entry)
+    }
+}
+
+/// How identifier reads were served since the interpreter was created.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LookupStats {
+    /// Reads answered directly from a frame slot.
+    pub slot_hits: u64,
+    /// Reads that walked the scope chain by name.
+    pub name_lookups: u64,
+}
+
 #[derive(Clone, Copy)]
 struct DeprecatedAlias {
     canonical: &'static str,
@@ -395,6 +510,11 @@ pub struct Interpreter {
     globals: Environment,
     locals: Vec<Environment>,
     call_stack: Vec<String>,
+    /// Whether the `main` being run was synthesized from top-level code.
+    implicit_main: bool,
+    /// Synthesized `main` declarations, which coverage does not count as
+    /// source lines.
+    implicit_entries: HashSet<NodeId>,
     max_call_depth: usize,
     events: HashMap<String, Vec<Value>>,
     resources: HashMap<u64, Resource>,
@@ -411,11 +531,32 @@ pub struct Interpreter {
     alias_warnings: HashSet<String>,
     start_time: Instant,
     instruction_counter: usize,
+    slots: SlotMap,
+    slot_frames: Vec<SlotFrame>,
+    slot_locals: bool,
+    lookup_stats: LookupStats,
+    captured_output: Option<String>,
+    clock: Clock,
+    coverage: Option<CoverageReport>,
+    current_origin: Option<Rc<PathBuf>>,
+}
+
+/// Source of wall-clock time for `time_now_ms`, in milliseconds since the
+/// Unix epoch. Replace it with `Interpreter::set_clock` for deterministic runs.
+pub type Clock = Rc<dyn Fn() -> i64>;
+
+fn system_clock() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis().min(i64::MAX as u128) as i64)
+        .unwrap_or(0)
 }
 
 const HOT_CALL_THRESHOLD: usize = 8;
 const INSTRUCTION_LIMIT: usize = 5_000_000;
 const TEST_TIMEOUT_SECS: u64 = 120;
+/// `tracing` target for events raised by `log_info`/`log_warn`/`log_error`.
+pub const SCRIPT_LOG_TARGET: &str = "solvrascript::script";
 
 //=============================================/*
 //  Defines environment storage, resource tracking, and core interpreter state.
@@ -446,6 +587,8 @@ impl Interpreter {
             globals: HashMap::new(),
             locals: Vec::new(),
             call_stack: Vec::new(),
+            implicit_main: false,
+            implicit_entries: HashSet::new(),
             max_call_depth: 1000,
             events: HashMap::new(),
             resources: HashMap::new(),
@@ -462,6 +605,14 @@ impl Interpreter {
             alias_warnings: HashSet::new(),
             start_time: Instant::now(),
             instruction_counter: 0,
+            slots: SlotMap::default(),
+            slot_frames: Vec::new(),
+            slot_locals: true,
+            lookup_stats: LookupStats::default(),
+            captured_output: None,
+            clock: Rc::new(system_clock),
+            coverage: None,
+            current_origin: None,
         };
         interpreter.init_builtins();
         crate::modules::core_vm::register_vm_builtins(&mut interpreter);
@@ -485,6 +636,79 @@ impl Interpreter {
         self.module_loader.borrow().script_search_paths()
     }
 
+    //Function: set_slot_locals
+    //Purpose: Toggle frame-slot storage for resolver-assigned locals (on by default).
+    //Inputs: &mut self, enabled: bool
+    //Returns: ()
+    pub fn set_slot_locals(&mut self, enabled: bool) {
+        self.slot_locals = enabled;
+    }
+
+    pub fn lookup_stats(&self) -> LookupStats {
+        self.lookup_stats
+    }
+
+    //Function: capture_output
+    //Purpose: Buffer script stdout (prt/println/endl/io_stdout_writeln) instead of writing it.
+    //Inputs: &mut self
+    //Returns: ()
+    pub fn capture_output(&mut self) {
+        self.captured_output.get_or_insert_with(String::new);
+    }
+
+    //Function: take_output
+    //Purpose: Return and clear the buffered stdout; empty when capture is off.
+    //Inputs: &mut self
+    //Returns: String
+    pub fn take_output(&mut self) -> String {
+        self.captured_output
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    fn write_output(&mut self, args: &[Value], newline: bool) -> io::Result<()> {
+        match self.captured_output.as_mut() {
+            Some(buffer) => {
+                for value in args {
+                    buffer.push_str(&format_stdout_value(value));
+                }
+                if newline {
+                    buffer.push('\n');
+                }
+                Ok(())
+            }
+            None => write_values_to_stdout(args, newline),
+        }
+    }
+
+    //Function: enable_coverage
+    //Purpose: Start recording which statement lines run, per source file.
+    //Inputs: &mut self
+    //Returns: ()
+    pub fn enable_coverage(&mut self) {
+        self.coverage.get_or_insert_with(CoverageReport::default);
+    }
+
+    //Function: take_coverage
+    //Purpose: Return and clear the recorded coverage; empty when coverage is off.
+    //Inputs: &mut self
+    //Returns: CoverageReport
+    pub fn take_coverage(&mut self) -> CoverageReport {
+        self.coverage
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    //Function: set_clock
+    //Purpose: Replace the wall clock read by time_now_ms (epoch milliseconds).
+    //Inputs: &mut self, clock: Fn() -> i64
+    //Returns: ()
+    pub fn set_clock(&mut self, clock: impl Fn() -> i64 + 'static) {
+        self.clock = Rc::new(clock);
+    }
+
     //Function: set_dry_run
     //Purpose: Toggle dry-run mode to skip side-effectful operations (spawns).
     //Inputs: &mut self, enabled: bool
@@ -525,6 +749,23 @@ impl Interpreter {
         self.eval_stmt(stmt)
     }
 
+    //Function: call_global_function
+    //Purpose: Call a top-level function defined by a previously evaluated program.
+    //Inputs: &mut self, name: &str, args: Vec<Value>
+    //Returns: Result<Value, RuntimeError>
+    pub fn call_global_function(
+        &mut self,
+        name: &str,
+        args: Vec<Value>,
+    ) -> Result<Value, RuntimeError> {
+        let func = self
+            .globals
+            .get(name)
+            .map(|entry| entry.value.clone())
+            .ok_or_else(|| RuntimeError::VariableNotFound(name.to_string()))?;
+        self.call_function(func, args)
+    }
+
     //=============================================
     //            Section 7: Builtin Registration
     //=============================================
@@ -1379,6 +1620,82 @@ impl Interpreter {
             Interpreter::builtin_core_memory_stats,
         );
         self.register_builtin("now_ms", NativeArity::Exact(0), Interpreter::builtin_now_ms);
+        self.register_builtin(
+            "base64_encode",
+            NativeArity::Exact(1),
+            Interpreter::builtin_base64_encode,
+        );
+        self.register_builtin(
+            "base64_decode",
+            NativeArity::Exact(1),
+            Interpreter::builtin_base64_decode,
+        );
+        self.register_builtin(
+            "base64_decode_bytes",
+            NativeArity::Exact(1),
+            Interpreter::builtin_base64_decode_bytes,
+        );
+        self.register_builtin(
+            "hex_encode",
+            NativeArity::Exact(1),
+            Interpreter::builtin_hex_encode,
+        );
+        self.register_builtin(
+            "hex_decode",
+            NativeArity::Exact(1),
+            Interpreter::builtin_hex_decode,
+        );
+        self.register_builtin(
+            "hex_decode_bytes",
+            NativeArity::Exact(1),
+            Interpreter::builtin_hex_decode_bytes,
+        );
+        self.register_builtin(
+            "time_now_ms",
+            NativeArity::Exact(0),
+            Interpreter::builtin_time_now_ms,
+        );
+        self.register_builtin(
+            "time_format",
+            NativeArity::Exact(2),
+            Interpreter::builtin_time_format,
+        );
+        self.register_builtin(
+            "time_parse",
+            NativeArity::Exact(2),
+            Interpreter::builtin_time_parse,
+        );
+        self.register_builtin(
+            "assert",
+            NativeArity::Range {
+                min: 1,
+                max: Some(2),
+            },
+            Interpreter::builtin_assert,
+        );
+        self.register_builtin(
+            "assert_eq",
+            NativeArity::Range {
+                min: 2,
+                max: Some(3),
+            },
+            Interpreter::builtin_assert_eq,
+        );
+        self.register_builtin(
+            "log_info",
+            NativeArity::Exact(1),
+            Interpreter::builtin_log_info,
+        );
+        self.register_builtin(
+            "log_warn",
+            NativeArity::Exact(1),
+            Interpreter::builtin_log_warn,
+        );
+        self.register_builtin(
+            "log_error",
+            NativeArity::Exact(1),
+            Interpreter::builtin_log_error,
+        );
     }
 
     pub(crate) fn register_builtin(
@@ -1426,7 +1743,7 @@ impl Interpreter {
         // Preserve the exact formatting supplied by the script, including escape sequences
         // that were decoded by the tokenizer. We intentionally avoid inserting separators or
         // implicit newlines so SolvraScript authors have full control over stdout layout.
-        write_values_to_stdout(args, false)?;
+        self.write_output(args, false)?;
         Ok(Value::Null)
     }
 
@@ -1454,14 +1771,14 @@ impl Interpreter {
 
     fn builtin_println(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
         // `println` is a convenience wrapper over `prt` that appends a newline after the payload.
-        write_values_to_stdout(args, true)?;
+        self.write_output(args, true)?;
         Ok(Value::Null)
     }
 
     fn builtin_endl(&mut self, _: &[Value]) -> Result<Value, RuntimeError> {
         // Provides a semantic newline emitter so SolvraScript code can mirror C++'s `std::endl`.
         // Flushing stdout here keeps interactive shells responsive when `endl()` is used alone.
-        write_values_to_stdout(&[], true)?;
+        self.write_output(&[], true)?;
         Ok(Value::Null)
     }
 
@@ -1884,9 +2201,20 @@ impl Interpreter {
         if let Some(dir) = &maybe_dir {
             self.module_path_stack.push(dir.clone());
         }
+        let previous_origin = match &origin {
+            Some(origin_path) => {
+                let path = origin_path.as_ref().to_path_buf();
+                if let Some(coverage) = self.coverage.as_mut() {
+                    coverage.add_program(&path, program);
+                }
+                self.current_origin.replace(Rc::new(path))
+            }
+            None => self.current_origin.clone(),
+        };
 
         let result = self.eval_program_internal(program);
 
+        self.current_origin = previous_origin;
         if maybe_dir.is_some() {
             self.module_path_stack.pop();
         }
@@ -1900,6 +2228,14 @@ impl Interpreter {
             program.statements.len(),
             program.implicit_entry
         );
+        if self.slot_locals {
+            let mut diagnostics = ResolverDiagnostics::new();
+            let resolution = resolver::resolve_module(program, &mut diagnostics);
+            self.slots.extend(resolution.slots);
+        }
+        if let Some(entry) = program.implicit_entry_decl() {
+            self.implicit_entries.insert(entry.node_id);
+        }
         let mut last = None;
         for stmt in &program.statements {
             match self.eval_stmt(stmt) {
@@ -1912,6 +2248,7 @@ impl Interpreter {
             let main = self
                 .get_variable("main")
                 .ok_or_else(|| RuntimeError::VariableNotFound("main".to_string()))?;
+            self.implicit_main = true;
             let value = self.call_function(main, Vec::new())?;
             Ok(Some(value))
         } else {
@@ -1919,8 +2256,25 @@ impl Interpreter {
         }
     }
 
+    fn record_coverage(&mut self, stmt: &Stmt) {
+        let Some(coverage) = self.coverage.as_mut() else {
+            return;
+        };
+        match stmt {
+            Stmt::Block { .. } => return,
+            Stmt::FunctionDecl { decl } if self.implicit_entries.contains(&decl.node_id) => return,
+            _ => {}
+        }
+        let path = match &self.current_origin {
+            Some(origin) => origin.as_path(),
+            None => Path::new("<input>"),
+        };
+        coverage.record_hit(path, stmt.position().line);
+    }
+
     fn eval_stmt(&mut self, stmt: &Stmt) -> Result<Option<Value>, RuntimeError> {
         self.guard_execution()?;
+        self.record_coverage(stmt);
         match stmt {
             Stmt::ImportDecl { decl } => {
                 self.execute_import(decl)?;
@@ -1932,7 +2286,7 @@ impl Interpreter {
                 } else {
                     Value::Null
                 };
-                self.define_variable(decl.name.clone(), val, decl.is_mutable);
+                self.define_local(decl.node_id, decl.name.clone(), val, decl.is_mutable);
                 Ok(None)
             }
 
@@ -2066,6 +2420,7 @@ impl Interpreter {
                     params: decl.params.iter().map(|p| p.name.to_string()).collect(),
                     body: decl.body.clone(),
                     closure: self.capture_environment(),
+                    origin: self.current_origin.clone(),
                 };
                 self.define_variable(decl.name.clone(), func, false);
                 Ok(None)
@@ -2088,6 +2443,7 @@ impl Interpreter {
                     params: function.params.iter().map(|p| p.name.to_string()).collect(),
                     body: function.body.clone(),
                     closure: self.capture_environment(),
+                    origin: self.current_origin.clone(),
                 };
                 self.define_variable(function.name.clone(), func, false);
                 Ok(None)
@@ -2457,8 +2813,8 @@ impl Interpreter {
         self.guard_execution()?;
         match expr {
             Expr::Literal { value, .. } => self.eval_literal(value),
-            Expr::Identifier { name, .. } => self
-                .get_variable(name)
+            Expr::Identifier { name, node_id, .. } => self
+                .read_local(*node_id, name)
                 .ok_or_else(|| RuntimeError::VariableNotFound(name.to_string())),
             Expr::Binary {
                 left,
@@ -2478,7 +2834,12 @@ impl Interpreter {
             }
             Expr::StringTemplate { parts, .. } => self.eval_string_template(parts),
 
-            Expr::Assign { target, value, .. } => self.eval_assignment(target, value),
+            Expr::Assign {
+                target,
+                value,
+                node_id,
+                ..
+            } => self.eval_assignment(*node_id, target, value),
             Expr::Call { callee, args, .. } => {
                 let func = self.eval_expr(callee)?;
                 let mut arg_values = Vec::new();
@@ -2553,21 +2914,18 @@ impl Interpreter {
                     result => result,
                 }
             }
-            Expr::Lambda {
-                params,
-                body,
-                position,
-            } => {
+            Expr::Lambda { params, body, span, .. } => {
                 let closure = self.capture_environment();
                 let body_stmt = Stmt::Return {
                     value: Some(*body.clone()),
-                    position: position.clone(),
+                    span: span.clone(),
                 };
                 Ok(Value::Function {
-                    name: format!("<lambda@{}:{}>", position.line, position.column),
+                    name: format!("<lambda@{}:{}>", span.start.line, span.start.column),
                     params: params.iter().map(|p| p.to_string()).collect(),
                     body: vec![body_stmt],
                     closure,
+                    origin: self.current_origin.clone(),
                 })
             }
 
@@ -2580,13 +2938,14 @@ impl Interpreter {
 
     fn eval_assignment(
         &mut self,
+        site: NodeId,
         target: &AssignTarget,
         value_expr: &Expr,
     ) -> Result<Value, RuntimeError> {
         match target {
             AssignTarget::Variable(name) => {
                 let val = self.eval_expr(value_expr)?;
-                self.assign_variable(name.as_str(), val.clone())?;
+                self.assign_local(site, name.as_str(), val.clone())?;
                 Ok(val)
             }
             AssignTarget::Index { array, index } => {
@@ -3332,7 +3691,165 @@ impl Interpreter {
         Ok(Value::Int(capped as i64))
     }
 
+    fn builtin_base64_encode(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        let bytes = expect_bytes(&args[0], "base64_encode")?;
+        Ok(Value::String(encoding::base64_encode(&bytes)))
+    }
+
+    fn builtin_base64_decode(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        let text = Self::expect_string(&args[0], "base64_decode")?;
+        let bytes = encoding::base64_decode(&text)
+            .map_err(|err| RuntimeError::ArgumentError(format!("base64_decode: {err}")))?;
+        bytes_to_utf8(bytes, "base64_decode")
+    }
+
+    fn builtin_base64_decode_bytes(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        let text = Self::expect_string(&args[0], "base64_decode_bytes")?;
+        let bytes = encoding::base64_decode(&text)
+            .map_err(|err| RuntimeError::ArgumentError(format!("base64_decode_bytes: {err}")))?;
+        Ok(bytes_to_array(bytes))
+    }
+
+    fn builtin_hex_encode(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        let bytes = expect_bytes(&args[0], "hex_encode")?;
+        Ok(Value::String(encoding::hex_encode(&bytes)))
+    }
+
+    fn builtin_hex_decode(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        let text = Self::expect_string(&args[0], "hex_decode")?;
+        let bytes = encoding::hex_decode(&text)
+            .map_err(|err| RuntimeError::ArgumentError(format!("hex_decode: {err}")))?;
+        bytes_to_utf8(bytes, "hex_decode")
+    }
+
+    fn builtin_hex_decode_bytes(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        let text = Self::expect_string(&args[0], "hex_decode_bytes")?;
+        let bytes = encoding::hex_decode(&text)
+            .map_err(|err| RuntimeError::ArgumentError(format!("hex_decode_bytes: {err}")))?;
+        Ok(bytes_to_array(bytes))
+    }
+
+    fn builtin_time_now_ms(&mut self, _args: &[Value]) -> Result<Value, RuntimeError> {
+        Ok(Value::Int((self.clock)()))
+    }
+
+    fn builtin_time_format(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        let epoch_ms = args[0].as_int().ok_or_else(|| {
+            RuntimeError::TypeError(format!(
+                "time_format expects epoch milliseconds as int, got {}",
+                args[0].type_name()
+            ))
+        })?;
+        let pattern = Self::expect_string(&args[1], "time_format pattern")?;
+        time_format::format_epoch_ms(epoch_ms, &pattern)
+            .map(Value::String)
+            .map_err(|err| RuntimeError::ArgumentError(format!("time_format: {err}")))
+    }
+
+    fn builtin_time_parse(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        let text = Self::expect_string(&args[0], "time_parse input")?;
+        let pattern = Self::expect_string(&args[1], "time_parse pattern")?;
+        time_format::parse_epoch_ms(&text, &pattern)
+            .map(Value::Int)
+            .map_err(|err| RuntimeError::ArgumentError(format!("time_parse: {err}")))
+    }
+
+    fn builtin_assert(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        if args[0].is_truthy() {
+            return Ok(Value::Null);
+        }
+        let message = match args.get(1) {
+            Some(message) => format_stdout_value(message),
+            None => format!("expected a truthy value, got {}", describe_value(&args[0])),
+        };
+        Err(self.assertion_failed(message))
+    }
+
+    fn builtin_assert_eq(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        if args[0] == args[1] {
+            return Ok(Value::Null);
+        }
+        let mut message = format!(
+            "{} != {}",
+            describe_value(&args[0]),
+            describe_value(&args[1])
+        );
+        if let Some(note) = args.get(2) {
+            message = format!("{}: {message}", format_stdout_value(note));
+        }
+        Err(self.assertion_failed(message))
+    }
+
+    /// The script functions below the assert builtin become the trace.
+    fn assertion_failed(&self, message: String) -> RuntimeError {
+        RuntimeError::AssertionFailed {
+            message,
+            stack: self.script_frames(),
+        }
+    }
+
+    fn builtin_log_info(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        if let Some((function, message)) = self.log_record("info", &args[0]) {
+            tracing::info!(target: SCRIPT_LOG_TARGET, function = %function, "{message}");
+        }
+        Ok(Value::Null)
+    }
+
+    fn builtin_log_warn(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        if let Some((function, message)) = self.log_record("warn", &args[0]) {
+            tracing::warn!(target: SCRIPT_LOG_TARGET, function = %function, "{message}");
+        }
+        Ok(Value::Null)
+    }
+
+    fn builtin_log_error(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        if let Some((function, message)) = self.log_record("error", &args[0]) {
+            tracing::error!(target: SCRIPT_LOG_TARGET, function = %function, "{message}");
+        }
+        Ok(Value::Null)
+    }
+
+    /// While output is captured the line is buffered like printed output;
+    /// otherwise returns the calling function and message for a tracing event.
+    fn log_record(&mut self, level: &str, value: &Value) -> Option<(String, String)> {
+        let message = format_stdout_value(value);
+        if let Some(buffer) = self.captured_output.as_mut() {
+            buffer.push_str(&format!("[{level}] {message}\n"));
+            return None;
+        }
+        let function = self
+            .script_frames()
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| "<main>".to_string());
+        Some((function, message))
+    }
+
+    /// Names of the script functions below the running builtin, innermost
+    /// first. A `main` synthesized from top-level code is reported as
+    /// `<main>`.
+    fn script_frames(&self) -> Vec<String> {
+        let callers = self.call_stack.len().saturating_sub(1);
+        self.call_stack[..callers]
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(depth, name)| {
+                if depth == 0 && self.implicit_main && name == "main" {
+                    "<main>".to_string()
+                } else {
+                    name.clone()
+                }
+            })
+            .collect()
+    }
+
     fn builtin_io_stdout_writeln(&mut self, args: &[Value]) -> Result<Value, RuntimeError> {
+        if let Some(buffer) = self.captured_output.as_mut() {
+            buffer.push_str(&args[0].to_string());
+            buffer.push('\n');
+            return Ok(Value::Null);
+        }
         platform::println(&args[0].to_string())
             .map_err(|e| RuntimeError::IoError(e.to_string()))?;
         Ok(Value::Null)
@@ -3692,6 +4209,7 @@ impl Interpreter {
                 params,
                 body,
                 closure,
+                origin,
             } => {
                 if !name.is_empty() && !self.promoted_functions.contains(&name) {
                     let entry = self.hot_counts.entry(name.clone()).or_insert(0);
@@ -3724,6 +4242,10 @@ impl Interpreter {
                 }
 
                 self.call_stack.push(name.clone());
+                let caller_origin = match origin {
+                    Some(origin) => self.current_origin.replace(origin),
+                    None => self.current_origin.clone(),
+                };
 
                 // Create new scope with closure and parameters
                 if closure.is_empty() {
@@ -3732,6 +4254,8 @@ impl Interpreter {
                     self.push_scope_with_closure(&closure);
                 }
 
+                self.slot_frames.push(SlotFrame::new(self.locals.len()));
+
                 // Bind parameters to arguments
                 for (param, arg) in params.iter().zip(args.iter()) {
                     self.define_variable(param.clone(), arg.clone(), true);
@@ -3747,15 +4271,19 @@ impl Interpreter {
                             break;
                         }
                         Err(e) => {
+                            self.slot_frames.pop();
                             self.pop_scope();
                             self.call_stack.pop();
+                            self.current_origin = caller_origin;
                             return Err(e);
                         }
                     }
                 }
 
+                self.slot_frames.pop();
                 self.pop_scope();
                 self.call_stack.pop();
+                self.current_origin = caller_origin;
                 Ok(result)
             }
 
@@ -3795,9 +4323,59 @@ impl Interpreter {
         }
     }
 
-    fn assign_variable(&mut self, name: &str, value: Value) -> Result<Value, RuntimeError> {
-        for scope in self.locals.iter_mut().rev() {
-            if let Some(entry) = scope.get_mut(name) {
+    /// Slot assigned to `node` in the current call frame, if slot storage is
+    /// enabled and there is a frame to store it in.
+    fn frame_slot(&self, node: NodeId) -> Option<u32> {
+        if !self.slot_locals || self.slot_frames.is_empty() {
+            return None;
+        }
+        self.slots.slot(node)
+    }
+
+    fn define_local(
+        &mut self,
+        decl: NodeId,
+        name: impl Into<String>,
+        value: Value,
+        mutable: bool,
+    ) {
+        match self.frame_slot(decl) {
+            Some(slot) => {
+                let entry = VariableEntry { value, mutable };
+                if let Some(frame) = self.slot_frames.last_mut() {
+                    frame.set(slot, name.into(), entry);
+                }
+            }
+            None => self.define_variable(name, value, mutable),
+        }
+    }
+
+    fn read_local(&mut self, site: NodeId, name: &str) -> Option<Value> {
+        if let Some(slot) = self.frame_slot(site) {
+            // An empty slot means the read runs before the declaration (for
+            // example `let x = x + 1` shadowing a parameter); the name-based
+            // environment still has the right answer.
+            if let Some(entry) = self.slot_frames.last().and_then(|frame| frame.get(slot)) {
+                self.lookup_stats.slot_hits += 1;
+                return Some(entry.value.clone());
+            }
+        }
+        self.lookup_stats.name_lookups += 1;
+        self.get_variable(name)
+    }
+
+    fn assign_local(
+        &mut self,
+        site: NodeId,
+        name: &str,
+        value: Value,
+    ) -> Result<Value, RuntimeError> {
+        if let Some(slot) = self.frame_slot(site) {
+            if let Some(entry) = self
+                .slot_frames
+                .last_mut()
+                .and_then(|frame| frame.get_mut(slot))
+            {
                 if !entry.mutable {
                     return Err(RuntimeError::TypeError(format!(
                         "Cannot assign to immutable variable '{}'",
@@ -3808,6 +4386,20 @@ impl Interpreter {
                 return Ok(value);
             }
         }
+        self.assign_variable(name, value)
+    }
+
+    fn assign_variable(&mut self, name: &str, value: Value) -> Result<Value, RuntimeError> {
+        if let Some(entry) = self.find_local_mut(name) {
+            if !entry.mutable {
+                return Err(RuntimeError::TypeError(format!(
+                    "Cannot assign to immutable variable '{}'",
+                    name
+                )));
+            }
+            entry.value = value.clone();
+            return Ok(value);
+        }
 
         if let Some(entry) = self.globals.get_mut(name) {
             if !entry.mutable {
@@ -3824,20 +4416,59 @@ impl Interpreter {
     }
 
     fn get_variable(&self, name: &str) -> Option<Value> {
-        for scope in self.locals.iter().rev() {
+        if let Some(entry) = self.find_local(name) {
+            return Some(entry.value.clone());
+        }
+        self.globals.get(name).map(|entry| entry.value.clone())
+    }
+
+    /// Walk local scopes innermost-first, checking each call's slot frame
+    /// just before that call's own scope.
+    fn find_local(&self, name: &str) -> Option<&VariableEntry> {
+        let mut frames = self.slot_frames.iter().rev().peekable();
+        for (depth, scope) in self.locals.iter().enumerate().rev() {
+            while let Some(frame) = frames.next_if(|frame| frame.base_depth > depth) {
+                if let Some(entry) = frame.find(name) {
+                    return Some(entry);
+                }
+            }
             if let Some(entry) = scope.get(name) {
-                return Some(entry.value.clone());
+                return Some(entry);
             }
         }
-        self.globals.get(name).map(|entry| entry.value.clone())
+        frames.find_map(|frame| frame.find(name))
+    }
+
+    fn find_local_mut(&mut self, name: &str) -> Option<&mut VariableEntry> {
+        let mut frames = self.slot_frames.iter_mut().rev().peekable();
+        for (depth, scope) in self.locals.iter_mut().enumerate().rev() {
+            while let Some(frame) = frames.next_if(|frame| frame.base_depth > depth) {
+                if let Some(entry) = frame.find_mut(name) {
+                    return Some(entry);
+                }
+            }
+            if let Some(entry) = scope.get_mut(name) {
+                return Some(entry);
+            }
+        }
+        frames.find_map(|frame| frame.find_mut(name))
     }
 
     fn capture_environment(&self) -> Environment {
         let mut snapshot = HashMap::new();
-        for scope in &self.locals {
+        for (depth, scope) in self.locals.iter().enumerate() {
             for (name, entry) in scope {
                 snapshot.insert(name.clone(), entry.clone());
             }
+            for frame in self
+                .slot_frames
+                .iter()
+                .filter(|frame| frame.base_depth == depth + 1)
+            {
+                for (name, entry) in frame.entries.iter().flatten() {
+                    snapshot.insert(name.clone(), entry.clone());
+                }
+            }
         }
         for (name, entry) in &self.globals {
             snapshot
@@ -3896,6 +4527,18 @@ fn write_values_to_stdout(args: &[Value], newline: bool) -> io::Result<()> {
     stdout.flush()
 }
 
+/// Render a value for an assertion message, quoting strings.
+fn describe_value(value: &Value) -> String {
+    match value {
+        Value::String(text) => format!("{text:?}"),
+        Value::Array(items) => {
+            let parts: Vec<String> = items.iter().map(describe_value).collect();
+            format!("[{}]", parts.join(", "))
+        }
+        other => other.to_string(),
+    }
+}
+
 fn format_stdout_value(value: &Value) -> String {
     match value {
         Value::Array(items) => {
@@ -3909,6 +4552,44 @@ fn format_stdout_value(value: &Value) -> String {
     }
 }
 
+/// Binary input for the encoding builtins: a string's UTF-8 bytes, or an
+/// array of integers in `0..=255`.
+fn expect_bytes(value: &Value, name: &str) -> Result<Vec<u8>, RuntimeError> {
+    match value {
+        Value::String(text) => Ok(text.as_bytes().to_vec()),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| match item {
+                Value::Int(byte @ 0..=255) => Ok(*byte as u8),
+                other => Err(RuntimeError::TypeError(format!(
+                    "{name} expects bytes in 0..=255, got {other}"
+                ))),
+            })
+            .collect(),
+        other => Err(RuntimeError::TypeError(format!(
+            "{name} expects string or byte array, got {}",
+            other.type_name()
+        ))),
+    }
+}
+
+fn bytes_to_utf8(bytes: Vec<u8>, name: &str) -> Result<Value, RuntimeError> {
+    String::from_utf8(bytes).map(Value::String).map_err(|_| {
+        RuntimeError::ArgumentError(format!(
+            "{name}: decoded data is not valid UTF-8; use {name}_bytes"
+        ))
+    })
+}
+
+fn bytes_to_array(bytes: Vec<u8>) -> Value {
+    Value::Array(
+        bytes
+            .into_iter()
+            .map(|byte| Value::Int(byte as i64))
+            .collect(),
+    )
+}
+
 fn expect_number(value: &Value, name: &str) -> Result<f64, RuntimeError> {
     value.to_number().ok_or_else(|| {
         RuntimeError::TypeError(format!(
@@ -4144,6 +4825,31 @@ mod tests {
         assert!(!Value::String("".to_string()).is_truthy());
     }
 
+    #[test]
+    fn test_typed_value_accessors() {
+        assert_eq!(Value::Int(11).as_int(), Some(11));
+        assert_eq!(Value::Float(2.5).as_float(), Some(2.5));
+        assert_eq!(Value::Int(11).as_float(), None);
+        assert_eq!(Value::Float(2.5).as_int(), None);
+        assert_eq!(Value::String("11".into()).as_int(), None);
+    }
+
+    #[test]
+    fn test_arithmetic_results_compare_by_value() {
+        let mut interpreter = Interpreter::new();
+        let mut eval = |source: &str| {
+            let tokens = Tokenizer::new(source).tokenize().expect("tokenize");
+            let expr = Parser::new(tokens)
+                .parse_expression_only()
+                .expect("parse expression");
+            interpreter.eval_expression(&expr).expect("evaluate")
+        };
+        assert_eq!(eval("5 + 6"), Value::Int(11));
+        assert_eq!(eval("2 * 3 + 4").as_int(), Some(10));
+        assert_eq!(eval("7.5 / 2.5").as_float(), Some(3.0));
+        assert_eq!(eval("1 + 0.5"), Value::Float(1.5));
+    }
+
     #[test]
     fn test_interpreter_creation() {
         let interpreter = Interpreter::new();
@@ -4400,6 +5106,45 @@ mod tests {
         assert_eq!(result, Value::Int(7));
     }
 
+    #[test]
+    fn test_slot_locals_skip_name_lookups() {
+        let source = r#"
+            fn sum_to(n) {
+                let total = 0;
+                let i = 0;
+                while i < n {
+                    total = total + i;
+                    i = i + 1;
+                }
+                return total;
+            }
+            sum_to(200);
+        "#;
+        let run = |slot_locals: bool| {
+            let mut tokenizer = Tokenizer::new(source);
+            let tokens = tokenizer.tokenize().expect("tokenize loop script");
+            let mut parser = Parser::new(tokens);
+            let program = parser.parse().expect("parse loop script");
+            let mut interpreter = Interpreter::new();
+            interpreter.set_slot_locals(slot_locals);
+            let result = interpreter
+                .eval_program(&program)
+                .expect("evaluate loop script")
+                .expect("loop script should return value");
+            (result, interpreter.lookup_stats())
+        };
+
+        let (slotted, slot_stats) = run(true);
+        let (named, name_stats) = run(false);
+        assert_eq!(slotted, Value::Int(19900));
+        assert_eq!(slotted, named);
+        assert_eq!(name_stats.slot_hits, 0);
+        // `total` and `i` are read 200+ times each; only `n` and the call
+        // to `sum_to` still go through the scope chain.
+        assert!(slot_stats.slot_hits >= 600, "{slot_stats:?}");
+        assert!(slot_stats.name_lookups * 2 < name_stats.name_lookups, "{slot_stats:?}");
+    }
+
     #[test]
     fn test_file_roundtrip() {
         let mut interpreter = Interpreter::new();
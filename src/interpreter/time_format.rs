@@ -0,0 +1,57 @@
+//=============================================
+// solvra_script/interpreter/time_format.rs
+//=============================================
+// Goal: Timestamp formatting for the time builtins
+// Objective: Render and parse epoch milliseconds with strftime patterns,
+//            reporting malformed patterns instead of panicking
+//=============================================
+
+use std::fmt::Write;
+
+use chrono::format::{self, Item, Parsed, StrftimeItems};
+use chrono::{DateTime, NaiveTime};
+
+fn pattern_items(pattern: &str) -> Result<Vec<Item<'_>>, String> {
+    let items: Vec<Item<'_>> = StrftimeItems::new(pattern).collect();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(format!("invalid time pattern '{pattern}'"));
+    }
+    Ok(items)
+}
+
+/// Format `epoch_ms` (milliseconds since the Unix epoch) in UTC.
+pub(super) fn format_epoch_ms(epoch_ms: i64, pattern: &str) -> Result<String, String> {
+    let items = pattern_items(pattern)?;
+    let moment = DateTime::from_timestamp_millis(epoch_ms)
+        .ok_or_else(|| format!("timestamp {epoch_ms} is out of range"))?;
+    let mut out = String::new();
+    write!(out, "{}", moment.format_with_items(items.iter()))
+        .map_err(|_| format!("time pattern '{pattern}' cannot format {epoch_ms}"))?;
+    Ok(out)
+}
+
+/// Parse `text` with `pattern` into epoch milliseconds. The date is required;
+/// a pattern without a time of day means midnight, and input without an
+/// offset is read as UTC.
+pub(super) fn parse_epoch_ms(text: &str, pattern: &str) -> Result<i64, String> {
+    let items = pattern_items(pattern)?;
+    let mut parsed = Parsed::new();
+    format::parse(&mut parsed, text, items.iter())
+        .map_err(|err| format!("cannot parse '{text}' as '{pattern}': {err}"))?;
+    let invalid = |err: format::ParseError| format!("cannot parse '{text}' as '{pattern}': {err}");
+    let date = parsed.to_naive_date().map_err(invalid)?;
+    let time = match parsed.to_naive_time() {
+        Ok(time) => time,
+        Err(_) if parsed.hour_mod_12().is_none() => NaiveTime::MIN,
+        Err(err) => return Err(invalid(err)),
+    };
+    let offset_ms = parsed
+        .to_fixed_offset()
+        .map(|offset| i64::from(offset.local_minus_utc()) * 1000)
+        .unwrap_or(0);
+    Ok(date.and_time(time).and_utc().timestamp_millis() - offset_ms)
+}
+
+//=============================================
+// End of file
+//=============================================
@@ -7,10 +7,11 @@
 //==============================================
 
 use crate::ir::ops::{Instruction, Terminator};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Identifier assigned to a [`BasicBlock`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct BlockId(pub(crate) u32);
 
 impl BlockId {
@@ -26,7 +27,7 @@ impl fmt::Display for BlockId {
 }
 
 /// SSA block storing instruction order and CFG state.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BasicBlock {
     pub id: BlockId,
     pub name: Option<String>,
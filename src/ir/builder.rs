@@ -10,7 +10,7 @@
 
 use crate::ir::block::BlockId;
 use crate::ir::function::FunctionIR;
-use crate::ir::ops::{IrOpcode, Terminator, TerminatorKind};
+use crate::ir::ops::{BranchHint, IrOpcode, Terminator, TerminatorKind};
 use crate::ir::types::{Ownership, TypeDescriptor};
 use crate::ir::value::{ConstantValue, ValueId};
 /// Errors surfaced when manipulating blocks.
@@ -138,6 +138,23 @@ impl<'a> FunctionBuilder<'a> {
         })
     }
 
+    /// Like [`emit_branch`](Self::emit_branch), also recording which way the
+    /// branch is expected to go.
+    pub fn emit_hinted_branch(
+        &mut self,
+        condition: ValueId,
+        then_block: BlockId,
+        else_block: BlockId,
+        hint: Option<BranchHint>,
+    ) -> Result<(), BlockBuilderError> {
+        let block = self.current_block;
+        self.emit_branch(condition, then_block, else_block)?;
+        if let Some(terminator) = &mut self.function.block_mut(block).terminator {
+            terminator.hint = hint;
+        }
+        Ok(())
+    }
+
     fn finish_with(&mut self, kind: TerminatorKind) -> Result<(), BlockBuilderError> {
         let block = self.current_block;
         self.ensure_block(block)?;
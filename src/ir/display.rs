@@ -10,7 +10,7 @@
 
 use crate::ir::block::BasicBlock;
 use crate::ir::function::FunctionIR;
-use crate::ir::ops::{Instruction, TerminatorKind};
+use crate::ir::ops::{BranchHint, Instruction, TerminatorKind};
 use std::fmt;
 
 pub struct IrFormatter<'a> {
@@ -71,7 +71,12 @@ fn render_block(
                 then_block,
                 else_block,
             } => {
-                writeln!(f, "    br {}, {}, {}", condition, then_block, else_block)?;
+                write!(f, "    br {}, {}, {}", condition, then_block, else_block)?;
+                match terminator.hint {
+                    Some(BranchHint::Likely) => writeln!(f, " [likely]")?,
+                    Some(BranchHint::Unlikely) => writeln!(f, " [unlikely]")?,
+                    None => writeln!(f)?,
+                }
             }
         }
     }
@@ -111,6 +116,109 @@ impl FunctionIR {
     }
 }
 
+/// Renders a function's control-flow graph in Graphviz DOT. Nodes are
+/// labelled with block names; branch edges carry their condition, negated on
+/// the else edge.
+pub struct CfgFormatter<'a> {
+    function: &'a FunctionIR,
+}
+
+impl<'a> CfgFormatter<'a> {
+    pub fn new(function: &'a FunctionIR) -> Self {
+        Self { function }
+    }
+}
+
+impl<'a> fmt::Display for CfgFormatter<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph \"{}\" {{", escape_dot(&self.function.name))?;
+        writeln!(f, "    node [shape=box];")?;
+        for block in &self.function.blocks {
+            let label = match &block.name {
+                Some(name) => escape_dot(name),
+                None => block.id.to_string(),
+            };
+            writeln!(f, "    {} [label=\"{}\"];", block.id, label)?;
+        }
+        for block in &self.function.blocks {
+            match block.terminator.as_ref().map(|term| &term.kind) {
+                Some(TerminatorKind::Jump { target }) => {
+                    writeln!(f, "    {} -> {};", block.id, target)?;
+                }
+                Some(TerminatorKind::Branch {
+                    condition,
+                    then_block,
+                    else_block,
+                }) => {
+                    writeln!(
+                        f,
+                        "    {} -> {} [label=\"{}\"];",
+                        block.id, then_block, condition
+                    )?;
+                    writeln!(
+                        f,
+                        "    {} -> {} [label=\"!{}\"];",
+                        block.id, else_block, condition
+                    )?;
+                }
+                Some(TerminatorKind::Return { .. }) | None => {}
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::builder::FunctionBuilder;
+    use crate::ir::function::FunctionSignature;
+    use crate::ir::ir::SolvraIrModule;
+    use crate::ir::types::{PrimitiveType, TypeDescriptor};
+
+    #[test]
+    fn branchy_function_renders_nodes_and_conditional_edges() {
+        let int = TypeDescriptor::primitive(PrimitiveType::Int64);
+        let flag = TypeDescriptor::primitive(PrimitiveType::Bool);
+        let mut module = SolvraIrModule::new();
+        let id = module.add_function("pick", FunctionSignature::new(vec![flag, int.clone()], int));
+        let mut builder = FunctionBuilder::new(module.function_mut(id).unwrap());
+        let (cond, n) = (builder.parameters()[0], builder.parameters()[1]);
+        let then_block = builder.append_block("then");
+        let else_block = builder.append_block("else");
+        let join = builder.append_block("join");
+        builder.emit_branch(cond, then_block, else_block).unwrap();
+        builder.position_at_end(then_block).unwrap();
+        builder.emit_jump(join).unwrap();
+        builder.position_at_end(else_block).unwrap();
+        builder.emit_jump(join).unwrap();
+        builder.position_at_end(join).unwrap();
+        builder.emit_return(Some(n)).unwrap();
+        builder.finalize();
+
+        let dot = CfgFormatter::new(module.function(id).unwrap()).to_string();
+        let expected = format!(
+            r#"digraph "pick" {{
+    node [shape=box];
+    bb0 [label="entry"];
+    bb1 [label="then"];
+    bb2 [label="else"];
+    bb3 [label="join"];
+    bb0 -> bb1 [label="{cond}"];
+    bb0 -> bb2 [label="!{cond}"];
+    bb1 -> bb3;
+    bb2 -> bb3;
+}}
+"#
+        );
+        assert_eq!(dot, expected);
+    }
+}
+
 //==============================================
 // End of file
 //==============================================
@@ -10,13 +10,14 @@
 
 use crate::ir::block::{BasicBlock, BlockId};
 use crate::ir::display::IrFormatter;
-use crate::ir::ops::{Instruction, IrOpcode, Terminator};
+use crate::ir::ops::{Instruction, IrOpcode, Terminator, TerminatorKind};
 use crate::ir::types::{BorrowKind, Ownership, PrimitiveType, TypeDescriptor};
 use crate::ir::value::{ConstantValue, InstructionId, ValueData, ValueId, ValueKind};
+use serde::{Deserialize, Serialize};
 use solvra_core::jit::tier0_codegen::{Tier0Function, Tier0FunctionId};
 
 /// Identifier assigned to functions inside a module.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct FunctionId(pub(crate) u32);
 
 impl FunctionId {
@@ -38,7 +39,7 @@ impl From<FunctionId> for u32 {
 }
 
 /// Function calling contract.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FunctionSignature {
     pub params: Vec<TypeDescriptor>,
     pub result: TypeDescriptor,
@@ -51,17 +52,17 @@ impl FunctionSignature {
 }
 
 /// Description of a call destination.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CallTarget {
     Internal(FunctionId),
     External { symbol: String },
-    Intrinsic { name: &'static str },
+    Intrinsic { name: String },
     Dynamic,
     Method { name: String },
 }
 
 /// Stack slot metadata consumed by GC + deoptimizer.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StackSlot {
     pub index: usize,
     pub ty: TypeDescriptor,
@@ -69,7 +70,7 @@ pub struct StackSlot {
 }
 
 /// Stack map stored per safepoint.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StackMap {
     pub id: u32,
     pub block: BlockId,
@@ -77,7 +78,7 @@ pub struct StackMap {
 }
 
 /// Deoptimization metadata associated with guards.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeoptRecord {
     pub stack_map: u32,
     pub resume_block: BlockId,
@@ -85,7 +86,7 @@ pub struct DeoptRecord {
 }
 
 /// Complete SSA function.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FunctionIR {
     pub id: FunctionId,
     pub name: String,
@@ -139,6 +140,10 @@ impl FunctionIR {
         &self.values
     }
 
+    pub(crate) fn value_mut(&mut self, value: ValueId) -> &mut ValueData {
+        &mut self.values[value.index()]
+    }
+
     pub fn block(&self, block: BlockId) -> &BasicBlock {
         &self.blocks[block.index()]
     }
@@ -182,6 +187,55 @@ impl FunctionIR {
         block.terminator = Some(terminator);
     }
 
+    /// Lay blocks out in `order`, which must list every block once, and
+    /// renumber them so ids keep matching positions. Edges, terminators,
+    /// stack maps, and deopt records follow the new ids.
+    pub fn reorder_blocks(&mut self, order: &[BlockId]) {
+        assert_eq!(
+            order.len(),
+            self.blocks.len(),
+            "order must list every block"
+        );
+        let mut renumbered = vec![BlockId(0); self.blocks.len()];
+        for (position, block) in order.iter().enumerate() {
+            renumbered[block.index()] = BlockId(position as u32);
+        }
+        let remap = |block: &mut BlockId| *block = renumbered[block.index()];
+
+        let mut blocks: Vec<Option<BasicBlock>> = std::mem::take(&mut self.blocks)
+            .into_iter()
+            .map(Some)
+            .collect();
+        self.blocks = order
+            .iter()
+            .map(|block| blocks[block.index()].take().expect("block listed once"))
+            .collect();
+        for block in &mut self.blocks {
+            remap(&mut block.id);
+            block.predecessors.iter_mut().for_each(remap);
+            block.successors.iter_mut().for_each(remap);
+            match block.terminator.as_mut().map(|term| &mut term.kind) {
+                Some(TerminatorKind::Jump { target }) => remap(target),
+                Some(TerminatorKind::Branch {
+                    then_block,
+                    else_block,
+                    ..
+                }) => {
+                    remap(then_block);
+                    remap(else_block);
+                }
+                Some(TerminatorKind::Return { .. }) | None => {}
+            }
+        }
+        remap(&mut self.entry_block);
+        for stack_map in &mut self.stack_maps {
+            remap(&mut stack_map.block);
+        }
+        for deopt in &mut self.deopts {
+            remap(&mut deopt.resume_block);
+        }
+    }
+
     pub fn alloc_stack_map(&mut self, block: BlockId, slots: Vec<StackSlot>) -> u32 {
         let id = self.stack_maps.len() as u32;
         self.stack_maps.push(StackMap { id, block, slots });
@@ -200,6 +254,21 @@ impl FunctionIR {
         self.alloc_value(ty, Ownership::Owned, ValueKind::Constant(value), None)
     }
 
+    /// Allocate a fresh value shaped like `data`, which may belong to another
+    /// function. Used when copying instructions between functions.
+    pub(crate) fn import_value(&mut self, data: &ValueData) -> ValueId {
+        let kind = match &data.kind {
+            ValueKind::Instruction(_) => ValueKind::Instruction(InstructionId(self.next_value)),
+            other => other.clone(),
+        };
+        self.alloc_value(
+            data.ty.clone(),
+            data.ownership,
+            kind,
+            data.debug_name.clone(),
+        )
+    }
+
     fn alloc_value(
         &mut self,
         ty: TypeDescriptor,
@@ -0,0 +1,531 @@
+//==============================================
+// File: solvra_script/ir/inline.rs
+// Author: Solvra Systems
+// License: Duality Public License (DPL v1.0)
+// Goal: Inline small internal calls in SolvraIR
+// Objective: Splice the bodies of small, non-recursive callees into their callers with fresh SSA values
+//==============================================
+
+use std::collections::{HashMap, HashSet};
+
+use crate::ir::block::BlockId;
+use crate::ir::function::{CallTarget, FunctionIR, FunctionId};
+use crate::ir::ir::SolvraIrModule;
+use crate::ir::ops::{Instruction, IrOpcode, Terminator, TerminatorKind};
+use crate::ir::value::{ConstantValue, ValueId, ValueKind};
+
+/// Replace direct internal calls to small functions with copies of their
+/// bodies and return the number of call sites replaced.
+///
+/// A callee is inlined when it has at most `threshold` instructions, cannot
+/// reach itself through the call graph, and its address never escapes, i.e.
+/// nothing names it except direct internal calls. Calls exposed by an inlined
+/// body are considered in turn.
+pub fn inline_small_functions(module: &mut SolvraIrModule, threshold: usize) -> usize {
+    inline(module, threshold, usize::MAX)
+}
+
+/// Like [`inline_small_functions`] with `budget` as the threshold, except
+/// that each caller absorbs at most `budget` copied instructions in total, so
+/// chains of small callees cannot blow a caller up.
+pub fn inline_calls(module: &mut SolvraIrModule, budget: usize) -> usize {
+    inline(module, budget, budget)
+}
+
+fn inline(module: &mut SolvraIrModule, threshold: usize, budget: usize) -> usize {
+    let originals = module.functions().to_vec();
+    let candidates: HashSet<FunctionId> = originals
+        .iter()
+        .filter(|function| is_inlinable(function, &originals, threshold))
+        .map(|function| function.id)
+        .collect();
+    module
+        .functions_mut()
+        .iter_mut()
+        .map(|caller| inline_into(caller, &originals, &candidates, budget))
+        .sum()
+}
+
+fn instruction_count(function: &FunctionIR) -> usize {
+    function
+        .blocks
+        .iter()
+        .map(|block| block.instructions.len())
+        .sum()
+}
+
+fn is_inlinable(function: &FunctionIR, module: &[FunctionIR], threshold: usize) -> bool {
+    instruction_count(function) <= threshold
+        && function.stack_maps.is_empty()
+        && function.deopts.is_empty()
+        && function.block(function.entry_block).predecessors.is_empty()
+        && function
+            .blocks
+            .iter()
+            .all(|block| block.terminator.is_some())
+        && function.blocks.iter().any(|block| {
+            matches!(
+                block.terminator.as_ref().map(|term| &term.kind),
+                Some(TerminatorKind::Return { .. })
+            )
+        })
+        && !function.blocks.iter().any(|block| {
+            block
+                .instructions
+                .iter()
+                .any(|inst| matches!(inst.opcode, IrOpcode::StackMapRef(_)))
+        })
+        && !is_recursive(function.id, module)
+        && !escapes(function, module)
+}
+
+fn internal_callees(function: &FunctionIR) -> impl Iterator<Item = FunctionId> + '_ {
+    function.blocks.iter().flat_map(|block| {
+        block
+            .instructions
+            .iter()
+            .filter_map(|inst| match &inst.opcode {
+                IrOpcode::Call {
+                    target: CallTarget::Internal(callee),
+                } => Some(*callee),
+                _ => None,
+            })
+    })
+}
+
+/// Whether `id` can reach itself, directly or through other functions.
+fn is_recursive(id: FunctionId, module: &[FunctionIR]) -> bool {
+    let mut visited = HashSet::new();
+    let mut pending: Vec<FunctionId> = module
+        .get(id.index())
+        .map(|function| internal_callees(function).collect())
+        .unwrap_or_default();
+    while let Some(next) = pending.pop() {
+        if next == id {
+            return true;
+        }
+        if visited.insert(next)
+            && let Some(function) = module.get(next.index())
+        {
+            pending.extend(internal_callees(function));
+        }
+    }
+    false
+}
+
+/// Whether `function` may be reached other than through a direct internal
+/// call: by name through a string constant, which dynamic calls resolve, or
+/// through an external call of the same symbol.
+fn escapes(function: &FunctionIR, module: &[FunctionIR]) -> bool {
+    module.iter().any(|other| {
+        let named_by_constant = other.values().iter().any(|value| {
+            matches!(&value.kind, ValueKind::Constant(ConstantValue::String(name)) if *name == function.name)
+        });
+        let named_by_external = other.blocks.iter().any(|block| {
+            block.instructions.iter().any(|inst| {
+                matches!(
+                    &inst.opcode,
+                    IrOpcode::Call { target: CallTarget::External { symbol } } if *symbol == function.name
+                )
+            })
+        });
+        named_by_constant || named_by_external
+    })
+}
+
+fn inline_into(
+    caller: &mut FunctionIR,
+    module: &[FunctionIR],
+    candidates: &HashSet<FunctionId>,
+    mut budget: usize,
+) -> usize {
+    let mut inlined = 0;
+    let mut block_index = 0;
+    while block_index < caller.blocks.len() {
+        let block = caller.blocks[block_index].id;
+        let site = caller.blocks[block_index]
+            .instructions
+            .iter()
+            .enumerate()
+            .find_map(|(position, inst)| match &inst.opcode {
+                IrOpcode::Call {
+                    target: CallTarget::Internal(callee),
+                } if *callee != caller.id && candidates.contains(callee) => module
+                    .get(callee.index())
+                    .filter(|callee| {
+                        callee.parameters.len() == inst.operands.len()
+                            && instruction_count(callee) <= budget
+                    })
+                    .map(|callee| (position, callee)),
+                _ => None,
+            });
+        match site {
+            Some((position, callee)) => {
+                splice(caller, block, position, callee);
+                budget -= instruction_count(callee);
+                inlined += 1;
+            }
+            None => block_index += 1,
+        }
+    }
+    inlined
+}
+
+/// Replace the call at `position` in `block` with a copy of `callee`.
+///
+/// The instructions after the call move to a new continuation block. The
+/// call block jumps to the copied entry, and every copied return jumps to the
+/// continuation. A single return value replaces the call result directly;
+/// several are merged with a phi that keeps the call's result id.
+fn splice(caller: &mut FunctionIR, block: BlockId, position: usize, callee: &FunctionIR) {
+    let mut tail = caller.block_mut(block).instructions.split_off(position);
+    let call = tail.remove(0);
+
+    // Copies of the callee blocks, laid out ahead of the continuation.
+    let blocks: HashMap<BlockId, BlockId> = callee
+        .blocks
+        .iter()
+        .map(|callee_block| {
+            let name = callee_block.name.as_deref().unwrap_or("block");
+            let copy = caller.create_block(Some(format!("{}.{name}", callee.name)));
+            (callee_block.id, copy)
+        })
+        .collect();
+    let continuation = caller.create_block(Some(format!("{}.ret", callee.name)));
+    let terminator = caller.block_mut(block).terminator.take();
+    let successors = std::mem::take(&mut caller.block_mut(block).successors);
+    for successor in &successors {
+        for pred in &mut caller.block_mut(*successor).predecessors {
+            if *pred == block {
+                *pred = continuation;
+            }
+        }
+    }
+    {
+        let continuation = caller.block_mut(continuation);
+        continuation.instructions = tail;
+        continuation.terminator = terminator;
+        continuation.successors = successors;
+        continuation.sealed = true;
+    }
+
+    let mut values: HashMap<ValueId, ValueId> = callee
+        .parameters
+        .iter()
+        .copied()
+        .zip(call.operands.iter().copied())
+        .collect();
+    let mut map_value = |caller: &mut FunctionIR, value: ValueId| {
+        *values
+            .entry(value)
+            .or_insert_with(|| caller.import_value(&callee.values()[value.index()]))
+    };
+
+    let mut returns = Vec::new();
+    for callee_block in &callee.blocks {
+        let copy = blocks[&callee_block.id];
+        let mut instructions = Vec::with_capacity(callee_block.instructions.len());
+        for inst in &callee_block.instructions {
+            let mut opcode = inst.opcode.clone();
+            if let IrOpcode::Guard(guard) = &mut opcode {
+                guard.value = map_value(caller, guard.value);
+            }
+            let operands = inst
+                .operands
+                .iter()
+                .map(|operand| map_value(caller, *operand))
+                .collect();
+            let result = inst.result.map(|result| map_value(caller, result));
+            let mut copied = Instruction::new(opcode, operands, inst.ty.clone(), result);
+            copied.debug_name = inst.debug_name.clone();
+            instructions.push(copied);
+        }
+
+        let callee_terminator = callee_block.terminator.as_ref().expect("inlinable");
+        let kind = match &callee_terminator.kind {
+            TerminatorKind::Return { value } => {
+                let value = value.map(|value| map_value(caller, value));
+                returns.push((copy, value));
+                TerminatorKind::Jump {
+                    target: continuation,
+                }
+            }
+            TerminatorKind::Jump { target } => TerminatorKind::Jump {
+                target: blocks[target],
+            },
+            TerminatorKind::Branch {
+                condition,
+                then_block,
+                else_block,
+            } => TerminatorKind::Branch {
+                condition: map_value(caller, *condition),
+                then_block: blocks[then_block],
+                else_block: blocks[else_block],
+            },
+        };
+        let successors = match &kind {
+            TerminatorKind::Jump { target } => vec![*target],
+            TerminatorKind::Branch {
+                then_block,
+                else_block,
+                ..
+            } => vec![*then_block, *else_block],
+            TerminatorKind::Return { .. } => Vec::new(),
+        };
+
+        let copy_block = caller.block_mut(copy);
+        copy_block.instructions = instructions;
+        let mut terminator = Terminator::new(kind);
+        terminator.hint = callee_terminator.hint;
+        copy_block.terminator = Some(terminator);
+        copy_block.predecessors = callee_block
+            .predecessors
+            .iter()
+            .map(|pred| blocks[pred])
+            .collect();
+        copy_block.successors = successors;
+        copy_block.sealed = true;
+    }
+
+    let entry = blocks[&callee.entry_block];
+    caller.block_mut(entry).predecessors.insert(0, block);
+    caller.block_mut(block).successors = vec![entry];
+    caller.set_terminator(
+        block,
+        Terminator::new(TerminatorKind::Jump { target: entry }),
+    );
+    caller.block_mut(continuation).predecessors = returns.iter().map(|(block, _)| *block).collect();
+
+    let Some(result) = call.result else {
+        return;
+    };
+    let ty = call.ty.clone().unwrap_or_else(FunctionIR::void_type);
+    let mut returned: Vec<ValueId> = returns
+        .iter()
+        .map(|(_, value)| {
+            value.unwrap_or_else(|| caller.alloc_constant(ConstantValue::Null, ty.clone()))
+        })
+        .collect();
+    if returned.len() == 1 {
+        replace_uses(caller, result, returned.remove(0));
+    } else {
+        let mut phi = Instruction::new(IrOpcode::Phi, returned, call.ty, Some(result));
+        phi.debug_name = call.debug_name;
+        caller.block_mut(continuation).instructions.insert(0, phi);
+    }
+}
+
+fn replace_uses(function: &mut FunctionIR, from: ValueId, to: ValueId) {
+    let swap = |value: &mut ValueId| {
+        if *value == from {
+            *value = to;
+        }
+    };
+    for block in &mut function.blocks {
+        for inst in &mut block.instructions {
+            inst.operands.iter_mut().for_each(swap);
+            if let IrOpcode::Guard(guard) = &mut inst.opcode {
+                swap(&mut guard.value);
+            }
+        }
+        match block.terminator.as_mut().map(|term| &mut term.kind) {
+            Some(TerminatorKind::Return { value: Some(value) }) => swap(value),
+            Some(TerminatorKind::Branch { condition, .. }) => swap(condition),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::builder::FunctionBuilder;
+    use crate::ir::function::FunctionSignature;
+    use crate::ir::types::{Ownership, PrimitiveType, TypeDescriptor};
+    use crate::ir::verify::verify_function;
+
+    fn int() -> TypeDescriptor {
+        TypeDescriptor::primitive(PrimitiveType::Int64)
+    }
+
+    fn calls_to(function: &FunctionIR, callee: FunctionId) -> usize {
+        internal_callees(function)
+            .filter(|id| *id == callee)
+            .count()
+    }
+
+    /// `scale(x) = (x + 1) * 2 - x`, three instructions, and a `main` that
+    /// returns `scale(5) + 1`.
+    fn module_with_small_callee() -> (SolvraIrModule, FunctionId, FunctionId) {
+        let mut module = SolvraIrModule::new();
+        let scale = module.add_function("scale", FunctionSignature::new(vec![int()], int()));
+        let main = module.add_function("main", FunctionSignature::new(Vec::new(), int()));
+
+        let mut builder = FunctionBuilder::new(module.function_mut(scale).unwrap());
+        let x = builder.parameters()[0];
+        let one = builder.make_constant(ConstantValue::Int(1), int());
+        let two = builder.make_constant(ConstantValue::Int(2), int());
+        let sum = builder
+            .emit_value(IrOpcode::Add, vec![x, one], int(), Ownership::Owned, None)
+            .unwrap();
+        let doubled = builder
+            .emit_value(IrOpcode::Mul, vec![sum, two], int(), Ownership::Owned, None)
+            .unwrap();
+        let result = builder
+            .emit_value(
+                IrOpcode::Sub,
+                vec![doubled, x],
+                int(),
+                Ownership::Owned,
+                None,
+            )
+            .unwrap();
+        builder.emit_return(Some(result)).unwrap();
+        builder.finalize();
+
+        let mut builder = FunctionBuilder::new(module.function_mut(main).unwrap());
+        let five = builder.make_constant(ConstantValue::Int(5), int());
+        let one = builder.make_constant(ConstantValue::Int(1), int());
+        let call = builder
+            .emit_value(
+                IrOpcode::Call {
+                    target: CallTarget::Internal(scale),
+                },
+                vec![five],
+                int(),
+                Ownership::Owned,
+                None,
+            )
+            .unwrap();
+        let total = builder
+            .emit_value(
+                IrOpcode::Add,
+                vec![call, one],
+                int(),
+                Ownership::Owned,
+                None,
+            )
+            .unwrap();
+        builder.emit_return(Some(total)).unwrap();
+        builder.finalize();
+
+        (module, scale, main)
+    }
+
+    #[test]
+    fn call_to_three_instruction_function_is_inlined() {
+        let (mut module, scale, main) = module_with_small_callee();
+        assert_eq!(calls_to(module.function(main).unwrap(), scale), 1);
+
+        assert_eq!(inline_small_functions(&mut module, 3), 1);
+
+        let main = module.function(main).unwrap();
+        assert_eq!(calls_to(main, scale), 0);
+        verify_function(main).expect("inlined caller verifies");
+        let opcodes: Vec<&IrOpcode> = main
+            .blocks
+            .iter()
+            .flat_map(|block| block.instructions.iter().map(|inst| &inst.opcode))
+            .collect();
+        assert_eq!(
+            opcodes,
+            [
+                &IrOpcode::Add,
+                &IrOpcode::Mul,
+                &IrOpcode::Sub,
+                &IrOpcode::Add
+            ]
+        );
+    }
+
+    #[test]
+    fn callees_above_threshold_are_kept() {
+        let (mut module, scale, main) = module_with_small_callee();
+        assert_eq!(inline_small_functions(&mut module, 2), 0);
+        assert_eq!(calls_to(module.function(main).unwrap(), scale), 1);
+    }
+
+    /// `countdown(n) = countdown(n - 1)`.
+    fn add_countdown(module: &mut SolvraIrModule) -> FunctionId {
+        let countdown =
+            module.add_function("countdown", FunctionSignature::new(vec![int()], int()));
+        let mut builder = FunctionBuilder::new(module.function_mut(countdown).unwrap());
+        let n = builder.parameters()[0];
+        let one = builder.make_constant(ConstantValue::Int(1), int());
+        let next = builder
+            .emit_value(IrOpcode::Sub, vec![n, one], int(), Ownership::Owned, None)
+            .unwrap();
+        let result = builder
+            .emit_value(
+                IrOpcode::Call {
+                    target: CallTarget::Internal(countdown),
+                },
+                vec![next],
+                int(),
+                Ownership::Owned,
+                None,
+            )
+            .unwrap();
+        builder.emit_return(Some(result)).unwrap();
+        builder.finalize();
+        countdown
+    }
+
+    /// A function that calls each of `callees` with `5` and returns the last
+    /// result.
+    fn add_caller(module: &mut SolvraIrModule, name: &str, callees: &[FunctionId]) -> FunctionId {
+        let caller = module.add_function(name, FunctionSignature::new(Vec::new(), int()));
+        let mut builder = FunctionBuilder::new(module.function_mut(caller).unwrap());
+        let five = builder.make_constant(ConstantValue::Int(5), int());
+        let mut result = five;
+        for callee in callees {
+            result = builder
+                .emit_value(
+                    IrOpcode::Call {
+                        target: CallTarget::Internal(*callee),
+                    },
+                    vec![five],
+                    int(),
+                    Ownership::Owned,
+                    None,
+                )
+                .unwrap();
+        }
+        builder.emit_return(Some(result)).unwrap();
+        builder.finalize();
+        caller
+    }
+
+    #[test]
+    fn recursive_callees_are_kept() {
+        let mut module = SolvraIrModule::new();
+        let countdown = add_countdown(&mut module);
+
+        assert_eq!(inline_small_functions(&mut module, 8), 0);
+        assert_eq!(calls_to(module.function(countdown).unwrap(), countdown), 1);
+    }
+
+    #[test]
+    fn inline_calls_spends_the_budget_per_caller() {
+        let (mut module, scale, main) = module_with_small_callee();
+        let countdown = add_countdown(&mut module);
+        let driver = add_caller(&mut module, "driver", &[scale, countdown, scale]);
+
+        let (mut oversized, _, _) = module_with_small_callee();
+        assert_eq!(inline_calls(&mut oversized, 2), 0);
+
+        // Each caller can afford one copy of `scale`; `countdown` never fits.
+        assert_eq!(inline_calls(&mut module, 4), 2);
+        let main = module.function(main).unwrap();
+        assert_eq!(calls_to(main, scale), 0);
+        verify_function(main).expect("inlined caller verifies");
+        let driver = module.function(driver).unwrap();
+        assert_eq!(calls_to(driver, scale), 1);
+        assert_eq!(calls_to(driver, countdown), 1);
+        verify_function(driver).expect("partially inlined caller verifies");
+    }
+}
+
+//==============================================
+// End of file
+//==============================================
@@ -7,9 +7,10 @@
 //==============================================
 
 use crate::ir::function::{FunctionIR, FunctionId, FunctionSignature};
+use serde::{Deserialize, Serialize};
 
 /// Top-level SolvraIR module.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct SolvraIrModule {
     functions: Vec<FunctionIR>,
 }
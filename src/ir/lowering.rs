@@ -6,17 +6,19 @@
 // Objective: Provide Phase 1 SSA construction with basic statement and expression support
 //==============================================
 
-use crate::ast::{AssignTarget, BinaryOp, Expr, FunctionDecl, Literal, Program, Stmt, StringPart};
+use crate::ast::{
+    AssignTarget, BinaryOp, BranchHint, Expr, FunctionDecl, Literal, Program, Stmt, StringPart,
+};
 use crate::ir::block::BlockId;
 use crate::ir::builder::{BlockBuilderError, FunctionBuilder};
 use crate::ir::function::{CallTarget, FunctionIR, FunctionId, FunctionSignature};
 use crate::ir::ir::SolvraIrModule;
-use crate::ir::ops::IrOpcode;
+use crate::ir::ops::{self, IrOpcode};
 use crate::ir::types::{ObjectField, ObjectSchema, Ownership, PrimitiveType, TypeDescriptor};
 use crate::ir::value::{ConstantValue, ValueId};
 use crate::resolver::SymbolResolution;
 use crate::symbol::Symbol;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -162,9 +164,10 @@ impl<'a> LoweringContext<'a> {
                 condition,
                 then_branch,
                 else_branch,
+                hint,
                 ..
             } => {
-                self.lower_if(condition, then_branch, else_branch.as_deref())?;
+                self.lower_if(condition, then_branch, else_branch.as_deref(), *hint)?;
             }
             Stmt::While {
                 condition, body, ..
@@ -788,14 +791,19 @@ impl<'a> LoweringContext<'a> {
         condition: &Expr,
         then_branch: &Stmt,
         else_branch: Option<&Stmt>,
+        hint: Option<BranchHint>,
     ) -> Result<(), LoweringError> {
         let cond_value = self.lower_expression(condition)?;
         let then_block = self.builder.append_block("if.then");
         let else_block = else_branch.map(|_| self.builder.append_block("if.else"));
         let merge_block = self.builder.append_block("if.merge");
         let else_target = else_block.unwrap_or(merge_block);
+        let hint = hint.map(|hint| match hint {
+            BranchHint::Likely => ops::BranchHint::Likely,
+            BranchHint::Unlikely => ops::BranchHint::Unlikely,
+        });
         self.builder
-            .emit_branch(cond_value, then_block, else_target)?;
+            .emit_hinted_branch(cond_value, then_block, else_target, hint)?;
         self.lower_branch_block(then_block, then_branch, merge_block)?;
         if let Some(stmt) = else_branch {
             if let Some(block) = else_block {
@@ -830,7 +838,9 @@ impl<'a> LoweringContext<'a> {
         let cond_block = self.builder.append_block("while.cond");
         let loop_block = self.builder.append_block("while.body");
         let exit_block = self.builder.append_block("while.exit");
-        let mut carried_names = HashSet::new();
+        // Ordered, so loop phis are emitted by name and the IR (and every JIT
+        // listing built from it) is identical from one run to the next.
+        let mut carried_names = BTreeSet::new();
         collect_expr_identifiers(condition, &mut carried_names);
         collect_assigned_variables(body, &mut carried_names);
         let mut loop_vars = Vec::new();
@@ -907,7 +917,7 @@ fn flatten_literal_template(parts: &[StringPart]) -> Option<String> {
     Some(result)
 }
 
-fn collect_expr_identifiers(expr: &Expr, names: &mut HashSet<String>) {
+fn collect_expr_identifiers(expr: &Expr, names: &mut BTreeSet<String>) {
     match expr {
         Expr::Identifier { name, .. } => {
             names.insert(name.to_string());
@@ -977,7 +987,7 @@ fn collect_expr_identifiers(expr: &Expr, names: &mut HashSet<String>) {
     }
 }
 
-fn collect_expr_assignments(expr: &Expr, names: &mut HashSet<String>) {
+fn collect_expr_assignments(expr: &Expr, names: &mut BTreeSet<String>) {
     match expr {
         Expr::Assign { target, value, .. } => {
             if let AssignTarget::Variable(symbol) = target {
@@ -1044,7 +1054,7 @@ fn collect_expr_assignments(expr: &Expr, names: &mut HashSet<String>) {
     }
 }
 
-fn collect_assigned_variables(stmt: &Stmt, names: &mut HashSet<String>) {
+fn collect_assigned_variables(stmt: &Stmt, names: &mut BTreeSet<String>) {
     match stmt {
         Stmt::Expression { expr, .. } => collect_expr_assignments(expr, names),
         Stmt::Block { statements, .. } => {
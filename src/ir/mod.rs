@@ -10,10 +10,13 @@ pub mod block;
 pub mod builder;
 pub mod display;
 pub mod function;
+pub mod inline;
 pub mod interpreter;
 pub mod ir;
 pub mod lowering;
 pub mod ops;
+pub mod opt;
+pub mod serialize;
 pub mod types;
 pub mod value;
 pub mod verify;
@@ -12,9 +12,10 @@ use crate::ir::block::BlockId;
 use crate::ir::function::CallTarget;
 use crate::ir::types::TypeDescriptor;
 use crate::ir::value::ValueId;
+use serde::{Deserialize, Serialize};
 
 /// SSA opcodes emitted by the builder.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum IrOpcode {
     Nop,
     Phi,
@@ -50,7 +51,7 @@ pub enum IrOpcode {
 }
 
 /// Branch terminators supported by SolvraIR.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TerminatorKind {
     Return {
         value: Option<ValueId>,
@@ -65,11 +66,20 @@ pub enum TerminatorKind {
     },
 }
 
-/// Terminator wrapper storing debug info.
-#[derive(Debug, Clone, PartialEq)]
+/// Which way a `Branch` is expected to go, from a source `@likely` or
+/// `@unlikely` hint. Relative to `then_block`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BranchHint {
+    Likely,
+    Unlikely,
+}
+
+/// Terminator wrapper storing debug info and layout hints.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Terminator {
     pub kind: TerminatorKind,
     pub debug_name: Option<String>,
+    pub hint: Option<BranchHint>,
 }
 
 impl Terminator {
@@ -77,29 +87,30 @@ impl Terminator {
         Self {
             kind,
             debug_name: None,
+            hint: None,
         }
     }
 }
 
 /// Guard metadata used for speculative execution tracking.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Guard {
     pub kind: GuardKind,
     pub value: ValueId,
     pub message: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum GuardKind {
     Type(TypeDescriptor),
     Shape(u32),
     Range { min: i64, max: i64 },
     Truthy,
-    Custom(&'static str),
+    Custom(String),
 }
 
 /// Instruction representation storing opcode and operands.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Instruction {
     pub opcode: IrOpcode,
     pub operands: Vec<ValueId>,
@@ -0,0 +1,86 @@
+//==============================================
+// File: solvra_script/ir/opt/const_fold.rs
+// Author: Solvra Systems
+// License: Duality Public License (DPL v1.0)
+// Goal: Fold constant SolvraIR instructions
+// Objective: Turn instructions whose operands are all constants into constant values
+//==============================================
+
+use crate::ir::function::FunctionIR;
+use crate::ir::ops::{Instruction, IrOpcode};
+use crate::ir::value::{ConstantValue, ValueId, ValueKind};
+
+/// Replace every instruction whose operands are all constants with the
+/// constant it computes, and report whether anything was folded.
+///
+/// The folded result keeps its value id, so uses need no rewriting. Only
+/// operations that every backend evaluates the same way are folded: integer
+/// `Add` and `Rem`, string concatenation, comparisons, and boolean logic.
+/// `Sub`, `Mul`, `Div`, and `Neg` are left alone because the IR interpreter
+/// computes them in `f64` while the JIT tiers keep integers.
+pub fn fold_constants(function: &mut FunctionIR) -> bool {
+    let mut changed = false;
+    for block_index in 0..function.blocks.len() {
+        let instructions = std::mem::take(&mut function.blocks[block_index].instructions);
+        let mut kept = Vec::with_capacity(instructions.len());
+        for instruction in instructions {
+            match (instruction.result, evaluate(function, &instruction)) {
+                (Some(result), Some(constant)) => {
+                    function.value_mut(result).kind = ValueKind::Constant(constant);
+                    changed = true;
+                }
+                _ => kept.push(instruction),
+            }
+        }
+        function.blocks[block_index].instructions = kept;
+    }
+    changed
+}
+
+fn evaluate(function: &FunctionIR, instruction: &Instruction) -> Option<ConstantValue> {
+    use ConstantValue::{Bool, Int, String};
+    let operands = instruction
+        .operands
+        .iter()
+        .map(|operand| constant(function, *operand))
+        .collect::<Option<Vec<_>>>()?;
+    match (&instruction.opcode, operands.as_slice()) {
+        (IrOpcode::Add, [Int(a), Int(b)]) => a.checked_add(*b).map(Int),
+        (IrOpcode::Add, [String(a), String(b)]) => Some(String(format!("{a}{b}"))),
+        (IrOpcode::Rem, [Int(a), Int(b)]) => a.checked_rem(*b).map(Int),
+        (IrOpcode::And, [Bool(a), Bool(b)]) => Some(Bool(*a && *b)),
+        (IrOpcode::Or, [Bool(a), Bool(b)]) => Some(Bool(*a || *b)),
+        (IrOpcode::Not, [Bool(value)]) => Some(Bool(!value)),
+        (IrOpcode::CmpEq, [a, b]) => scalar_eq(a, b).map(Bool),
+        (IrOpcode::CmpNe, [a, b]) => scalar_eq(a, b).map(|equal| Bool(!equal)),
+        (IrOpcode::CmpLt, [Int(a), Int(b)]) => Some(Bool(a < b)),
+        (IrOpcode::CmpLe, [Int(a), Int(b)]) => Some(Bool(a <= b)),
+        (IrOpcode::CmpGt, [Int(a), Int(b)]) => Some(Bool(a > b)),
+        (IrOpcode::CmpGe, [Int(a), Int(b)]) => Some(Bool(a >= b)),
+        _ => None,
+    }
+}
+
+fn constant(function: &FunctionIR, value: ValueId) -> Option<&ConstantValue> {
+    match &function.values().get(value.index())?.kind {
+        ValueKind::Constant(constant) => Some(constant),
+        _ => None,
+    }
+}
+
+/// Equality for constants of the same scalar kind. Mixed kinds, such as an
+/// integer compared with a float, are left for the backends to decide.
+fn scalar_eq(left: &ConstantValue, right: &ConstantValue) -> Option<bool> {
+    use ConstantValue::{Bool, Int, Null, String};
+    match (left, right) {
+        (Int(a), Int(b)) => Some(a == b),
+        (Bool(a), Bool(b)) => Some(a == b),
+        (String(a), String(b)) => Some(a == b),
+        (Null, Null) => Some(true),
+        _ => None,
+    }
+}
+
+//==============================================
+// End of file
+//==============================================
@@ -0,0 +1,204 @@
+//==============================================
+// File: solvra_script/ir/opt/cse.rs
+// Author: Solvra Systems
+// License: Duality Public License (DPL v1.0)
+// Goal: Share repeated SolvraIR computations
+// Objective: Replace a pure instruction that repeats an earlier one in its block with the earlier result
+//==============================================
+
+use std::collections::HashMap;
+
+use crate::ir::function::FunctionIR;
+use crate::ir::ops::{Instruction, IrOpcode, TerminatorKind};
+use crate::ir::types::TypeDescriptor;
+use crate::ir::value::ValueId;
+
+/// Remove pure instructions that repeat an earlier instruction of the same
+/// block (same opcode, operands, and type) and point their uses at the
+/// earlier result. Returns whether anything was replaced.
+///
+/// Matching is block-local: the earlier instruction always dominates the
+/// later one, so every rewired use stays defined. Operands are rewritten
+/// before matching, so chains such as `(a + b) * c` repeated twice collapse
+/// in one run.
+pub fn cse(function: &mut FunctionIR) -> bool {
+    let mut replaced: HashMap<ValueId, ValueId> = HashMap::new();
+    for block in &mut function.blocks {
+        let mut seen: Vec<(IrOpcode, Vec<ValueId>, Option<TypeDescriptor>, ValueId)> = Vec::new();
+        let mut kept = Vec::with_capacity(block.instructions.len());
+        for mut instruction in std::mem::take(&mut block.instructions) {
+            rewrite_operands(&mut instruction, &replaced);
+            let Some(result) = instruction.result.filter(|_| is_pure(&instruction.opcode)) else {
+                kept.push(instruction);
+                continue;
+            };
+            let earlier = seen.iter().find(|(opcode, operands, ty, _)| {
+                *opcode == instruction.opcode
+                    && *operands == instruction.operands
+                    && *ty == instruction.ty
+            });
+            match earlier {
+                Some((.., value)) => {
+                    replaced.insert(result, *value);
+                }
+                None => {
+                    seen.push((
+                        instruction.opcode.clone(),
+                        instruction.operands.clone(),
+                        instruction.ty.clone(),
+                        result,
+                    ));
+                    kept.push(instruction);
+                }
+            }
+        }
+        block.instructions = kept;
+    }
+    if replaced.is_empty() {
+        return false;
+    }
+
+    // Uses in blocks visited before the defining block, such as loop phis.
+    for block in &mut function.blocks {
+        for instruction in &mut block.instructions {
+            rewrite_operands(instruction, &replaced);
+        }
+        if let Some(terminator) = &mut block.terminator {
+            match &mut terminator.kind {
+                TerminatorKind::Return { value: Some(value) } => rewrite(value, &replaced),
+                TerminatorKind::Branch { condition, .. } => rewrite(condition, &replaced),
+                _ => {}
+            }
+        }
+    }
+    true
+}
+
+/// Computations that depend only on their operands. Loads, element reads,
+/// and allocations are excluded because repeating them can observe or
+/// create different state.
+fn is_pure(opcode: &IrOpcode) -> bool {
+    matches!(
+        opcode,
+        IrOpcode::Add
+            | IrOpcode::Sub
+            | IrOpcode::Mul
+            | IrOpcode::Div
+            | IrOpcode::Rem
+            | IrOpcode::And
+            | IrOpcode::Or
+            | IrOpcode::Neg
+            | IrOpcode::Not
+            | IrOpcode::CmpEq
+            | IrOpcode::CmpNe
+            | IrOpcode::CmpLt
+            | IrOpcode::CmpLe
+            | IrOpcode::CmpGt
+            | IrOpcode::CmpGe
+    )
+}
+
+fn rewrite_operands(instruction: &mut Instruction, replaced: &HashMap<ValueId, ValueId>) {
+    for operand in &mut instruction.operands {
+        rewrite(operand, replaced);
+    }
+    if let IrOpcode::Guard(guard) = &mut instruction.opcode {
+        rewrite(&mut guard.value, replaced);
+    }
+}
+
+fn rewrite(value: &mut ValueId, replaced: &HashMap<ValueId, ValueId>) {
+    if let Some(earlier) = replaced.get(value) {
+        *value = *earlier;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::builder::FunctionBuilder;
+    use crate::ir::function::{CallTarget, FunctionSignature};
+    use crate::ir::ir::SolvraIrModule;
+    use crate::ir::types::{Ownership, PrimitiveType};
+    use crate::ir::verify::verify_function;
+
+    fn int() -> TypeDescriptor {
+        TypeDescriptor::primitive(PrimitiveType::Int64)
+    }
+
+    fn function(params: usize) -> FunctionIR {
+        let mut module = SolvraIrModule::new();
+        let id = module.add_function("f", FunctionSignature::new(vec![int(); params], int()));
+        module.function(id).unwrap().clone()
+    }
+
+    fn emit(
+        builder: &mut FunctionBuilder<'_>,
+        opcode: IrOpcode,
+        operands: Vec<ValueId>,
+    ) -> ValueId {
+        builder
+            .emit_value(opcode, operands, int(), Ownership::Owned, None)
+            .unwrap()
+    }
+
+    #[test]
+    fn repeated_sum_collapses_and_uses_are_rewired() {
+        // s1 = a + b; s2 = a + b; p = s1 * s2; q = p + s2; ret s2
+        let mut function = function(2);
+        let mut builder = FunctionBuilder::new(&mut function);
+        let (a, b) = (builder.parameters()[0], builder.parameters()[1]);
+        let s1 = emit(&mut builder, IrOpcode::Add, vec![a, b]);
+        let s2 = emit(&mut builder, IrOpcode::Add, vec![a, b]);
+        let p = emit(&mut builder, IrOpcode::Mul, vec![s1, s2]);
+        let q = emit(&mut builder, IrOpcode::Add, vec![p, s2]);
+        builder.emit_return(Some(s2)).unwrap();
+        builder.finalize();
+
+        assert!(cse(&mut function));
+        let entry = function.block(function.entry_block);
+        let defs: Vec<_> = entry
+            .instructions
+            .iter()
+            .map(|instruction| (instruction.result, instruction.operands.clone()))
+            .collect();
+        assert_eq!(
+            defs,
+            vec![
+                (Some(s1), vec![a, b]),
+                (Some(p), vec![s1, s1]),
+                (Some(q), vec![p, s1]),
+            ]
+        );
+        assert!(matches!(
+            entry.terminator.as_ref().map(|term| &term.kind),
+            Some(TerminatorKind::Return { value: Some(value) }) if *value == s1
+        ));
+        verify_function(&function).expect("rewired function verifies");
+        assert!(!cse(&mut function));
+    }
+
+    #[test]
+    fn identical_calls_are_not_merged() {
+        let mut function = function(1);
+        let mut builder = FunctionBuilder::new(&mut function);
+        let x = builder.parameters()[0];
+        let call = IrOpcode::Call {
+            target: CallTarget::External {
+                symbol: "read_line".into(),
+            },
+        };
+        let first = emit(&mut builder, call.clone(), vec![x]);
+        let second = emit(&mut builder, call, vec![x]);
+        let sum = emit(&mut builder, IrOpcode::Add, vec![first, second]);
+        builder.emit_return(Some(sum)).unwrap();
+        builder.finalize();
+
+        assert!(!cse(&mut function));
+        assert_eq!(function.block(function.entry_block).instructions.len(), 3);
+    }
+}
+
+//==============================================
+// End of file
+//==============================================
@@ -0,0 +1,195 @@
+//==============================================
+// File: solvra_script/ir/opt/dce.rs
+// Author: Solvra Systems
+// License: Duality Public License (DPL v1.0)
+// Goal: Remove dead SolvraIR definitions
+// Objective: Drop side-effect-free instructions whose results are never used, until none remain
+//==============================================
+
+use crate::ir::function::FunctionIR;
+use crate::ir::ops::{Instruction, IrOpcode, TerminatorKind};
+use crate::ir::value::ValueId;
+
+/// Remove instructions that define an unused value and have no side effects,
+/// repeating until stable so operands of removed instructions are removed in
+/// turn. Returns whether anything was removed.
+pub fn eliminate_dead_code(function: &mut FunctionIR) -> bool {
+    let mut changed = false;
+    loop {
+        let uses = use_counts(function);
+        let mut removed = false;
+        for block in &mut function.blocks {
+            let before = block.instructions.len();
+            block
+                .instructions
+                .retain(|instruction| !is_dead(instruction, &uses));
+            removed |= block.instructions.len() != before;
+        }
+        if !removed {
+            return changed;
+        }
+        changed = true;
+    }
+}
+
+fn is_dead(instruction: &Instruction, uses: &[usize]) -> bool {
+    match instruction.result {
+        Some(result) => uses[result.index()] == 0 && !has_side_effects(&instruction.opcode),
+        None => false,
+    }
+}
+
+/// Calls, stores, guards, and safepoints must stay. So must operations that
+/// can fail at run time even when their result is unused: division and
+/// remainder by zero, out-of-range array reads and slices, missing object
+/// fields, and taking the length of a non-array.
+fn has_side_effects(opcode: &IrOpcode) -> bool {
+    matches!(
+        opcode,
+        IrOpcode::Call { .. }
+            | IrOpcode::Store
+            | IrOpcode::ArraySet
+            | IrOpcode::ObjectSet
+            | IrOpcode::Guard(_)
+            | IrOpcode::StackMapRef(_)
+            | IrOpcode::Div
+            | IrOpcode::Rem
+            | IrOpcode::ArrayGet
+            | IrOpcode::ArrayLen
+            | IrOpcode::Slice
+            | IrOpcode::ObjectGet
+    )
+}
+
+fn use_counts(function: &FunctionIR) -> Vec<usize> {
+    let mut uses = vec![0; function.values().len()];
+    let mut count = |value: ValueId| {
+        if let Some(slot) = uses.get_mut(value.index()) {
+            *slot += 1;
+        }
+    };
+    for block in &function.blocks {
+        for instruction in &block.instructions {
+            instruction.operands.iter().copied().for_each(&mut count);
+            if let IrOpcode::Guard(guard) = &instruction.opcode {
+                count(guard.value);
+            }
+        }
+        match block.terminator.as_ref().map(|term| &term.kind) {
+            Some(TerminatorKind::Return { value: Some(value) }) => count(*value),
+            Some(TerminatorKind::Branch { condition, .. }) => count(*condition),
+            _ => {}
+        }
+    }
+    uses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::builder::FunctionBuilder;
+    use crate::ir::function::{CallTarget, FunctionSignature};
+    use crate::ir::ir::SolvraIrModule;
+    use crate::ir::types::{Ownership, PrimitiveType, TypeDescriptor};
+    use crate::ir::value::ConstantValue;
+    use crate::ir::verify::verify_function;
+
+    fn int() -> TypeDescriptor {
+        TypeDescriptor::primitive(PrimitiveType::Int64)
+    }
+
+    /// `f(x)` computes `(x + 1) * 2` and `println(x)`, ignores both results,
+    /// and returns `x`.
+    fn function_with_dead_values() -> FunctionIR {
+        let mut module = SolvraIrModule::new();
+        let id = module.add_function("f", FunctionSignature::new(vec![int()], int()));
+        let mut function = module.function(id).unwrap().clone();
+        let mut builder = FunctionBuilder::new(&mut function);
+        let x = builder.parameters()[0];
+        let one = builder.make_constant(ConstantValue::Int(1), int());
+        let two = builder.make_constant(ConstantValue::Int(2), int());
+        let sum = builder
+            .emit_value(IrOpcode::Add, vec![x, one], int(), Ownership::Owned, None)
+            .unwrap();
+        builder
+            .emit_value(IrOpcode::Mul, vec![sum, two], int(), Ownership::Owned, None)
+            .unwrap();
+        builder
+            .emit_value(
+                IrOpcode::Call {
+                    target: CallTarget::External {
+                        symbol: "println".into(),
+                    },
+                },
+                vec![x],
+                TypeDescriptor::default(),
+                Ownership::Owned,
+                None,
+            )
+            .unwrap();
+        builder.emit_return(Some(x)).unwrap();
+        builder.finalize();
+        function
+    }
+
+    #[test]
+    fn unused_pure_chain_is_removed_and_calls_are_kept() {
+        let mut function = function_with_dead_values();
+        assert!(eliminate_dead_code(&mut function));
+
+        let entry = function.block(function.entry_block);
+        assert_eq!(entry.instructions.len(), 1);
+        assert!(matches!(
+            entry.instructions[0].opcode,
+            IrOpcode::Call { .. }
+        ));
+        verify_function(&function).expect("function still verifies");
+
+        assert!(!eliminate_dead_code(&mut function));
+    }
+
+    #[test]
+    fn unused_reads_that_can_fail_are_kept() {
+        let mut module = SolvraIrModule::new();
+        let array = TypeDescriptor::default();
+        let id = module.add_function("g", FunctionSignature::new(vec![array.clone()], int()));
+        let mut function = module.function(id).unwrap().clone();
+        let mut builder = FunctionBuilder::new(&mut function);
+        let items = builder.parameters()[0];
+        let index = builder.make_constant(ConstantValue::Int(10), int());
+        builder
+            .emit_value(
+                IrOpcode::ArrayGet,
+                vec![items, index],
+                TypeDescriptor::default(),
+                Ownership::Owned,
+                None,
+            )
+            .unwrap();
+        builder
+            .emit_value(
+                IrOpcode::ArrayLen,
+                vec![items],
+                int(),
+                Ownership::Owned,
+                None,
+            )
+            .unwrap();
+        builder.emit_return(Some(index)).unwrap();
+        builder.finalize();
+
+        assert!(!eliminate_dead_code(&mut function));
+        let opcodes: Vec<&IrOpcode> = function
+            .block(function.entry_block)
+            .instructions
+            .iter()
+            .map(|instruction| &instruction.opcode)
+            .collect();
+        assert!(opcodes.contains(&&IrOpcode::ArrayGet));
+        assert!(opcodes.contains(&&IrOpcode::ArrayLen));
+    }
+}
+
+//==============================================
+// End of file
+//==============================================
@@ -0,0 +1,340 @@
+#![allow(dead_code)]
+
+//==============================================
+// File: solvra_script/ir/opt/mod.rs
+// Author: Solvra Systems
+// License: Duality Public License (DPL v1.0)
+// Goal: Orchestrate SolvraIR optimization passes
+// Objective: Run a configurable pass sequence to a fixpoint and report which passes changed the module
+//==============================================
+
+pub mod const_fold;
+pub mod cse;
+pub mod dce;
+
+pub use crate::ir::inline::inline_calls;
+pub use const_fold::fold_constants;
+pub use cse::cse;
+pub use dce::eliminate_dead_code;
+
+use crate::ir::function::FunctionIR;
+use crate::ir::ir::SolvraIrModule;
+use thiserror::Error;
+
+/// Rounds of the whole pipeline allowed before giving up on a fixpoint.
+pub const DEFAULT_MAX_ITERATIONS: usize = 8;
+
+/// Instruction budget per caller for the `inline` pass, spent once per
+/// [`PassManager::run`].
+pub const DEFAULT_INLINE_BUDGET: usize = 16;
+
+/// A single IR optimization pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pass {
+    ConstantFold,
+    CommonSubexpr,
+    DeadCode,
+    Inline,
+}
+
+impl Pass {
+    pub const ALL: &'static [Pass] = &[
+        Pass::ConstantFold,
+        Pass::CommonSubexpr,
+        Pass::DeadCode,
+        Pass::Inline,
+    ];
+
+    /// Name accepted by `--ir-opt-passes`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Pass::ConstantFold => "const-fold",
+            Pass::CommonSubexpr => "cse",
+            Pass::DeadCode => "dce",
+            Pass::Inline => "inline",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|pass| pass.name() == name)
+    }
+
+    /// Run the pass over `module` and report whether it changed anything.
+    pub fn run(self, module: &mut SolvraIrModule) -> bool {
+        match self {
+            Pass::ConstantFold => run_per_function(module, fold_constants),
+            Pass::CommonSubexpr => run_per_function(module, cse),
+            Pass::DeadCode => run_per_function(module, eliminate_dead_code),
+            Pass::Inline => inline_calls(module, DEFAULT_INLINE_BUDGET) > 0,
+        }
+    }
+}
+
+fn run_per_function(module: &mut SolvraIrModule, pass: fn(&mut FunctionIR) -> bool) -> bool {
+    module
+        .functions_mut()
+        .iter_mut()
+        .fold(false, |changed, function| pass(function) | changed)
+}
+
+#[derive(Debug, Error)]
+#[error("unknown IR pass `{0}`")]
+pub struct UnknownPassError(pub String);
+
+/// Outcome of [`PassManager::run`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PassReport {
+    /// Rounds run, including the final round that changed nothing.
+    pub iterations: usize,
+    /// False when the iteration cap was hit while passes were still firing.
+    pub reached_fixpoint: bool,
+    /// Every pass that changed the module, with the round it ran in.
+    pub changes: Vec<(usize, Pass)>,
+}
+
+impl PassReport {
+    /// Passes that changed the module at least once, in first-fired order.
+    pub fn fired(&self) -> Vec<Pass> {
+        let mut fired = Vec::new();
+        for (_, pass) in &self.changes {
+            if !fired.contains(pass) {
+                fired.push(*pass);
+            }
+        }
+        fired
+    }
+}
+
+/// Runs a sequence of passes repeatedly until a full round changes nothing
+/// or the iteration cap is reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassManager {
+    passes: Vec<Pass>,
+    max_iterations: usize,
+}
+
+impl PassManager {
+    pub fn new(passes: impl IntoIterator<Item = Pass>) -> Self {
+        Self {
+            passes: passes.into_iter().collect(),
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+        }
+    }
+
+    /// Folding, CSE, and DCE. Inlining grows callers, so it is opt-in.
+    pub fn default_pipeline() -> Self {
+        Self::new([Pass::ConstantFold, Pass::CommonSubexpr, Pass::DeadCode])
+    }
+
+    /// Parse a comma-separated list of pass names; `default` expands to the
+    /// default pipeline.
+    pub fn parse(spec: &str) -> Result<Self, UnknownPassError> {
+        let mut passes = Vec::new();
+        for name in spec
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+        {
+            if name == "default" {
+                passes.extend(Self::default_pipeline().passes);
+            } else {
+                passes.push(Pass::from_name(name).ok_or_else(|| UnknownPassError(name.into()))?);
+            }
+        }
+        Ok(Self::new(passes))
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    pub fn passes(&self) -> &[Pass] {
+        &self.passes
+    }
+
+    /// Run the passes in order, round after round, until a round changes
+    /// nothing. `inline` only runs in the first round: its budget is per
+    /// caller, and rerunning it would grant every caller a fresh budget.
+    pub fn run(&self, module: &mut SolvraIrModule) -> PassReport {
+        let mut report = PassReport::default();
+        while report.iterations < self.max_iterations {
+            report.iterations += 1;
+            let mut changed = false;
+            for pass in &self.passes {
+                if *pass == Pass::Inline && report.iterations > 1 {
+                    continue;
+                }
+                if pass.run(module) {
+                    report.changes.push((report.iterations, *pass));
+                    changed = true;
+                }
+            }
+            if !changed {
+                report.reached_fixpoint = true;
+                break;
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::builder::FunctionBuilder;
+    use crate::ir::function::{CallTarget, FunctionSignature};
+    use crate::ir::ops::{IrOpcode, TerminatorKind};
+    use crate::ir::types::{Ownership, PrimitiveType, TypeDescriptor};
+    use crate::ir::value::{ConstantValue, ValueKind};
+    use crate::ir::verify::verify_function;
+
+    fn int() -> TypeDescriptor {
+        TypeDescriptor::primitive(PrimitiveType::Int64)
+    }
+
+    /// `main() = 2 + 3 < 10`, where the comparison only becomes foldable once
+    /// the addition has been folded.
+    fn foldable_module() -> SolvraIrModule {
+        let mut module = SolvraIrModule::new();
+        let main = module.add_function("main", FunctionSignature::new(Vec::new(), int()));
+        let mut builder = FunctionBuilder::new(module.function_mut(main).unwrap());
+        let two = builder.make_constant(ConstantValue::Int(2), int());
+        let three = builder.make_constant(ConstantValue::Int(3), int());
+        let ten = builder.make_constant(ConstantValue::Int(10), int());
+        let sum = builder
+            .emit_value(
+                IrOpcode::Add,
+                vec![two, three],
+                int(),
+                Ownership::Owned,
+                None,
+            )
+            .unwrap();
+        let less = builder
+            .emit_value(
+                IrOpcode::CmpLt,
+                vec![sum, ten],
+                TypeDescriptor::primitive(PrimitiveType::Bool),
+                Ownership::Owned,
+                None,
+            )
+            .unwrap();
+        builder.emit_return(Some(less)).unwrap();
+        builder.finalize();
+        module
+    }
+
+    #[test]
+    fn default_pipeline_folds_to_a_fixpoint() {
+        let mut module = foldable_module();
+        let report = PassManager::default_pipeline().run(&mut module);
+
+        assert!(report.reached_fixpoint);
+        assert_eq!(report.iterations, 2);
+        assert_eq!(report.changes, vec![(1, Pass::ConstantFold)]);
+        assert_eq!(report.fired(), vec![Pass::ConstantFold]);
+
+        let main = module.function_by_name("main").unwrap();
+        let entry = main.block(main.entry_block);
+        assert!(entry.instructions.is_empty());
+        let Some(TerminatorKind::Return { value: Some(value) }) =
+            entry.terminator.as_ref().map(|term| &term.kind)
+        else {
+            panic!("expected a value return");
+        };
+        assert_eq!(
+            main.values()[value.index()].kind,
+            ValueKind::Constant(ConstantValue::Bool(true))
+        );
+        verify_function(main).expect("folded function verifies");
+    }
+
+    #[test]
+    fn iteration_cap_stops_before_the_fixpoint_is_confirmed() {
+        let mut module = foldable_module();
+        let report = PassManager::default_pipeline()
+            .with_max_iterations(1)
+            .run(&mut module);
+        assert_eq!(report.iterations, 1);
+        assert!(!report.reached_fixpoint);
+    }
+
+    #[test]
+    fn inlining_budget_is_not_renewed_each_round() {
+        let mut module = SolvraIrModule::new();
+        let inc = module.add_function("inc", FunctionSignature::new(vec![int()], int()));
+        let main = module.add_function("main", FunctionSignature::new(Vec::new(), int()));
+
+        let mut builder = FunctionBuilder::new(module.function_mut(inc).unwrap());
+        let x = builder.parameters()[0];
+        let one = builder.make_constant(ConstantValue::Int(1), int());
+        let next = builder
+            .emit_value(IrOpcode::Add, vec![x, one], int(), Ownership::Owned, None)
+            .unwrap();
+        builder.emit_return(Some(next)).unwrap();
+        builder.finalize();
+
+        // `main` applies `inc` twenty times; each copy costs one instruction.
+        let mut builder = FunctionBuilder::new(module.function_mut(main).unwrap());
+        let mut value = builder.make_constant(ConstantValue::Int(0), int());
+        for _ in 0..20 {
+            value = builder
+                .emit_value(
+                    IrOpcode::Call {
+                        target: CallTarget::Internal(inc),
+                    },
+                    vec![value],
+                    int(),
+                    Ownership::Owned,
+                    None,
+                )
+                .unwrap();
+        }
+        builder.emit_return(Some(value)).unwrap();
+        builder.finalize();
+
+        let report = PassManager::new([Pass::Inline]).run(&mut module);
+        assert!(report.reached_fixpoint);
+        assert_eq!(report.changes, vec![(1, Pass::Inline)]);
+
+        let main = module.function(main).unwrap();
+        let remaining = main
+            .blocks
+            .iter()
+            .flat_map(|block| &block.instructions)
+            .filter(|inst| {
+                inst.opcode
+                    == IrOpcode::Call {
+                        target: CallTarget::Internal(inc),
+                    }
+            })
+            .count();
+        assert_eq!(remaining, 20 - DEFAULT_INLINE_BUDGET);
+        verify_function(main).expect("inlined caller verifies");
+    }
+
+    #[test]
+    fn pass_lists_parse_by_name() {
+        let manager = PassManager::parse("default, const-fold").unwrap();
+        assert_eq!(
+            manager.passes(),
+            [
+                Pass::ConstantFold,
+                Pass::CommonSubexpr,
+                Pass::DeadCode,
+                Pass::ConstantFold
+            ]
+        );
+        assert_eq!(
+            PassManager::parse("inline,dce").unwrap().passes(),
+            [Pass::Inline, Pass::DeadCode]
+        );
+        let err = PassManager::parse("const-fold,unroll").unwrap_err();
+        assert_eq!(err.to_string(), "unknown IR pass `unroll`");
+    }
+}
+
+//==============================================
+// End of file
+//==============================================
@@ -0,0 +1,146 @@
+#![allow(dead_code)]
+
+//==============================================
+// File: solvra_script/ir/serialize.rs
+// Author: Solvra Systems — Generated by Codex Agent
+// License: Duality Public License (DPL v1.0)
+// Goal: Persist lowered SolvraIR modules
+// Objective: Encode modules behind a versioned header so cached IR from another build is rejected
+//==============================================
+
+use crate::ir::ir::SolvraIrModule;
+use bincode::Options;
+use thiserror::Error;
+
+const MAGIC: &[u8; 4] = b"SVIR";
+
+/// Bumped whenever an IR type changes shape, so modules written by an older
+/// build are rejected instead of misread.
+pub const IR_FORMAT_VERSION: u32 = 2;
+
+#[derive(Debug, Error)]
+pub enum IrSerializeError {
+    #[error("input is not a serialized SolvraIR module")]
+    BadMagic,
+    #[error("SolvraIR format version {found} is not supported (expected {expected})")]
+    UnsupportedVersion { found: u32, expected: u32 },
+    #[error("failed to encode SolvraIR module: {0}")]
+    Encode(bincode::Error),
+    #[error("malformed SolvraIR module: {0}")]
+    Malformed(bincode::Error),
+}
+
+/// Serialize `module` as the magic bytes, the little-endian format version,
+/// and a bincode payload.
+pub fn encode_module(module: &SolvraIrModule) -> Result<Vec<u8>, IrSerializeError> {
+    let payload = bincode::serialize(module).map_err(IrSerializeError::Encode)?;
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + payload.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&IR_FORMAT_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&payload);
+    Ok(bytes)
+}
+
+/// Decode a module written by [`encode_module`]. Length prefixes may not
+/// claim more bytes than the input holds.
+pub fn decode_module(bytes: &[u8]) -> Result<SolvraIrModule, IrSerializeError> {
+    let rest = bytes
+        .strip_prefix(MAGIC.as_slice())
+        .ok_or(IrSerializeError::BadMagic)?;
+    let (version, payload) = rest.split_at_checked(4).ok_or(IrSerializeError::BadMagic)?;
+    let found = u32::from_le_bytes(version.try_into().expect("four version bytes"));
+    if found != IR_FORMAT_VERSION {
+        return Err(IrSerializeError::UnsupportedVersion {
+            found,
+            expected: IR_FORMAT_VERSION,
+        });
+    }
+    bincode::options()
+        .with_fixint_encoding()
+        .with_limit(payload.len() as u64)
+        .deserialize(payload)
+        .map_err(IrSerializeError::Malformed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::display::IrFormatter;
+    use crate::ir::lowering::lower_program;
+    use crate::ir::verify::verify_function;
+    use crate::parser::Parser;
+    use crate::resolver::{Diagnostics, resolve_module};
+    use crate::tokenizer::Tokenizer;
+
+    fn lowered(source: &str) -> SolvraIrModule {
+        let mut tokenizer = Tokenizer::new(source);
+        let tokens = tokenizer.tokenize().expect("tokenize script");
+        let program = Parser::new(tokens).parse().expect("parse program");
+        let mut diagnostics = Diagnostics::new();
+        let resolutions = resolve_module(&program, &mut diagnostics);
+        lower_program(&program, &resolutions).expect("lower program")
+    }
+
+    fn listings(module: &SolvraIrModule) -> Vec<String> {
+        module
+            .functions()
+            .iter()
+            .map(|function| IrFormatter::new(function).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn lowered_module_round_trips_and_reverifies() {
+        let module = lowered(
+            r#"
+fn pick(flag, n) {
+    if flag {
+        return n * 2;
+    }
+    return n + 1;
+}
+
+fn main() {
+    return pick(true, 20) + pick(false, 1);
+}
+"#,
+        );
+        let decoded = decode_module(&encode_module(&module).unwrap()).expect("decode module");
+
+        assert_eq!(listings(&decoded), listings(&module));
+        for (original, restored) in module.functions().iter().zip(decoded.functions()) {
+            assert_eq!(restored.name, original.name);
+            assert_eq!(restored.values(), original.values());
+            verify_function(original).expect("original verifies");
+            verify_function(restored).expect("decoded module verifies");
+        }
+        assert!(decoded.function_by_name("pick").is_some());
+    }
+
+    #[test]
+    fn other_versions_and_foreign_bytes_are_rejected() {
+        let mut bytes = encode_module(&lowered("fn main() { return 1; }")).unwrap();
+        bytes[MAGIC.len()..MAGIC.len() + 4].copy_from_slice(&(IR_FORMAT_VERSION + 1).to_le_bytes());
+        assert!(matches!(
+            decode_module(&bytes),
+            Err(IrSerializeError::UnsupportedVersion { found, expected })
+                if found == IR_FORMAT_VERSION + 1 && expected == IR_FORMAT_VERSION
+        ));
+
+        assert!(matches!(
+            decode_module(b"SVC\0 not ir"),
+            Err(IrSerializeError::BadMagic)
+        ));
+        let mut truncated = MAGIC.to_vec();
+        truncated.extend_from_slice(&IR_FORMAT_VERSION.to_le_bytes());
+        truncated.extend_from_slice(&[0xff; 8]);
+        assert!(matches!(
+            decode_module(&truncated),
+            Err(IrSerializeError::Malformed(_))
+        ));
+    }
+}
+
+//==============================================
+// End of file
+//==============================================
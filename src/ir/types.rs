@@ -8,10 +8,11 @@
 // Objective: Provide descriptors for primitive/object/collection types and ownership models
 //==============================================
 
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Primitive scalar kinds tracked by SolvraIR.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PrimitiveType {
     Void,
     Bool,
@@ -43,7 +44,7 @@ impl fmt::Display for PrimitiveType {
 }
 
 /// Ownership semantics recorded for SSA values.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Ownership {
     Owned,
     Shared,
@@ -51,14 +52,14 @@ pub enum Ownership {
 }
 
 /// Borrow flavour captured for references.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum BorrowKind {
     Immutable,
     Mutable,
 }
 
 /// Field metadata inside an [`ObjectSchema`].
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ObjectField {
     pub name: String,
     pub ty: TypeDescriptor,
@@ -81,7 +82,7 @@ impl ObjectField {
 }
 
 /// Structural schema for Solvra objects.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ObjectSchema {
     pub shape_id: u32,
     pub sealed: bool,
@@ -109,7 +110,7 @@ impl ObjectSchema {
 }
 
 /// Rich type descriptor stored with every IR value.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TypeDescriptor {
     pub primitive: PrimitiveType,
     pub element: Option<Box<TypeDescriptor>>,
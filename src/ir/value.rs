@@ -9,10 +9,11 @@
 //==============================================
 
 use crate::ir::types::{Ownership, TypeDescriptor};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Identifier for SSA values.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct ValueId(pub(crate) u32);
 
 impl ValueId {
@@ -28,7 +29,7 @@ impl fmt::Display for ValueId {
 }
 
 /// Identifier for SSA instructions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct InstructionId(pub(crate) u32);
 
 impl InstructionId {
@@ -44,7 +45,7 @@ impl From<InstructionId> for ValueId {
 }
 
 /// Classification of an SSA value.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ValueKind {
     Parameter(usize),
     Constant(ConstantValue),
@@ -53,7 +54,7 @@ pub enum ValueKind {
 }
 
 /// Constant payload stored in SSA form.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ConstantValue {
     Int(i64),
     Float(f64),
@@ -63,7 +64,7 @@ pub enum ConstantValue {
 }
 
 /// Complete metadata describing a value.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ValueData {
     pub id: ValueId,
     pub ty: TypeDescriptor,
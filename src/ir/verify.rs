@@ -30,6 +30,8 @@ pub enum IrVerifyError {
     SuccessorMismatch(BlockId),
     #[error("value {0} violates SSA dominance rules")]
     DominanceViolation(ValueId),
+    #[error("value {value} used in block {block} is not defined on every path reaching it")]
+    UndefinedOnSomePath { value: ValueId, block: BlockId },
     #[error("opcode {opcode} expects {expected} operands, got {actual}")]
     InvalidOperandCount {
         opcode: &'static str,
@@ -87,27 +89,47 @@ pub fn verify_function(function: &FunctionIR) -> Result<(), IrVerifyError> {
         }
     }
 
-    // Dominance tracking: parameters/constants available at start.
-    let mut available = vec![false; value_count];
-    for value in function.values() {
-        match value.kind {
-            ValueKind::Parameter(_) | ValueKind::Constant(_) => {
-                available[value.id.index()] = true;
+    // Definedness: a value may only be used where every path from the entry
+    // block has already defined it.
+    let (defined_on_entry, defined_on_exit) = definitions_on_all_paths(function, &traversal);
+    let mut defining_block = vec![None; value_count];
+    for block in &function.blocks {
+        for result in block.instructions.iter().filter_map(|inst| inst.result) {
+            if result.index() < value_count {
+                defining_block[result.index()] = Some(block.id);
             }
-            _ => {}
         }
     }
+    let undefined = |value: ValueId, block: BlockId| {
+        if defining_block[value.index()] == Some(block) {
+            IrVerifyError::DominanceViolation(value)
+        } else {
+            IrVerifyError::UndefinedOnSomePath { value, block }
+        }
+    };
 
     for block_id in traversal {
         let block = function.block(block_id);
+        let mut available = defined_on_entry[block_id.index()].clone();
         for instruction in &block.instructions {
             let is_phi = matches!(instruction.opcode, IrOpcode::Phi);
-            for operand in &instruction.operands {
+            for (position, operand) in instruction.operands.iter().enumerate() {
                 if operand.index() >= value_count {
                     return Err(IrVerifyError::UnknownValue(*operand));
                 }
-                if !is_phi && !available[operand.index()] {
-                    return Err(IrVerifyError::DominanceViolation(*operand));
+                // A phi operand only has to be defined along the edge from its
+                // matching predecessor.
+                let defined = if is_phi {
+                    block
+                        .predecessors
+                        .get(position)
+                        .filter(|pred| pred.index() < block_count)
+                        .is_none_or(|pred| defined_on_exit[pred.index()][operand.index()])
+                } else {
+                    available[operand.index()]
+                };
+                if !defined {
+                    return Err(undefined(*operand, block_id));
                 }
             }
             if let Some(result) = instruction.result {
@@ -172,7 +194,7 @@ pub fn verify_function(function: &FunctionIR) -> Result<(), IrVerifyError> {
                         return Err(IrVerifyError::UnknownValue(*value));
                     }
                     if !available[value.index()] {
-                        return Err(IrVerifyError::DominanceViolation(*value));
+                        return Err(undefined(*value, block_id));
                     }
                 }
             }
@@ -190,7 +212,7 @@ pub fn verify_function(function: &FunctionIR) -> Result<(), IrVerifyError> {
                     return Err(IrVerifyError::UnknownValue(*condition));
                 }
                 if !available[condition.index()] {
-                    return Err(IrVerifyError::DominanceViolation(*condition));
+                    return Err(undefined(*condition, block_id));
                 }
                 if then_block.index() >= block_count {
                     return Err(IrVerifyError::UnknownTarget(*then_block));
@@ -212,15 +234,7 @@ pub fn verify_function(function: &FunctionIR) -> Result<(), IrVerifyError> {
 
         let mut recorded = block.successors.clone();
         recorded.sort_by_key(|id| id.index());
-        let mut expected = match &block.terminator.as_ref().unwrap().kind {
-            TerminatorKind::Jump { target } => vec![*target],
-            TerminatorKind::Branch {
-                then_block,
-                else_block,
-                ..
-            } => vec![*then_block, *else_block],
-            TerminatorKind::Return { .. } => Vec::new(),
-        };
+        let mut expected = terminator_targets(&block.terminator.as_ref().unwrap().kind);
         recorded.dedup();
         expected.sort_by_key(|id| id.index());
         if recorded != expected {
@@ -230,6 +244,152 @@ pub fn verify_function(function: &FunctionIR) -> Result<(), IrVerifyError> {
     Ok(())
 }
 
+/// Blocks a terminator can transfer control to.
+fn terminator_targets(kind: &TerminatorKind) -> Vec<BlockId> {
+    match kind {
+        TerminatorKind::Jump { target } => vec![*target],
+        TerminatorKind::Branch {
+            then_block,
+            else_block,
+            ..
+        } => vec![*then_block, *else_block],
+        TerminatorKind::Return { .. } => Vec::new(),
+    }
+}
+
+/// Forward "must be defined" dataflow over the CFG. Returns, per block, the
+/// values defined on every path from the entry block to the block's start and
+/// to its end. Parameters and constants count as defined everywhere.
+fn definitions_on_all_paths(
+    function: &FunctionIR,
+    order: &[BlockId],
+) -> (Vec<Vec<bool>>, Vec<Vec<bool>>) {
+    let block_count = function.blocks.len();
+    let value_count = function.values().len();
+
+    let mut predecessors = vec![Vec::new(); block_count];
+    for block in &function.blocks {
+        if let Some(terminator) = &block.terminator {
+            for target in terminator_targets(&terminator.kind) {
+                if target.index() < block_count {
+                    predecessors[target.index()].push(block.id);
+                }
+            }
+        }
+    }
+
+    let mut globals = vec![false; value_count];
+    for value in function.values() {
+        if matches!(value.kind, ValueKind::Parameter(_) | ValueKind::Constant(_)) {
+            globals[value.id.index()] = true;
+        }
+    }
+
+    // Start from "everything defined" and shrink until nothing changes.
+    let mut on_entry = vec![vec![true; value_count]; block_count];
+    let mut on_exit = vec![vec![true; value_count]; block_count];
+    on_entry[function.entry_block.index()] = globals;
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &block_id in order {
+            let index = block_id.index();
+            if block_id != function.entry_block {
+                let mut incoming = vec![true; value_count];
+                for pred in &predecessors[index] {
+                    for (slot, defined) in incoming.iter_mut().zip(&on_exit[pred.index()]) {
+                        *slot &= *defined;
+                    }
+                }
+                on_entry[index] = incoming;
+            }
+            let mut outgoing = on_entry[index].clone();
+            for result in function
+                .block(block_id)
+                .instructions
+                .iter()
+                .filter_map(|inst| inst.result)
+            {
+                if result.index() < value_count {
+                    outgoing[result.index()] = true;
+                }
+            }
+            if outgoing != on_exit[index] {
+                on_exit[index] = outgoing;
+                changed = true;
+            }
+        }
+    }
+    (on_entry, on_exit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::builder::FunctionBuilder;
+    use crate::ir::function::FunctionSignature;
+    use crate::ir::ir::SolvraIrModule;
+    use crate::ir::types::{Ownership, PrimitiveType, TypeDescriptor};
+
+    fn int() -> TypeDescriptor {
+        TypeDescriptor::primitive(PrimitiveType::Int64)
+    }
+
+    /// `if cond { x = n + n }` followed by a merge block that returns either
+    /// `x` directly or a phi of `x` and `n`.
+    fn branch_function(merge_with_phi: bool) -> (FunctionIR, ValueId, BlockId) {
+        let mut module = SolvraIrModule::new();
+        let signature = FunctionSignature::new(
+            vec![TypeDescriptor::primitive(PrimitiveType::Bool), int()],
+            int(),
+        );
+        let id = module.add_function("branchy", signature);
+        let mut function = module.function(id).unwrap().clone();
+        let mut builder = FunctionBuilder::new(&mut function);
+        let (cond, n) = (builder.parameters()[0], builder.parameters()[1]);
+        let then_block = builder.append_block("then");
+        let merge_block = builder.append_block("merge");
+        builder.emit_branch(cond, then_block, merge_block).unwrap();
+
+        builder.position_at_end(then_block).unwrap();
+        let x = builder
+            .emit_value(IrOpcode::Add, vec![n, n], int(), Ownership::Owned, None)
+            .unwrap();
+        builder.emit_jump(merge_block).unwrap();
+
+        builder.position_at_end(merge_block).unwrap();
+        let result = if merge_with_phi {
+            // Predecessors are recorded as [entry, then].
+            builder
+                .emit_value(IrOpcode::Phi, vec![n, x], int(), Ownership::Owned, None)
+                .unwrap()
+        } else {
+            x
+        };
+        builder.emit_return(Some(result)).unwrap();
+        builder.finalize();
+        (function, x, merge_block)
+    }
+
+    #[test]
+    fn value_defined_in_one_branch_is_rejected() {
+        let (function, x, merge_block) = branch_function(false);
+        match verify_function(&function) {
+            Err(IrVerifyError::UndefinedOnSomePath { value, block }) => {
+                assert_eq!(value, x);
+                assert_eq!(block, merge_block);
+            }
+            other => panic!("expected UndefinedOnSomePath, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn phi_of_branch_values_is_accepted() {
+        let (function, _, _) = branch_function(true);
+        verify_function(&function).expect("phi operands are defined on their edges");
+    }
+}
+
 //==============================================
 // End of file
 //==============================================
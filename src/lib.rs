@@ -1,5 +1,6 @@
 pub mod ast;
 pub mod bytecode;
+pub mod diagnostics;
 pub mod interpreter;
 pub mod parser;
 pub mod resolver;
@@ -12,6 +12,7 @@
 mod ast;
 mod bytecode;
 mod core_bridge;
+mod diagnostics;
 mod interpreter;
 mod ir;
 mod modules;
@@ -34,20 +35,27 @@ use std::sync::Arc;
 
 use anyhow::{Context, Result, anyhow};
 use bincode;
-use clap::{Args as ClapArgs, Parser, Subcommand};
+use clap::{Args as ClapArgs, Parser, Subcommand, ValueEnum};
+use compiler::tier2::Tier2Options;
+use diagnostics::Diagnostic;
+use ir::display::CfgFormatter;
 use ir::interpreter::{IrInterpreter, RuntimeValue};
+use ir::ir::SolvraIrModule;
 use ir::lowering::lower_program;
+use ir::opt::PassManager;
 use ir::verify::verify_function;
-use parser::{ParseError, Parser as AstParser};
+use parser::Parser as AstParser;
 use resolver::{Diagnostics, SymbolResolution, resolve_module};
 use serde_json::json;
 use solvra_core::jit::tier0_codegen::Tier0Compiler;
 use solvra_core::vm::bytecode::VmBytecode;
 use solvra_core::{SolvraError, StackFrame, Value};
-use tokenizer::Tokenizer;
+use solvrascript::runtime::{TestOutcome, TestRunOptions, run_test_dir_with};
 use vm::TelemetryCollector;
-use vm::compiler as vm_compiler;
-use vm::runtime::{MemoryTracker, RuntimeOptions, SolvraProgram, run_bytecode};
+use vm::compile_cache::CompileCache;
+use vm::compiler::{self as vm_compiler, CompilerOptions};
+use vm::jit_cache::JitCodeCache;
+use vm::runtime::{MemoryTracker, RuntimeOptions, SolvraProgram, TraceFilter, run_bytecode};
 
 #[derive(Parser, Debug)]
 #[command(name = "solvrascript", about = "SolvraScript CLI")]
@@ -62,6 +70,26 @@ pub enum Command {
     Run(RunArgs),
     /// Compile a .svs source file into .svc bytecode.
     Compile(CompileArgs),
+    /// Print a readable listing of .svc bytecode, or of a .svs file's
+    /// compiled output.
+    Disassemble {
+        /// Input .svc bytecode or .svs source file.
+        input: PathBuf,
+    },
+    /// Run every `test_*` function in the .svs files under a directory.
+    Test {
+        /// Directory to search for .svs test files.
+        dir: PathBuf,
+        /// Number of tests to run at once; defaults to the number of cores.
+        #[arg(short = 'j', long = "jobs")]
+        jobs: Option<usize>,
+        /// Print line coverage merged across all tests.
+        #[arg(long = "coverage")]
+        coverage: bool,
+        /// Also write the merged coverage as an LCOV tracefile; implies --coverage.
+        #[arg(long = "lcov")]
+        lcov: Option<PathBuf>,
+    },
 }
 
 #[derive(ClapArgs, Debug, Clone)]
@@ -71,6 +99,29 @@ pub struct CompileArgs {
     /// Output .svc bytecode file.
     #[arg(short = 'o', long = "output")]
     pub output: PathBuf,
+
+    /// Diagnostic output format.
+    #[arg(long = "format", value_enum, default_value_t = DiagnosticFormat::Text)]
+    pub format: DiagnosticFormat,
+
+    /// Stop reporting after this many errors.
+    #[arg(
+        long = "max-errors",
+        default_value_t = DEFAULT_MAX_ERRORS,
+        value_parser = clap::value_parser!(usize).range(1..)
+    )]
+    pub max_errors: usize,
+}
+
+/// Default for `--max-errors`; enough to fix in one pass without flooding the terminal.
+const DEFAULT_MAX_ERRORS: usize = 20;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticFormat {
+    /// Human-readable `path:line:column` lines on stderr.
+    Text,
+    /// A single `{"diagnostics": [...]}` JSON document on stdout.
+    Json,
 }
 
 #[derive(ClapArgs, Debug, Clone)]
@@ -102,6 +153,15 @@ pub struct RunArgs {
     #[arg(long = "emit-regalloc")]
     pub emit_regalloc: bool,
 
+    /// Print each IR function's control-flow graph as Graphviz DOT and exit.
+    #[arg(long = "emit-cfg")]
+    pub emit_cfg: bool,
+
+    /// Run these comma-separated IR optimization passes after lowering;
+    /// `default` selects the default pipeline.
+    #[arg(long = "ir-opt-passes")]
+    pub ir_opt_passes: Option<String>,
+
     /// Enable Tier-0 JIT dispatch.
     #[arg(long = "jit-tier0")]
     pub jit_tier0: bool,
@@ -141,9 +201,74 @@ pub struct RunArgs {
     /// Print Tier-2 OSR/metadata debug information.
     #[arg(long = "jit-osr-tier2-debug")]
     pub jit_osr_tier2_debug: bool,
+
+    /// Inline calls to small, non-recursive functions before JIT lowering.
+    /// Takes an optional instruction limit for inlined callees.
+    #[arg(long = "jit-tier2-inline", num_args = 0..=1, default_missing_value = "8")]
+    pub jit_tier2_inline: Option<usize>,
+
+    /// Cap Tier-0 code at this many bytes of Tier-0 listing, evicting the
+    /// least recently used functions back to the interpreter once it is full.
+    /// An evicted function is compiled again after 1000 interpreted calls.
+    #[arg(long = "jit-code-cache-listing-bytes")]
+    pub jit_code_cache_listing_bytes: Option<usize>,
+
+    /// Diagnostic output format.
+    #[arg(long = "format", value_enum, default_value_t = DiagnosticFormat::Text)]
+    pub format: DiagnosticFormat,
+
+    /// Stop reporting after this many errors.
+    #[arg(
+        long = "max-errors",
+        default_value_t = DEFAULT_MAX_ERRORS,
+        value_parser = clap::value_parser!(usize).range(1..)
+    )]
+    pub max_errors: usize,
+
+    /// Compile with source line tables and without bytecode optimizations.
+    #[arg(long = "debug")]
+    pub debug: bool,
+
+    /// Reuse compiled bytecode and lowered IR from this directory when the
+    /// source is unchanged.
+    #[arg(long = "cache-dir")]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Disable compile-time constant folding.
+    #[arg(long = "no-const-fold")]
+    pub no_const_fold: bool,
+
+    /// Disable hoisting of loop-invariant expressions.
+    #[arg(long = "no-licm")]
+    pub no_licm: bool,
+
+    /// Print VM memory statistics, including garbage collection counts,
+    /// after the script finishes.
+    #[arg(long = "memory-stats")]
+    pub memory_stats: bool,
+
+    /// Record runtime telemetry, including per-function calls and time, and
+    /// print it as JSON with the hottest functions after the script finishes.
+    #[arg(long = "telemetry")]
+    pub telemetry: bool,
+
+    /// Print each VM opcode as it executes.
+    #[arg(long = "trace")]
+    pub trace: bool,
+
+    /// Only trace opcodes inside functions whose name matches this regex;
+    /// implies --trace.
+    #[arg(long = "trace-filter")]
+    pub trace_filter: Option<String>,
+
+    /// Directory to search for imported script modules; may be repeated.
+    /// Directories are searched in the order given, after the script's own.
+    #[arg(long = "module-path")]
+    pub module_paths: Vec<PathBuf>,
 }
 
 struct ParsedModule {
+    source: String,
     program: ast::Program,
     resolutions: SymbolResolution,
 }
@@ -152,21 +277,45 @@ fn main() -> Result<()> {
     let args = Args::parse();
 
     match args.command {
-        Command::Compile(cmd) => compile_svs_to_svc(&cmd.input, &cmd.output),
+        Command::Compile(cmd) => {
+            compile_svs_to_svc(&cmd.input, &cmd.output, cmd.format, cmd.max_errors)
+        }
         Command::Run(cmd) => run_entry(cmd),
+        Command::Disassemble { input } => disassemble_file(&input),
+        Command::Test {
+            dir,
+            jobs,
+            coverage,
+            lcov,
+        } => run_tests(&dir, jobs, coverage, lcov.as_deref()),
     }
 }
 
 fn run_entry(args: RunArgs) -> Result<()> {
-    let options = RuntimeOptions {
+    modules::set_global_script_paths(&args.module_paths);
+    let trace_filter = args
+        .trace_filter
+        .as_deref()
+        .map(TraceFilter::new)
+        .transpose()
+        .map_err(|err| anyhow!("invalid --trace-filter: {err}"))?;
+    let telemetry = args.telemetry.then(TelemetryCollector::new);
+    let mut options = RuntimeOptions {
+        trace: args.trace || trace_filter.is_some(),
+        trace_filter,
         jit_tier0: args.jit_tier0,
         jit_tier1: args.jit_tier1,
         jit_deopt_debug: args.jit_deopt_debug,
         jit_transfer_debug: args.jit_transfer_debug,
         jit_tier2: args.jit_tier2,
         jit_osr_tier2_debug: args.jit_osr_tier2_debug,
+        jit_code_cache: args.jit_code_cache_listing_bytes.map(JitCodeCache::new),
+        source_name: Some(args.script.display().to_string()),
         ..Default::default()
     };
+    if let Some(collector) = &telemetry {
+        options = options.with_telemetry_collector(collector.clone());
+    }
 
     if args
         .script
@@ -176,18 +325,38 @@ fn run_entry(args: RunArgs) -> Result<()> {
     {
         run_svc_file(&args.script, options)
     } else {
-        let parsed = parse_source(&args.script)?;
+        let parsed = parse_source(&args.script, args.format, args.max_errors)?;
+        let mut compiler_options = if args.debug {
+            CompilerOptions::debug()
+        } else {
+            CompilerOptions::release()
+        };
+        if args.no_const_fold {
+            compiler_options.fold_constants = false;
+        }
+        if args.no_licm {
+            compiler_options.licm = false;
+        }
+        let cache = args.cache_dir.map(CompileCache::new);
+        let ir_passes = args
+            .ir_opt_passes
+            .as_deref()
+            .map(PassManager::parse)
+            .transpose()
+            .map_err(|err| anyhow!("invalid --ir-opt-passes: {err}"))?;
         run_source_program(
             &args.script,
-            &parsed.program,
-            &parsed.resolutions,
+            &parsed,
             options,
+            compiler_options,
+            cache.as_ref(),
             args.print_ast,
             args.enable_ir,
             args.emit_tier0,
             args.emit_mir,
             args.emit_mir_verified,
             args.emit_regalloc,
+            args.emit_cfg,
             args.jit_tier0,
             args.jit_tier1,
             args.jit_stats,
@@ -196,23 +365,27 @@ fn run_entry(args: RunArgs) -> Result<()> {
             args.jit_osr_debug,
             args.jit_transfer_debug,
             args.jit_osr_validate,
-            None,
-            None,
+            args.jit_tier2_inline,
+            ir_passes.as_ref(),
+            telemetry,
+            args.memory_stats.then(MemoryTracker::new),
         )
     }
 }
 
 fn run_source_program(
     _path: &Path,
-    program: &ast::Program,
-    resolutions: &SymbolResolution,
+    parsed: &ParsedModule,
     options: RuntimeOptions,
+    compiler_options: CompilerOptions,
+    cache: Option<&CompileCache>,
     print_ast: bool,
     enable_ir: bool,
     emit_tier0: bool,
     emit_mir: bool,
     emit_mir_verified: bool,
     emit_regalloc: bool,
+    emit_cfg: bool,
     jit_tier0: bool,
     jit_tier1: bool,
     jit_stats: bool,
@@ -221,21 +394,32 @@ fn run_source_program(
     jit_osr_debug: bool,
     jit_transfer_debug: bool,
     jit_osr_validate: bool,
+    jit_tier2_inline: Option<usize>,
+    ir_passes: Option<&PassManager>,
     telemetry: Option<TelemetryCollector>,
     memory_tracker: Option<MemoryTracker>,
 ) -> Result<()> {
+    let program = &parsed.program;
+    let resolutions = &parsed.resolutions;
     if print_ast {
         println!("{:#?}", program);
     }
 
     if emit_tier0 {
-        return run_tier0_pipeline(&program, resolutions);
+        return run_tier0_pipeline(&program, resolutions, &parsed.source, ir_passes, cache);
+    }
+
+    if emit_cfg {
+        return run_cfg_pipeline(&program, resolutions, &parsed.source, ir_passes, cache);
     }
 
     if emit_mir || emit_mir_verified || emit_regalloc {
         return run_tier1_debug_pipeline(
             &program,
             resolutions,
+            &parsed.source,
+            ir_passes,
+            cache,
             emit_mir,
             emit_mir_verified,
             emit_regalloc,
@@ -243,13 +427,16 @@ fn run_source_program(
     }
 
     if enable_ir {
-        return run_ir_pipeline(&program, resolutions);
+        return run_ir_pipeline(&program, resolutions, &parsed.source, ir_passes, cache);
     }
 
     run_vm_pipeline(
         &program,
         resolutions,
+        &parsed.source,
         options,
+        compiler_options,
+        cache,
         jit_tier0,
         jit_tier1,
         jit_stats,
@@ -258,6 +445,8 @@ fn run_source_program(
         jit_osr_debug,
         jit_transfer_debug,
         jit_osr_validate,
+        jit_tier2_inline,
+        ir_passes,
         telemetry,
         memory_tracker,
     )
@@ -266,7 +455,10 @@ fn run_source_program(
 fn run_vm_pipeline(
     program: &ast::Program,
     resolutions: &SymbolResolution,
+    source: &str,
     mut options: RuntimeOptions,
+    compiler_options: CompilerOptions,
+    cache: Option<&CompileCache>,
     jit_tier0: bool,
     jit_tier1: bool,
     jit_stats: bool,
@@ -275,6 +467,8 @@ fn run_vm_pipeline(
     jit_osr_debug: bool,
     jit_transfer_debug: bool,
     jit_osr_validate: bool,
+    jit_tier2_inline: Option<usize>,
+    ir_passes: Option<&PassManager>,
     telemetry: Option<TelemetryCollector>,
     memory_tracker: Option<MemoryTracker>,
 ) -> Result<()> {
@@ -286,10 +480,17 @@ fn run_vm_pipeline(
     options.jit_osr_debug = jit_osr_debug;
     options.jit_transfer_debug = jit_transfer_debug;
     options.jit_osr_validate = jit_osr_validate;
+    if let Some(tracker) = &memory_tracker {
+        options = options.with_memory_tracker(tracker.clone());
+    }
 
     if jit_tier0 || jit_stats || jit_tier1 {
-        let module = lower_program(program, resolutions)
-            .map_err(|err| anyhow!("IR lowering failed: {err}"))?;
+        let mut module = lower_module(program, resolutions, source, ir_passes, cache)?;
+        if let Some(threshold) = jit_tier2_inline {
+            let tier2_options = Tier2Options::default().with_inline_threshold(threshold);
+            compiler::tier2::inline_module(&mut module, &tier2_options);
+        }
+        compiler::tier2::layout_module(&mut module);
         let module_arc = Arc::new(module);
         if jit_tier0 || jit_stats {
             options.jit_ir_module = Some(Arc::clone(&module_arc));
@@ -312,27 +513,48 @@ fn run_vm_pipeline(
         }
     }
 
-    let bytecode =
-        vm_compiler::compile_program(program).map_err(|err| anyhow!("compiler error: {err}"))?;
-    let vm_program =
-        VmBytecode::decode(&bytecode[..]).map_err(|err| anyhow!("bytecode decode error: {err}"))?;
-    let value = execute_vm(Arc::new(vm_program), options)?;
-    emit_runtime_value(&value);
-    emit_runtime_metrics(telemetry, memory_tracker)?;
-    Ok(())
+    let compile = || {
+        vm_compiler::compile_program_with_options(program, compiler_options)
+            .map_err(|err| anyhow!("compiler error: {err}"))
+    };
+    let compiled = match cache {
+        Some(cache) => cache.get_or_compile(source, compiler_options, compile)?.0,
+        None => compile()?,
+    };
+    let vm_program = VmBytecode::decode(&compiled.bytecode[..])
+        .map_err(|err| anyhow!("bytecode decode error: {err}"))?;
+    if let Some(debug_info) = compiled.debug_info {
+        options = options.with_debug_info(Arc::new(debug_info));
+    }
+    match execute_vm(Arc::new(vm_program), options) {
+        Ok(value) => {
+            emit_runtime_value(&value);
+            emit_runtime_metrics(telemetry, memory_tracker)
+        }
+        // Metrics gathered before a timeout or runtime error are still reported.
+        Err(err) => {
+            emit_runtime_metrics(telemetry, memory_tracker)?;
+            Err(err)
+        }
+    }
 }
 
 fn run_svc_file(path: &Path, options: RuntimeOptions) -> Result<()> {
     let bytes = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
-    let bytecode: VmBytecode =
-        bincode::deserialize(&bytes).map_err(|err| anyhow!("svc decode error: {err}"))?;
+    let bytecode =
+        vm::svc::decode_svc(&bytes).map_err(|err| anyhow!("{}: {err}", path.display()))?;
     let value = execute_vm(Arc::new(bytecode), options)?;
     emit_runtime_value(&value);
     Ok(())
 }
 
-fn compile_svs_to_svc(input: &Path, output: &Path) -> Result<()> {
-    let parsed = parse_source(input)?;
+fn compile_svs_to_svc(
+    input: &Path,
+    output: &Path,
+    format: DiagnosticFormat,
+    max_errors: usize,
+) -> Result<()> {
+    let parsed = parse_source(input, format, max_errors)?;
     let bytecode = vm_compiler::compile_program(&parsed.program)
         .map_err(|err| anyhow!("compiler error: {err}"))?;
     let vm_program =
@@ -343,9 +565,112 @@ fn compile_svs_to_svc(input: &Path, output: &Path) -> Result<()> {
     Ok(())
 }
 
-fn run_ir_pipeline(program: &ast::Program, resolutions: &SymbolResolution) -> Result<()> {
-    let module =
-        lower_program(program, resolutions).map_err(|err| anyhow!("IR lowering failed: {err}"))?;
+fn disassemble_file(input: &Path) -> Result<()> {
+    let bytecode = if input.extension().map(|ext| ext == "svs").unwrap_or(false) {
+        let parsed = parse_source(input, DiagnosticFormat::Text, DEFAULT_MAX_ERRORS)?;
+        let bytes = vm_compiler::compile_program(&parsed.program)
+            .map_err(|err| anyhow!("compiler error: {err}"))?;
+        VmBytecode::decode(&bytes[..]).map_err(|err| anyhow!("bytecode decode error: {err}"))?
+    } else {
+        let bytes =
+            fs::read(input).with_context(|| format!("failed to read {}", input.display()))?;
+        vm::svc::decode_svc(&bytes).map_err(|err| anyhow!("{}: {err}", input.display()))?
+    };
+    print!("{}", vm::disasm::disassemble(&bytecode));
+    Ok(())
+}
+
+fn run_tests(dir: &Path, jobs: Option<usize>, coverage: bool, lcov: Option<&Path>) -> Result<()> {
+    let start = std::time::Instant::now();
+    let mut options = TestRunOptions {
+        coverage: coverage || lcov.is_some(),
+        ..TestRunOptions::default()
+    };
+    if let Some(jobs) = jobs {
+        options.jobs = jobs;
+    }
+    let summary = run_test_dir_with(dir, &options)
+        .with_context(|| format!("failed to read {}", dir.display()))?;
+    for result in &summary.results {
+        let file = result.file.strip_prefix(dir).unwrap_or(&result.file);
+        let label = format!("{}::{}", file.display(), result.name);
+        let elapsed_ms = result.elapsed.as_secs_f64() * 1000.0;
+        match &result.outcome {
+            TestOutcome::Passed => println!("ok   {label} ({elapsed_ms:.1}ms)"),
+            TestOutcome::Failed(message) => {
+                println!("FAIL {label} ({elapsed_ms:.1}ms)");
+                for line in message.lines() {
+                    println!("    {line}");
+                }
+            }
+        }
+    }
+    println!(
+        "\ntest result: {} passed; {} failed; finished in {:.2}s",
+        summary.passed(),
+        summary.failed(),
+        start.elapsed().as_secs_f64()
+    );
+    if let Some(report) = summary.coverage() {
+        println!("\ncoverage:\n{}", report.summary().trim_end());
+        if let Some(path) = lcov {
+            fs::write(path, report.to_lcov())
+                .with_context(|| format!("failed to write {}", path.display()))?;
+        }
+    }
+    if summary.failed() > 0 {
+        return Err(anyhow!("{} test(s) failed", summary.failed()));
+    }
+    Ok(())
+}
+
+/// Lower `program` to SolvraIR and run `passes` over it. With a `cache`,
+/// the optimized module is stored under a hash of `source` and the pass list,
+/// and later runs decode it instead of lowering again.
+fn lower_module(
+    program: &ast::Program,
+    resolutions: &SymbolResolution,
+    source: &str,
+    passes: Option<&PassManager>,
+    cache: Option<&CompileCache>,
+) -> Result<SolvraIrModule> {
+    let lower = || -> Result<SolvraIrModule> {
+        let mut module = lower_program(program, resolutions)
+            .map_err(|err| anyhow!("IR lowering failed: {err}"))?;
+        if let Some(passes) = passes {
+            let report = passes.run(&mut module);
+            let fired: Vec<&str> = report.fired().iter().map(|pass| pass.name()).collect();
+            eprintln!(
+                "ir-opt: {} round(s), {}; changed by: {}",
+                report.iterations,
+                if report.reached_fixpoint {
+                    "fixpoint reached"
+                } else {
+                    "iteration cap hit"
+                },
+                if fired.is_empty() {
+                    "none".to_string()
+                } else {
+                    fired.join(", ")
+                }
+            );
+        }
+        Ok(module)
+    };
+    match cache {
+        Some(cache) => Ok(cache.get_or_lower(source, passes, lower)?.0),
+        None => lower(),
+    }
+}
+
+fn run_ir_pipeline(
+    program: &ast::Program,
+    resolutions: &SymbolResolution,
+    source: &str,
+    passes: Option<&PassManager>,
+    cache: Option<&CompileCache>,
+) -> Result<()> {
+    let module = lower_module(program, resolutions, source, passes, cache)?;
     for function in module.functions() {
         verify_function(function)
             .map_err(|err| anyhow!("IR verification failed for {}: {err}", function.name))?;
@@ -360,9 +685,14 @@ fn run_ir_pipeline(program: &ast::Program, resolutions: &SymbolResolution) -> Re
     Ok(())
 }
 
-fn run_tier0_pipeline(program: &ast::Program, resolutions: &SymbolResolution) -> Result<()> {
-    let module =
-        lower_program(program, resolutions).map_err(|err| anyhow!("IR lowering failed: {err}"))?;
+fn run_tier0_pipeline(
+    program: &ast::Program,
+    resolutions: &SymbolResolution,
+    source: &str,
+    passes: Option<&PassManager>,
+    cache: Option<&CompileCache>,
+) -> Result<()> {
+    let module = lower_module(program, resolutions, source, passes, cache)?;
     let compiler = Tier0Compiler::new();
     for function in module.functions() {
         verify_function(function)
@@ -375,15 +705,38 @@ fn run_tier0_pipeline(program: &ast::Program, resolutions: &SymbolResolution) ->
     Ok(())
 }
 
+fn run_cfg_pipeline(
+    program: &ast::Program,
+    resolutions: &SymbolResolution,
+    source: &str,
+    passes: Option<&PassManager>,
+    cache: Option<&CompileCache>,
+) -> Result<()> {
+    let module = lower_module(program, resolutions, source, passes, cache)?;
+    for function in module.functions() {
+        println!("{}", CfgFormatter::new(function));
+    }
+    Ok(())
+}
+
 fn run_tier1_debug_pipeline(
     program: &ast::Program,
     resolutions: &SymbolResolution,
+    source: &str,
+    passes: Option<&PassManager>,
+    cache: Option<&CompileCache>,
     emit_mir: bool,
     emit_verified: bool,
     emit_regalloc: bool,
 ) -> Result<()> {
-    let module =
-        lower_program(program, resolutions).map_err(|err| anyhow!("IR lowering failed: {err}"))?;
+    let mut module = lower_module(program, resolutions, source, passes, cache)?;
+    compiler::tier2::layout_module(&mut module);
+    if emit_verified {
+        for function in module.functions() {
+            verify_function(function)
+                .map_err(|err| anyhow!("IR verification failed for {}: {err}", function.name))?;
+        }
+    }
     let lowered = compiler::tier1::lower_ir_to_mir(&module);
     let mir_module = lowered.module;
     if emit_verified {
@@ -419,15 +772,33 @@ fn emit_runtime_value(value: &Value) {
     }
 }
 
+/// How many functions the `--telemetry` summary lists.
+const HOT_FUNCTION_LIMIT: usize = 10;
+
 fn emit_runtime_metrics(
     telemetry: Option<TelemetryCollector>,
     memory_tracker: Option<MemoryTracker>,
 ) -> Result<()> {
     if let Some(collector) = telemetry {
         let events = collector.snapshot();
-        let json = serde_json::to_string(&json!({ "events": events }))
-            .map_err(|err| anyhow!("failed to serialise telemetry events: {err}"))?;
+        let call_summary = collector.call_summary();
+        let json = serde_json::to_string(&json!({
+            "events": events,
+            "call_summary": call_summary,
+        }))
+        .map_err(|err| anyhow!("failed to serialise telemetry events: {err}"))?;
         println!("{json}");
+        if !call_summary.is_empty() {
+            eprintln!("[telemetry] hottest functions:");
+            for stat in call_summary.iter().take(HOT_FUNCTION_LIMIT) {
+                eprintln!(
+                    " - fn={} calls={} total_ms={:.3}",
+                    stat.name,
+                    stat.calls,
+                    stat.total_nanos as f64 / 1_000_000.0
+                );
+            }
+        }
     }
 
     if let Some(tracker) = memory_tracker {
@@ -440,61 +811,78 @@ fn emit_runtime_metrics(
     Ok(())
 }
 
-fn parse_source(path: &Path) -> Result<ParsedModule> {
+fn parse_source(path: &Path, format: DiagnosticFormat, max_errors: usize) -> Result<ParsedModule> {
     let source =
         fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
-    let mut tokenizer = Tokenizer::new(&source);
-    let tokens = tokenizer
-        .tokenize()
-        .map_err(|err| anyhow!("Tokenizer error: {err}"))?;
-    let mut parser = AstParser::new(tokens);
-    let program = parser
-        .parse()
-        .map_err(|error| map_parse_error(path, error))?;
-    let mut diagnostics = Diagnostics::new();
-    let resolutions = resolve_module(&program, &mut diagnostics);
-    if diagnostics.has_errors() {
-        for (name, position) in diagnostics.unresolved() {
-            eprintln!(
-                "{}:{}:{}: unresolved identifier '{}'",
-                path.display(),
-                position.line,
-                position.column,
-                name
-            );
+    let tokens = diagnostics::tokenize(&source)
+        .map_err(|diagnostic| {
+            report_diagnostics(path, format, max_errors, vec![*diagnostic], 0)
+        })?;
+    let mut parser = AstParser::new(tokens).with_max_errors(max_errors);
+    let program = match parser.parse_recovering() {
+        (Some(program), errors) if errors.is_empty() => program,
+        (_, errors) => {
+            let items = errors.iter().map(Diagnostic::from).collect();
+            let omitted = parser.omitted_errors();
+            return Err(report_diagnostics(path, format, max_errors, items, omitted));
         }
+    };
+    let mut resolver_diagnostics = Diagnostics::new();
+    let resolutions = resolve_module(&program, &mut resolver_diagnostics);
+    let items = diagnostics::from_resolver(&resolver_diagnostics);
+    if items.iter().any(Diagnostic::is_error) {
+        return Err(report_diagnostics(path, format, max_errors, items, 0));
+    }
+    if !items.is_empty() {
+        emit_diagnostics(path, format, &items, 0);
     }
     Ok(ParsedModule {
+        source,
         program,
         resolutions,
     })
 }
 
-fn map_parse_error(path: &Path, error: ParseError) -> anyhow::Error {
-    match error {
-        ParseError::UnexpectedToken {
-            expected,
-            found,
-            position,
-        } => anyhow!(
-            "{}:{}:{}: expected {}, found {:?}",
-            path.display(),
-            position.line,
-            position.column,
-            expected,
-            found
-        ),
-        ParseError::UnexpectedEndOfInput { expected, position } => anyhow!(
-            "{}:{}:{}: unexpected end of input (expected {})",
-            path.display(),
-            position.line,
-            position.column,
-            expected
-        ),
-        other => anyhow!("{other:?}"),
+/// Print `items`, noting how many errors were `omitted` by the cap.
+fn emit_diagnostics(path: &Path, format: DiagnosticFormat, items: &[Diagnostic], omitted: usize) {
+    match format {
+        DiagnosticFormat::Text => {
+            let display = path.display().to_string();
+            for diagnostic in items {
+                eprintln!("{}", diagnostic.render(&display));
+            }
+            if omitted > 0 {
+                eprintln!("… and {omitted} more");
+            }
+        }
+        DiagnosticFormat::Json => {
+            let mut document = diagnostics::to_json(items);
+            if omitted > 0 {
+                document["omitted"] = omitted.into();
+            }
+            println!("{document}");
+        }
     }
 }
 
+fn report_diagnostics(
+    path: &Path,
+    format: DiagnosticFormat,
+    max_errors: usize,
+    mut items: Vec<Diagnostic>,
+    already_omitted: usize,
+) -> anyhow::Error {
+    let errors = items.iter().filter(|item| item.is_error()).count() + already_omitted;
+    let omitted = diagnostics::cap_errors(&mut items, max_errors) + already_omitted;
+    emit_diagnostics(path, format, &items, omitted);
+    anyhow!(
+        "{}: aborting due to {} error{}",
+        path.display(),
+        errors,
+        if errors == 1 { "" } else { "s" }
+    )
+}
+
 fn value_to_string(value: &Value) -> String {
     match value {
         Value::Null => "null".into(),
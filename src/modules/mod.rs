@@ -12,9 +12,13 @@ use std::ffi::OsStr;
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 pub mod core_vm;
+pub mod resolver;
+
+pub use resolver::ModuleResolver;
 
 const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
 const FNV_PRIME: u64 = 0x1000_0000_01b3;
@@ -25,6 +29,17 @@ pub fn set_global_hot_reload(enabled: bool) {
     GLOBAL_HOT_RELOAD.store(enabled, Ordering::Relaxed);
 }
 
+static GLOBAL_SCRIPT_PATHS: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+/// Extra script search directories every new [`ModuleLoader`] picks up,
+/// searched in order after its built-in roots. Used for `--module-path`.
+pub fn set_global_script_paths(paths: &[PathBuf]) {
+    let mut global = GLOBAL_SCRIPT_PATHS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *global = paths.to_vec();
+}
+
 #[derive(Debug, Clone)]
 pub enum ModuleOrigin {
     Script(PathBuf),
@@ -89,7 +104,10 @@ struct ParsedScript {
 
 #[derive(Debug)]
 pub enum ModuleError {
-    NotFound { module: String },
+    NotFound {
+        module: String,
+        searched: Vec<PathBuf>,
+    },
     Io { path: PathBuf, error: io::Error },
     Tokenize { path: PathBuf, error: String },
     Parse { path: PathBuf, error: ParseError },
@@ -100,8 +118,16 @@ pub enum ModuleError {
 impl std::fmt::Display for ModuleError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ModuleError::NotFound { module } => {
-                write!(f, "Module '{}' could not be located", module)
+            ModuleError::NotFound { module, searched } => {
+                write!(f, "Module '{}' could not be located", module)?;
+                if !searched.is_empty() {
+                    let dirs: Vec<String> = searched
+                        .iter()
+                        .map(|dir| dir.display().to_string())
+                        .collect();
+                    write!(f, " (searched: {})", dirs.join(", "))?;
+                }
+                Ok(())
             }
             ModuleError::Io { path, error } => {
                 write!(f, "Failed reading module '{}': {}", path.display(), error)
@@ -136,7 +162,7 @@ impl std::error::Error for ModuleError {}
 
 #[derive(Debug)]
 pub struct ModuleLoader {
-    script_paths: Vec<PathBuf>,
+    script_resolver: ModuleResolver,
     stdlib_paths: Vec<PathBuf>,
     compiled_paths: Vec<PathBuf>,
     stdlib: StdlibContext,
@@ -177,9 +203,21 @@ impl ModuleLoader {
             })
             .unwrap_or(false);
         let hot_reload = GLOBAL_HOT_RELOAD.load(Ordering::Relaxed) || env_hot_reload;
+        let mut script_resolver = ModuleResolver::with_search_dirs([
+            current_dir.clone(),
+            src_root.clone(),
+            stdx_root.clone(),
+            stdx_core_root.clone(),
+        ]);
+        for path in GLOBAL_SCRIPT_PATHS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+        {
+            script_resolver.add_search_dir(path.clone());
+        }
         Self {
-            script_paths: vec![current_dir.clone(), src_root.clone(), stdx_root.clone(),
-            stdx_core_root.clone()],
+            script_resolver,
             stdlib_paths: vec![stdx_root.clone(), compat_root.clone()],
             compiled_paths: vec![stdx_root.clone(), compat_root.clone(), cache_dir.clone()],
             stdlib,
@@ -193,10 +231,7 @@ impl ModuleLoader {
     }
 
     pub fn add_script_path<P: Into<PathBuf>>(&mut self, path: P) {
-        let path = path.into();
-        if !self.script_paths.contains(&path) {
-            self.script_paths.push(path);
-        }
+        self.script_resolver.add_search_dir(path);
     }
 
     pub fn add_stdlib_path<P: Into<PathBuf>>(&mut self, path: P) {
@@ -206,8 +241,7 @@ impl ModuleLoader {
     }
 
     pub fn script_search_paths(&self) -> Vec<PathBuf> {
-        self.script_paths.clone()
-        
+        self.script_resolver.search_dirs().to_vec()
     }
 
     pub fn preload_standard_modules(&mut self) {
@@ -343,46 +377,31 @@ impl ModuleLoader {
             return Ok(direct);
         }
 
-        let mut candidates = Vec::new();
-        if let Some(base) = base_dir {
-            candidates.push(base.join(module_path));
-        }
-        for root in &self.script_paths {
-            candidates.push(root.join(module_path));
+        if let Some(found) =
+            base_dir.and_then(|base| ModuleResolver::probe(&base.join(module_path)))
+        {
+            println!(
+                "[debug] Found script '{}' -> {}",
+                module_path,
+                found.display()
+            );
+            return Ok(found);
         }
 
-        for candidate in candidates {
-            if candidate.exists() {
-                println!(
-                    "[debug] Found script '{}' -> {}",
-                    module_path,
-                    candidate.display()
-                );
-                if candidate.is_file() {
-                    return Ok(candidate);
-                }
-                if candidate.is_dir() {
-                    let mod_svs = candidate.join("mod.svs");
-                    if mod_svs.exists() && mod_svs.is_file() {
-                        return Ok(mod_svs);
-                    }
-                    let with_ext = candidate.with_extension("svs");
-                    if with_ext.exists() {
-                        return Ok(with_ext);
+        self.script_resolver
+            .resolve(module_path)
+            .map_err(|error| match error {
+                ModuleError::NotFound {
+                    module,
+                    mut searched,
+                } => {
+                    if let Some(base) = base_dir {
+                        searched.insert(0, base.to_path_buf());
                     }
+                    ModuleError::NotFound { module, searched }
                 }
-            }
-            if candidate.extension().is_none() {
-                let with_ext = candidate.with_extension("svs");
-                if with_ext.exists() {
-                    return Ok(with_ext);
-                }
-            }
-        }
-
-        Err(ModuleError::NotFound {
-            module: module_path.to_string(),
-        })
+                other => other,
+            })
     }
 
     fn load_script_descriptor(
@@ -499,6 +518,7 @@ impl ModuleLoader {
 
         Err(ModuleError::NotFound {
             module: format!("std::{}", name),
+            searched: self.stdlib_paths.clone(),
         })
     }
 
@@ -850,6 +870,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn global_script_paths_reach_new_loaders() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        fs::write(
+            dir.path().join("extra_path_mod.svs"),
+            "export fn help() { return 1; }\n",
+        )
+        .expect("write module");
+
+        set_global_script_paths(&[dir.path().to_path_buf()]);
+        let mut loader = ModuleLoader::new();
+        set_global_script_paths(&[]);
+
+        assert_eq!(
+            loader.script_search_paths().last(),
+            Some(&dir.path().to_path_buf())
+        );
+        let descriptor = loader
+            .prepare_module(
+                &ImportSource::ScriptPath("extra_path_mod.svs".to_string()),
+                None,
+            )
+            .expect("module found through the global path");
+        assert!(matches!(descriptor.origin, ModuleOrigin::Script(_)));
+    }
+
     #[test]
     fn export_declarations_are_recorded() {
         let dir = tempfile::tempdir().expect("create temp dir");
@@ -0,0 +1,131 @@
+//=====================================================
+// File: modules/resolver.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Explicit search-path resolution for script imports
+// Objective: Map an import string to a file by checking a configurable,
+//            ordered list of directories and report every place looked at
+//=====================================================
+
+use super::ModuleError;
+use std::path::{Path, PathBuf};
+
+/// Ordered list of directories that script imports are resolved against.
+/// Earlier directories win when a module exists in more than one.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleResolver {
+    search_dirs: Vec<PathBuf>,
+}
+
+impl ModuleResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_search_dirs<I, P>(dirs: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        let mut resolver = Self::new();
+        for dir in dirs {
+            resolver.add_search_dir(dir);
+        }
+        resolver
+    }
+
+    /// Append `dir` to the search order, ignoring duplicates.
+    pub fn add_search_dir<P: Into<PathBuf>>(&mut self, dir: P) {
+        let dir = dir.into();
+        if !self.search_dirs.contains(&dir) {
+            self.search_dirs.push(dir);
+        }
+    }
+
+    pub fn search_dirs(&self) -> &[PathBuf] {
+        &self.search_dirs
+    }
+
+    /// Find `import` in the first search directory that contains it. The
+    /// import may name a file (`util.svs`), omit the extension (`util`), or
+    /// name a directory containing `mod.svs`.
+    pub fn resolve(&self, import: &str) -> Result<PathBuf, ModuleError> {
+        let direct = Path::new(import);
+        if direct.is_absolute() && direct.is_file() {
+            return Ok(direct.to_path_buf());
+        }
+        self.search_dirs
+            .iter()
+            .find_map(|dir| Self::probe(&dir.join(import)))
+            .ok_or_else(|| ModuleError::NotFound {
+                module: import.to_string(),
+                searched: self.search_dirs.clone(),
+            })
+    }
+
+    /// Resolve `candidate` as a file, a module directory, or an extensionless
+    /// script name.
+    pub(crate) fn probe(candidate: &Path) -> Option<PathBuf> {
+        if candidate.is_file() {
+            return Some(candidate.to_path_buf());
+        }
+        if candidate.is_dir() {
+            let mod_svs = candidate.join("mod.svs");
+            if mod_svs.is_file() {
+                return Some(mod_svs);
+            }
+        }
+        if candidate.extension().is_none() || candidate.is_dir() {
+            let with_ext = candidate.with_extension("svs");
+            if with_ext.is_file() {
+                return Some(with_ext);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn resolves_module_from_second_search_dir() {
+        let first = tempfile::tempdir().expect("create first dir");
+        let second = tempfile::tempdir().expect("create second dir");
+        let module = second.path().join("helpers.svs");
+        fs::write(&module, "export fn help() { return 1; }\n").expect("write module");
+
+        let resolver = ModuleResolver::with_search_dirs([first.path(), second.path()]);
+        assert_eq!(resolver.resolve("helpers.svs").expect("resolve"), module);
+        assert_eq!(resolver.resolve("helpers").expect("resolve"), module);
+    }
+
+    #[test]
+    fn missing_module_lists_searched_dirs() {
+        let first = tempfile::tempdir().expect("create first dir");
+        let second = tempfile::tempdir().expect("create second dir");
+        let resolver = ModuleResolver::with_search_dirs([first.path(), second.path()]);
+
+        let error = resolver.resolve("absent").expect_err("module is missing");
+        match &error {
+            ModuleError::NotFound { module, searched } => {
+                assert_eq!(module, "absent");
+                assert_eq!(
+                    searched,
+                    &vec![first.path().to_path_buf(), second.path().to_path_buf()]
+                );
+            }
+            other => panic!("expected NotFound, got {other:?}"),
+        }
+        let message = error.to_string();
+        assert!(message.contains(&first.path().display().to_string()));
+        assert!(message.contains(&second.path().display().to_string()));
+    }
+}
+
+//=====================================================
+// End of file
+//=====================================================
@@ -15,10 +15,12 @@
 #![allow(dead_code)]
 
 use crate::ast::{
-    AssignTarget, BinaryOp, BindingKind, CatchBlock, ExportDecl, ExportItem, Expr, FunctionDecl,
-    ImportDecl, ImportSource, Literal, MatchArm, MemberKind, Parameter, Pattern, Program, Span,
-    Stmt, StringPart, Type, TypeNode, UnaryOp, VariableDecl, Visibility, next_node_id,
+    AssignTarget, BinaryOp, BindingKind, BranchHint, CatchBlock, ExportDecl, ExportItem, Expr,
+    FunctionDecl, ImportDecl, ImportSource, Literal, MatchArm, MemberKind, Parameter, Pattern,
+    Program, Span, Stmt, StringPart, Type, TypeNode, UnaryOp, VariableDecl, Visibility,
+    next_node_id,
 };
+use crate::diagnostics::{Fix, codes};
 use crate::symbol::Symbol;
 use crate::tokenizer::{Position, Token, TokenKind};
 
@@ -35,7 +37,7 @@ pub enum ParseError {
     UnexpectedToken {
         expected: String,
         found: TokenKind,
-        position: Position,
+        span: Span,
     },
     UnexpectedEndOfInput {
         expected: String,
@@ -45,20 +47,88 @@ pub enum ParseError {
         message: String,
         position: Position,
     },
+    /// A statement was not followed by `;` or a newline. `span` covers the
+    /// token found instead; `after` is the end of the statement.
+    MissingTerminator {
+        found: TokenKind,
+        span: Span,
+        after: Position,
+    },
+}
+
+impl ParseError {
+    /// Source position the error is reported at.
+    pub fn position(&self) -> &Position {
+        match self {
+            ParseError::UnexpectedToken { span, .. }
+            | ParseError::MissingTerminator { span, .. } => &span.start,
+            ParseError::UnexpectedEndOfInput { position, .. }
+            | ParseError::InvalidSyntax { position, .. } => position,
+        }
+    }
+
+    /// Source range covered by the error. Unexpected-token errors span the
+    /// offending token; other errors are a zero-width point.
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::UnexpectedToken { span, .. }
+            | ParseError::MissingTerminator { span, .. } => span.clone(),
+            _ => Span::new(self.position().clone(), self.position().clone()),
+        }
+    }
+
+    /// Stable diagnostic code for this error; see `diagnostics::explain`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParseError::UnexpectedToken { .. } | ParseError::MissingTerminator { .. } => {
+                codes::UNEXPECTED_TOKEN
+            }
+            ParseError::UnexpectedEndOfInput { .. } => codes::UNEXPECTED_END_OF_INPUT,
+            ParseError::InvalidSyntax { .. } => codes::INVALID_SYNTAX,
+        }
+    }
+
+    /// Suggest a quick fix for errors with an obvious mechanical repair, such
+    /// as a missing `;` between statements or an unclosed delimiter at EOF.
+    pub fn fix(&self) -> Option<Fix> {
+        if let ParseError::MissingTerminator { after, .. } = self {
+            return Some(Fix::insert("insert `;`", after.clone(), ";"));
+        }
+        let ParseError::UnexpectedToken {
+            expected,
+            found,
+            span,
+        } = self
+        else {
+            return None;
+        };
+        let insertion = match expected.as_str() {
+            "RightBrace" if *found == TokenKind::Eof => "}",
+            "RightParen" if *found == TokenKind::Eof => ")",
+            "RightBracket" if *found == TokenKind::Eof => "]",
+            _ => return None,
+        };
+        Some(Fix::insert(
+            format!("insert `{insertion}`"),
+            span.start.clone(),
+            insertion,
+        ))
+    }
 }
 
 impl std::fmt::Display for ParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] ", self.code())?;
         match self {
             ParseError::UnexpectedToken {
                 expected,
                 found,
-                position,
+                span,
             } => {
                 write!(
                     f,
                     "Expected {} but found {:?} at line {}, column {}",
-                    expected, found, position.line, position.column
+                    expected, found, span.start.line, span.start.column
                 )
             }
             ParseError::UnexpectedEndOfInput { expected, position } => {
@@ -75,6 +145,13 @@ impl std::fmt::Display for ParseError {
                     message, position.line, position.column
                 )
             }
+            ParseError::MissingTerminator { found, span, .. } => {
+                write!(
+                    f,
+                    "Expected statement terminator but found {:?} at line {}, column {}",
+                    found, span.start.line, span.start.column
+                )
+            }
         }
     }
 }
@@ -93,6 +170,8 @@ pub struct Parser {
     tokens: Vec<Token>,
     current: usize,
     expr_depth: usize,
+    max_errors: usize,
+    omitted_errors: usize,
 }
 
 const MAX_EXPRESSION_DEPTH: usize = 2048;
@@ -110,9 +189,24 @@ impl Parser {
             tokens,
             current: 0,
             expr_depth: 0,
+            max_errors: usize::MAX,
+            omitted_errors: 0,
         }
     }
 
+    /// Make `parse_recovering` keep only the first `max_errors` errors. It
+    /// still recovers through the rest of the file so the others are counted.
+    pub fn with_max_errors(mut self, max_errors: usize) -> Self {
+        self.max_errors = max_errors;
+        self
+    }
+
+    /// How many errors the last `parse_recovering` found beyond the cap set
+    /// with `with_max_errors`.
+    pub fn omitted_errors(&self) -> usize {
+        self.omitted_errors
+    }
+
     //=============================================
     //            Section 4: Token Navigation
     //=============================================
@@ -157,6 +251,98 @@ impl Parser {
         Ok(program)
     }
 
+    /// Parse a complete program, recovering at statement boundaries so that
+    /// every syntax error in the file is found. Errors past the cap set with
+    /// `with_max_errors` are only counted; see `omitted_errors`. Statements
+    /// that fail to parse are dropped from the returned program, which is
+    /// `None` only when no statement could be parsed at all.
+    pub fn parse_recovering(&mut self) -> (Option<Program>, Vec<ParseError>) {
+        self.omitted_errors = 0;
+        let position = self.current_position();
+        let mut statements = Vec::new();
+        let mut errors = Vec::new();
+
+        while !self.is_at_end() {
+            if matches!(
+                self.peek().kind,
+                TokenKind::Newline | TokenKind::Indent | TokenKind::Dedent | TokenKind::Comment(_)
+            ) {
+                self.advance();
+                continue;
+            }
+            let start = self.current;
+            match self.parse_statement() {
+                Ok(statement) => statements.push(statement),
+                Err(error) => {
+                    if errors.len() < self.max_errors {
+                        errors.push(error);
+                    } else {
+                        self.omitted_errors += 1;
+                    }
+                    self.synchronize(start);
+                }
+            }
+        }
+
+        if statements.is_empty() && !errors.is_empty() {
+            return (None, errors);
+        }
+        let mut program = Program::new(statements, position);
+        program.ensure_entry_point();
+        (Some(program), errors)
+    }
+
+    /// Skip the rest of the statement that started at token `start`. When the
+    /// error sits inside a block the statement opened, everything up to and
+    /// including the matching `}` is skipped, so the block's remaining
+    /// statements are not mistaken for top-level ones. Otherwise stop just
+    /// past a `;` or before the next statement keyword.
+    fn synchronize(&mut self, start: usize) {
+        self.expr_depth = 0;
+        let mut depth = self.tokens[start..self.current]
+            .iter()
+            .fold(0usize, |depth, token| match token.kind {
+                TokenKind::LeftBrace => depth + 1,
+                TokenKind::RightBrace => depth.saturating_sub(1),
+                _ => depth,
+            });
+        let mut skipped_any = false;
+        while !self.is_at_end() {
+            if depth == 0 && skipped_any {
+                if matches!(self.tokens[self.current - 1].kind, TokenKind::Semicolon) {
+                    return;
+                }
+                if matches!(
+                    self.peek().kind,
+                    TokenKind::Fn
+                        | TokenKind::Let
+                        | TokenKind::Const
+                        | TokenKind::Import
+                        | TokenKind::Export
+                        | TokenKind::If
+                        | TokenKind::While
+                        | TokenKind::For
+                        | TokenKind::Return
+                ) {
+                    return;
+                }
+            }
+            match self.peek().kind {
+                TokenKind::LeftBrace => depth += 1,
+                TokenKind::RightBrace if depth > 0 => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.advance();
+                        return;
+                    }
+                }
+                _ => {}
+            }
+            self.advance();
+            skipped_any = true;
+        }
+    }
+
     /// Parse a single expression and ensure the stream is fully consumed.
     pub fn parse_expression_only(&mut self) -> Result<Expr, ParseError> {
         let expression = self.parse_expression()?;
@@ -165,7 +351,7 @@ impl Parser {
             return Err(ParseError::UnexpectedToken {
                 expected: "end of expression".into(),
                 found: token.kind.clone(),
-                position: token.position.clone(),
+                span: token.span.clone(),
             });
         }
         Ok(expression)
@@ -195,6 +381,7 @@ impl Parser {
             TokenKind::Import => self.parse_import_declaration(),
             TokenKind::Export => self.parse_export_statement(),
             TokenKind::If => self.parse_if_statement(),
+            TokenKind::At => self.parse_hinted_if_statement(),
             TokenKind::While => self.parse_while_statement(),
             TokenKind::For => self.parse_for_statement(),
             TokenKind::Return => self.parse_return_statement(),
@@ -262,7 +449,7 @@ impl Parser {
             binding,
             is_mutable,
             initializer,
-            position: start_pos,
+            span: self.span_from(start_pos),
             node_id: next_node_id(),
         };
 
@@ -279,7 +466,10 @@ impl Parser {
                 if let Stmt::FunctionDecl { mut decl } = stmt {
                     decl.visibility = Visibility::Public;
                     Ok(Stmt::ExportDecl {
-                        decl: ExportDecl::new(ExportItem::Function(decl), start_pos),
+                        decl: ExportDecl::new(
+                            ExportItem::Function(decl),
+                            self.span_from(start_pos),
+                        ),
                     })
                 } else {
                     Err(ParseError::InvalidSyntax {
@@ -293,7 +483,10 @@ impl Parser {
                 let stmt = self.parse_variable_declaration(start_pos.clone(), BindingKind::Let)?;
                 if let Stmt::VariableDecl { decl } = stmt {
                     Ok(Stmt::ExportDecl {
-                        decl: ExportDecl::new(ExportItem::Variable(decl), start_pos),
+                        decl: ExportDecl::new(
+                            ExportItem::Variable(decl),
+                            self.span_from(start_pos),
+                        ),
                     })
                 } else {
                     Err(ParseError::InvalidSyntax {
@@ -308,7 +501,10 @@ impl Parser {
                     self.parse_variable_declaration(start_pos.clone(), BindingKind::Const)?;
                 if let Stmt::VariableDecl { decl } = stmt {
                     Ok(Stmt::ExportDecl {
-                        decl: ExportDecl::new(ExportItem::Variable(decl), start_pos),
+                        decl: ExportDecl::new(
+                            ExportItem::Variable(decl),
+                            self.span_from(start_pos),
+                        ),
                     })
                 } else {
                     Err(ParseError::InvalidSyntax {
@@ -321,7 +517,10 @@ impl Parser {
                 let (name, alias) = self.parse_export_symbol_spec()?;
                 self.consume_statement_terminator()?;
                 Ok(Stmt::ExportDecl {
-                    decl: ExportDecl::new(ExportItem::Symbol { name, alias }, start_pos),
+                    decl: ExportDecl::new(
+                        ExportItem::Symbol { name, alias },
+                        self.span_from(start_pos),
+                    ),
                 })
             }
         }
@@ -362,7 +561,7 @@ impl Parser {
             }
             other => Err(ParseError::InvalidSyntax {
                 message: format!("Unexpected token after export: {:?}", other),
-                position: self.peek().position.clone(),
+                position: self.peek().position().clone(),
             }),
         }
     }
@@ -440,7 +639,7 @@ impl Parser {
             body,
             is_async,
             visibility: Visibility::Private,
-            position: start_pos,
+            span: self.span_from(start_pos),
             node_id: next_node_id(),
         };
 
@@ -484,7 +683,7 @@ impl Parser {
             source,
             items,
             alias,
-            position: start_pos,
+            span: self.span_from(start_pos),
         };
 
         Ok(Stmt::ImportDecl { decl })
@@ -512,7 +711,7 @@ impl Parser {
             _ => Err(ParseError::UnexpectedToken {
                 expected: "module path (\"file.svs\" or <module>)".to_string(),
                 found: self.peek().kind.clone(),
-                position: self.peek().position.clone(),
+                span: self.peek().span.clone(),
             }),
         }
     }
@@ -545,10 +744,40 @@ impl Parser {
             condition,
             then_branch,
             else_branch,
-            position: start_pos,
+            hint: None,
+            span: self.span_from(start_pos),
         })
     }
 
+    /// Parse a branch hint and the if statement it applies to:
+    /// @likely if condition { body }
+    fn parse_hinted_if_statement(&mut self) -> Result<Stmt, ParseError> {
+        let position = self.current_position();
+        self.consume(&TokenKind::At, "Expected '@'")?;
+        let name = self.consume_identifier("Expected hint name after '@'")?;
+        let hint = match name.as_str() {
+            "likely" => BranchHint::Likely,
+            "unlikely" => BranchHint::Unlikely,
+            other => {
+                return Err(ParseError::InvalidSyntax {
+                    message: format!("unknown hint '@{other}', expected '@likely' or '@unlikely'"),
+                    position,
+                });
+            }
+        };
+        if !self.check(&TokenKind::If) {
+            return Err(ParseError::InvalidSyntax {
+                message: format!("'@{name}' must be followed by an if statement"),
+                position: self.current_position(),
+            });
+        }
+        let mut stmt = self.parse_if_statement()?;
+        if let Stmt::If { hint: slot, .. } = &mut stmt {
+            *slot = Some(hint);
+        }
+        Ok(stmt)
+    }
+
     fn parse_optional_else_clause(&mut self) -> Result<Option<Box<Stmt>>, ParseError> {
         if self.check(&TokenKind::Else) {
             self.advance();
@@ -565,7 +794,8 @@ impl Parser {
                 condition,
                 then_branch,
                 else_branch,
-                position: start_pos,
+                hint: None,
+                span: self.span_from(start_pos),
             };
             return Ok(Some(Box::new(nested)));
         }
@@ -584,7 +814,7 @@ impl Parser {
         Ok(Stmt::While {
             condition,
             body,
-            position: start_pos,
+            span: self.span_from(start_pos),
         })
     }
 
@@ -593,6 +823,7 @@ impl Parser {
         let start_pos = self.current_position();
         self.consume(&TokenKind::For, "Expected 'for'")?;
 
+        let variable_span = self.peek().span.clone();
         let variable = self.consume_identifier("Expected variable name")?;
         self.consume(&TokenKind::In, "Expected 'in' after for variable")?;
 
@@ -601,9 +832,10 @@ impl Parser {
 
         Ok(Stmt::For {
             variable,
+            variable_span,
             iterable,
             body,
-            position: start_pos,
+            span: self.span_from(start_pos),
             node_id: next_node_id(),
         })
     }
@@ -623,7 +855,7 @@ impl Parser {
 
         Ok(Stmt::Return {
             value,
-            position: start_pos,
+            span: self.span_from(start_pos),
         })
     }
 
@@ -635,7 +867,7 @@ impl Parser {
 
         Ok(Stmt::Break {
             label: None,
-            position: start_pos,
+            span: self.span_from(start_pos),
         })
     }
 
@@ -647,7 +879,7 @@ impl Parser {
 
         Ok(Stmt::Continue {
             label: None,
-            position: start_pos,
+            span: self.span_from(start_pos),
         })
     }
 
@@ -701,7 +933,7 @@ impl Parser {
             try_block,
             catch_blocks,
             finally_block,
-            position: start_pos,
+            span: self.span_from(start_pos),
         })
     }
 
@@ -720,7 +952,7 @@ impl Parser {
 
         Ok(Stmt::Panic {
             message,
-            position: start_pos,
+            span: self.span_from(start_pos),
         })
     }
 
@@ -733,7 +965,7 @@ impl Parser {
 
         Ok(Stmt::Block {
             statements,
-            position: start_pos,
+            span: self.span_from(start_pos),
         })
     }
 
@@ -766,10 +998,13 @@ impl Parser {
     /// Parse expression statement: expression;
     fn parse_expression_statement(&mut self) -> Result<Stmt, ParseError> {
         let expr = self.parse_expression()?;
-        let position = expr.position().clone();
+        let start_pos = expr.position().clone();
         self.consume_statement_terminator()?;
 
-        Ok(Stmt::Expression { expr, position })
+        Ok(Stmt::Expression {
+            expr,
+            span: self.span_from(start_pos),
+        })
     }
 
     //=============================================/*
@@ -786,7 +1021,7 @@ impl Parser {
                     "expression recursion limit of {} exceeded",
                     MAX_EXPRESSION_DEPTH
                 ),
-                position: self.peek().position.clone(),
+                position: self.peek().position().clone(),
             });
         }
         self.expr_depth += 1;
@@ -820,9 +1055,13 @@ impl Parser {
                 left: Box::new(target_expr),
                 operator: op,
                 right: Box::new(value),
-                position: assign_pos.clone(),
+                span: self.span_from(assign_pos.clone()),
             };
-            return Ok(Expr::assignment(target, combined, assign_pos));
+            return Ok(Expr::assignment(
+                target,
+                combined,
+                self.span_from(assign_pos),
+            ));
         }
 
         if self.check(&TokenKind::Equal) {
@@ -831,7 +1070,7 @@ impl Parser {
             let value = self.parse_assignment()?;
             let target = self.assignment_target_from_expr(expr)?;
 
-            return Ok(Expr::assignment(target, value, assign_pos));
+            return Ok(Expr::assignment(target, value, self.span_from(assign_pos)));
         }
 
         Ok(expr)
@@ -871,7 +1110,7 @@ impl Parser {
         let mut expr = self.parse_logical_and()?;
 
         while self.check(&TokenKind::Or) {
-            let start_pos = self.current_position();
+            let start_pos = expr.position().clone();
             self.advance();
             let right = self.parse_logical_and()?;
 
@@ -879,7 +1118,7 @@ impl Parser {
                 left: Box::new(expr),
                 operator: BinaryOp::Or,
                 right: Box::new(right),
-                position: start_pos,
+                span: self.span_from(start_pos),
             };
         }
 
@@ -891,7 +1130,7 @@ impl Parser {
         let mut expr = self.parse_equality()?;
 
         while self.check(&TokenKind::And) {
-            let start_pos = self.current_position();
+            let start_pos = expr.position().clone();
             self.advance();
             let right = self.parse_equality()?;
 
@@ -899,7 +1138,7 @@ impl Parser {
                 left: Box::new(expr),
                 operator: BinaryOp::And,
                 right: Box::new(right),
-                position: start_pos,
+                span: self.span_from(start_pos),
             };
         }
 
@@ -911,14 +1150,14 @@ impl Parser {
         let mut expr = self.parse_comparison()?;
 
         while let Some(op) = self.match_binary_op(&[TokenKind::EqualEqual, TokenKind::NotEqual]) {
-            let start_pos = self.current_position();
+            let start_pos = expr.position().clone();
             let right = self.parse_comparison()?;
 
             expr = Expr::Binary {
                 left: Box::new(expr),
                 operator: op,
                 right: Box::new(right),
-                position: start_pos,
+                span: self.span_from(start_pos),
             };
         }
 
@@ -936,7 +1175,7 @@ impl Parser {
             TokenKind::GreaterEqual,
             TokenKind::Is,
         ]) {
-            let start_pos = self.current_position();
+            let start_pos = expr.position().clone();
             let mut operator = op;
             if matches!(operator, BinaryOp::Is) && self.check(&TokenKind::Not) {
                 self.advance();
@@ -948,7 +1187,7 @@ impl Parser {
                 left: Box::new(expr),
                 operator,
                 right: Box::new(right),
-                position: start_pos,
+                span: self.span_from(start_pos),
             };
         }
 
@@ -960,14 +1199,14 @@ impl Parser {
         let mut expr = self.parse_factor()?;
 
         while let Some(op) = self.match_binary_op(&[TokenKind::Plus, TokenKind::Minus]) {
-            let start_pos = self.current_position();
+            let start_pos = expr.position().clone();
             let right = self.parse_factor()?;
 
             expr = Expr::Binary {
                 left: Box::new(expr),
                 operator: op,
                 right: Box::new(right),
-                position: start_pos,
+                span: self.span_from(start_pos),
             };
         }
 
@@ -981,14 +1220,14 @@ impl Parser {
         while let Some(op) =
             self.match_binary_op(&[TokenKind::Star, TokenKind::Slash, TokenKind::Percent])
         {
-            let start_pos = self.current_position();
+            let start_pos = expr.position().clone();
             let right = self.parse_unary()?;
 
             expr = Expr::Binary {
                 left: Box::new(expr),
                 operator: op,
                 right: Box::new(right),
-                position: start_pos,
+                span: self.span_from(start_pos),
             };
         }
 
@@ -998,33 +1237,33 @@ impl Parser {
     /// Parse unary expression: !, -
     fn parse_unary(&mut self) -> Result<Expr, ParseError> {
         if self.check(&TokenKind::Async) {
-            let position = self.current_position();
+            let start_pos = self.current_position();
             self.advance();
             let expr = self.parse_unary()?;
             return Ok(Expr::Async {
                 expr: Box::new(expr),
-                position,
+                span: self.span_from(start_pos),
             });
         }
 
         if self.check(&TokenKind::Await) {
-            let position = self.current_position();
+            let start_pos = self.current_position();
             self.advance();
             let expr = self.parse_unary()?;
             return Ok(Expr::Await {
                 expr: Box::new(expr),
-                position,
+                span: self.span_from(start_pos),
             });
         }
 
         if let Some(op) = self.match_unary_op(&[TokenKind::Not, TokenKind::Minus]) {
-            let start_pos = self.current_position();
+            let start_pos = self.previous_position();
             let operand = self.parse_unary()?;
 
             return Ok(Expr::Unary {
                 operator: op,
                 operand: Box::new(operand),
-                position: start_pos,
+                span: self.span_from(start_pos),
             });
         }
 
@@ -1038,7 +1277,7 @@ impl Parser {
         loop {
             let kind = self.peek().kind.clone();
             if kind == TokenKind::LeftParen {
-                let start_pos = self.current_position();
+                let start_pos = expr.position().clone();
                 self.advance();
 
                 let mut args = Vec::new();
@@ -1064,23 +1303,23 @@ impl Parser {
                         receiver: object,
                         method: property,
                         args,
-                        position: start_pos,
+                        span: self.span_from(start_pos),
                     },
                     other => Expr::Call {
                         callee: Box::new(other),
                         args,
-                        position: start_pos,
+                        span: self.span_from(start_pos),
                     },
                 };
             } else if matches!(kind, TokenKind::Dot | TokenKind::DoubleColon) {
-                let start_pos = self.current_position();
+                let start_pos = expr.position().clone();
                 self.advance();
                 let property = self.consume_identifier("Expected property name after '.'")?;
 
                 expr = Expr::Member {
                     object: Box::new(expr),
                     property,
-                    position: start_pos,
+                    span: self.span_from(start_pos),
                     kind: if kind == TokenKind::Dot {
                         MemberKind::Dot
                     } else {
@@ -1088,7 +1327,7 @@ impl Parser {
                     },
                 };
             } else if kind == TokenKind::LeftBracket {
-                let start_pos = self.current_position();
+                let start_pos = expr.position().clone();
                 self.advance();
                 let mut start = None;
                 let mut end = None;
@@ -1107,13 +1346,12 @@ impl Parser {
                         step = Some(self.parse_expression()?);
                     }
                     self.consume(&TokenKind::RightBracket, "Expected ']' after slice")?;
-                    let end_pos = self.previous_position();
                     expr = Expr::Slice {
                         object: Box::new(expr),
                         start: start.map(Box::new),
                         end: end.map(Box::new),
                         step: step.map(Box::new),
-                        span: Span::new(start_pos, end_pos),
+                        span: self.span_from(start_pos),
                         node_id: next_node_id(),
                     };
                 } else if self.check(&TokenKind::Colon) {
@@ -1128,13 +1366,12 @@ impl Parser {
                         }
                     }
                     self.consume(&TokenKind::RightBracket, "Expected ']' after slice")?;
-                    let end_pos = self.previous_position();
                     expr = Expr::Slice {
                         object: Box::new(expr),
                         start: start.map(Box::new),
                         end: end.map(Box::new),
                         step: step.map(Box::new),
-                        span: Span::new(start_pos, end_pos),
+                        span: self.span_from(start_pos),
                         node_id: next_node_id(),
                     };
                 } else {
@@ -1146,7 +1383,7 @@ impl Parser {
                     expr = Expr::Index {
                         object: Box::new(expr),
                         index: Box::new(index),
-                        position: start_pos,
+                        span: self.span_from(start_pos),
                     };
                 }
             } else {
@@ -1160,7 +1397,7 @@ impl Parser {
     /// Parse primary expression: literals, identifiers, parenthesized expressions
     fn parse_primary(&mut self) -> Result<Expr, ParseError> {
         let token = self.peek().clone();
-        let position = token.position.clone();
+        let span = token.span.clone();
 
         match &token.kind {
             TokenKind::Integer(n) => {
@@ -1168,7 +1405,7 @@ impl Parser {
                 self.advance();
                 Ok(Expr::Literal {
                     value: Literal::Integer(n),
-                    position,
+                    span,
                 })
             }
             TokenKind::Float(f) => {
@@ -1176,7 +1413,7 @@ impl Parser {
                 self.advance();
                 Ok(Expr::Literal {
                     value: Literal::Float(f),
-                    position,
+                    span,
                 })
             }
             TokenKind::String(s) => {
@@ -1184,7 +1421,7 @@ impl Parser {
                 self.advance();
                 Ok(Expr::StringTemplate {
                     parts: vec![StringPart::Literal(s)],
-                    position,
+                    span,
                 })
             }
             TokenKind::StringTemplate(s) => {
@@ -1192,7 +1429,7 @@ impl Parser {
                 self.advance();
                 Ok(Expr::StringTemplate {
                     parts: vec![StringPart::Literal(s)],
-                    position,
+                    span,
                 })
             }
             TokenKind::Boolean(b) => {
@@ -1200,14 +1437,14 @@ impl Parser {
                 self.advance();
                 Ok(Expr::Literal {
                     value: Literal::Boolean(b),
-                    position,
+                    span,
                 })
             }
             TokenKind::Null => {
                 self.advance();
                 Ok(Expr::Literal {
                     value: Literal::Null,
-                    position,
+                    span,
                 })
             }
             TokenKind::Identifier(name) => {
@@ -1215,7 +1452,7 @@ impl Parser {
                 self.advance();
                 Ok(Expr::Identifier {
                     name,
-                    position,
+                    span,
                     node_id: next_node_id(),
                 })
             }
@@ -1223,7 +1460,7 @@ impl Parser {
                 self.advance();
                 Ok(Expr::Identifier {
                     name: Symbol::from("string"),
-                    position,
+                    span,
                     node_id: next_node_id(),
                 })
             }
@@ -1231,7 +1468,7 @@ impl Parser {
                 self.advance();
                 Ok(Expr::Identifier {
                     name: Symbol::from("int"),
-                    position,
+                    span,
                     node_id: next_node_id(),
                 })
             }
@@ -1239,7 +1476,7 @@ impl Parser {
                 self.advance();
                 Ok(Expr::Identifier {
                     name: Symbol::from("float"),
-                    position,
+                    span,
                     node_id: next_node_id(),
                 })
             }
@@ -1247,7 +1484,7 @@ impl Parser {
                 self.advance();
                 Ok(Expr::Identifier {
                     name: Symbol::from("bool"),
-                    position,
+                    span,
                     node_id: next_node_id(),
                 })
             }
@@ -1275,7 +1512,7 @@ impl Parser {
                 )?;
                 Ok(Expr::Literal {
                     value: Literal::Array(elements),
-                    position,
+                    span: self.span_from(span.start),
                 })
             }
             TokenKind::LeftBrace => self.parse_object_literal(),
@@ -1283,12 +1520,12 @@ impl Parser {
             TokenKind::If => self.parse_if_expression(),
             TokenKind::Lambda => {
                 self.advance();
-                self.parse_lambda_expression()
+                self.parse_lambda_expression(span.start)
             }
             _ => Err(ParseError::UnexpectedToken {
                 expected: "expression".to_string(),
                 found: token.kind.clone(),
-                position,
+                span: token.span.clone(),
             }),
         }
     }
@@ -1314,7 +1551,7 @@ impl Parser {
                         return Err(ParseError::UnexpectedToken {
                             expected: "property name".to_string(),
                             found: self.peek().kind.clone(),
-                            position: self.peek().position.clone(),
+                            span: self.peek().span.clone(),
                         });
                     }
                 };
@@ -1340,7 +1577,7 @@ impl Parser {
 
         Ok(Expr::Literal {
             value: Literal::Object(properties),
-            position: start_pos,
+            span: self.span_from(start_pos),
         })
     }
 
@@ -1387,7 +1624,7 @@ impl Parser {
         Ok(Expr::Match {
             expr,
             arms,
-            position: start_pos,
+            span: self.span_from(start_pos),
         })
     }
 
@@ -1405,18 +1642,19 @@ impl Parser {
             condition,
             then_expr,
             else_expr,
-            position: start_pos,
+            span: self.span_from(start_pos),
         })
     }
 
     /// Parse lambda expression: |params| -> expr
-    fn parse_lambda_expression(&mut self) -> Result<Expr, ParseError> {
-        let start_pos = self.current_position();
+    fn parse_lambda_expression(&mut self, start_pos: Position) -> Result<Expr, ParseError> {
         self.consume(&TokenKind::Pipe, "Expected '|' after lambda keyword")?;
 
         let mut params = Vec::new();
+        let mut param_spans = Vec::new();
         if !self.check(&TokenKind::Pipe) {
             loop {
+                param_spans.push(self.peek().span.clone());
                 let param_name = self.consume_identifier("Expected parameter name")?;
                 params.push(param_name);
 
@@ -1434,8 +1672,9 @@ impl Parser {
 
         Ok(Expr::Lambda {
             params,
+            param_spans,
             body,
-            position: start_pos,
+            span: self.span_from(start_pos),
         })
     }
 
@@ -1517,7 +1756,7 @@ impl Parser {
             _ => Err(ParseError::UnexpectedToken {
                 expected: "pattern".to_string(),
                 found: self.peek().kind.clone(),
-                position: self.peek().position.clone(),
+                span: self.peek().span.clone(),
             }),
         }
     }
@@ -1562,7 +1801,7 @@ impl Parser {
             Err(ParseError::UnexpectedToken {
                 expected: format!("{:?}", kind),
                 found: self.peek().kind.clone(),
-                position: self.peek().position.clone(),
+                span: self.peek().span.clone(),
             })
         }
     }
@@ -1594,7 +1833,7 @@ impl Parser {
             _ => Err(ParseError::UnexpectedToken {
                 expected: "identifier".to_string(),
                 found: self.peek().kind.clone(),
-                position: self.peek().position.clone(),
+                span: self.peek().span.clone(),
             }),
         }
     }
@@ -1616,7 +1855,7 @@ impl Parser {
             Err(ParseError::UnexpectedToken {
                 expected: format!("'{}'", keyword),
                 found: self.peek().kind.clone(),
-                position: self.peek().position.clone(),
+                span: self.peek().span.clone(),
             })
         }
     }
@@ -1631,10 +1870,10 @@ impl Parser {
             if self.is_at_end() {
                 Ok(())
             } else {
-                Err(ParseError::UnexpectedToken {
-                    expected: "statement terminator".to_string(),
+                Err(ParseError::MissingTerminator {
                     found: self.peek().kind.clone(),
-                    position: self.peek().position.clone(),
+                    span: self.peek().span.clone(),
+                    after: self.span_from(self.current_position()).end,
                 })
             }
         }
@@ -1647,14 +1886,34 @@ impl Parser {
 
     // Utility: get current position
     fn current_position(&self) -> Position {
-        self.peek().position.clone()
+        self.peek().position().clone()
+    }
+
+    /// Span from `start` to the end of the last consumed token, ignoring
+    /// layout tokens such as newlines so spans never run onto the next line.
+    fn span_from(&self, start: Position) -> Span {
+        let end = self.tokens[..self.current]
+            .iter()
+            .rev()
+            .find(|token| {
+                !matches!(
+                    token.kind,
+                    TokenKind::Newline
+                        | TokenKind::Indent
+                        | TokenKind::Dedent
+                        | TokenKind::Comment(_)
+                )
+            })
+            .map(|token| token.span.end.clone())
+            .unwrap_or_else(|| start.clone());
+        Span::new(start, end)
     }
 
     fn previous_position(&self) -> Position {
         if self.current == 0 {
             self.current_position()
         } else {
-            self.tokens[self.current - 1].position.clone()
+            self.tokens[self.current - 1].position().clone()
         }
     }
 
@@ -1768,7 +2027,7 @@ impl Parser {
             _ => Err(ParseError::UnexpectedToken {
                 expected: "type annotation".to_string(),
                 found: self.peek().kind.clone(),
-                position: self.peek().position.clone(),
+                span: self.peek().span.clone(),
             }),
         }
     }
@@ -7,23 +7,43 @@
 // Objective: Map identifier usages to their declarations with a simple scope stack
 //=====================================================
 
+mod slots;
+mod typecheck;
+
+pub use slots::SlotMap;
+
 use crate::ast::{
-    AssignTarget, Expr, FunctionDecl, NodeId, Program, Stmt, StringPart, VariableDecl, next_node_id,
+    AssignTarget, BinaryOp, BindingKind, ExportItem, Expr, FunctionDecl, NodeId, Program, Span,
+    Stmt, StringPart, Type, VariableDecl, next_node_id,
 };
+use crate::symbol::{SymbolInfo, SymbolKind, SymbolTable};
 use crate::tokenizer::Position;
+use slots::SlotAllocator;
 use std::collections::HashMap;
 
 pub type Module = Program;
 
+/// A value whose type can never match what its context requires.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeMismatch {
+    /// Human-readable site, e.g. "variable 'x'" or "operator '-'".
+    pub context: String,
+    pub expected: Type,
+    pub found: Type,
+    pub span: Span,
+}
+
 #[derive(Default)]
 pub struct Diagnostics {
     unresolved: Vec<(String, Position)>,
+    type_mismatches: Vec<TypeMismatch>,
 }
 
 impl Diagnostics {
     pub fn new() -> Self {
         Self {
             unresolved: Vec::new(),
+            type_mismatches: Vec::new(),
         }
     }
 
@@ -31,18 +51,31 @@ impl Diagnostics {
         self.unresolved.push((name.to_string(), position));
     }
 
+    pub fn record_type_mismatch(&mut self, mismatch: TypeMismatch) {
+        self.type_mismatches.push(mismatch);
+    }
+
     pub fn has_errors(&self) -> bool {
-        !self.unresolved.is_empty()
+        !self.unresolved.is_empty() || !self.type_mismatches.is_empty()
     }
 
     pub fn unresolved(&self) -> &[(String, Position)] {
         &self.unresolved
     }
+
+    pub fn type_mismatches(&self) -> &[TypeMismatch] {
+        &self.type_mismatches
+    }
 }
 
 pub struct SymbolResolution {
     #[allow(dead_code)]
     pub map: HashMap<NodeId, NodeId>,
+    /// Every scope and declaration seen during resolution, for tooling
+    /// queries after the pass has finished.
+    pub symbols: SymbolTable,
+    /// Frame slots for locals that never need a name lookup.
+    pub slots: SlotMap,
 }
 
 /// Run name resolution over a module and capture identifier bindings.
@@ -52,18 +85,14 @@ pub fn resolve_module(ast: &Module, diagnostics: &mut Diagnostics) -> SymbolReso
     resolver.resolve_statements(&ast.statements);
     SymbolResolution {
         map: resolver.resolutions,
+        symbols: resolver.symbols,
+        slots: resolver.slots.finish(),
     }
 }
 
-#[derive(Clone)]
-struct Scope {
-    parent: Option<usize>,
-    bindings: HashMap<String, NodeId>,
-}
-
 struct Resolver<'a> {
-    scopes: Vec<Scope>,
-    current_scope: usize,
+    symbols: SymbolTable,
+    slots: SlotAllocator,
     resolutions: HashMap<NodeId, NodeId>,
     diagnostics: &'a mut Diagnostics,
 }
@@ -71,11 +100,8 @@ struct Resolver<'a> {
 impl<'a> Resolver<'a> {
     fn new(diagnostics: &'a mut Diagnostics) -> Self {
         Self {
-            scopes: vec![Scope {
-                parent: None,
-                bindings: HashMap::new(),
-            }],
-            current_scope: 0,
+            symbols: SymbolTable::new(),
+            slots: SlotAllocator::default(),
             resolutions: HashMap::new(),
             diagnostics,
         }
@@ -84,7 +110,7 @@ impl<'a> Resolver<'a> {
     fn collect_function_decls(&mut self, module: &Module) {
         for stmt in &module.statements {
             if let Stmt::FunctionDecl { decl } = stmt {
-                self.define(&decl.name, decl.node_id);
+                self.define_function(decl);
             }
         }
     }
@@ -101,7 +127,7 @@ impl<'a> Resolver<'a> {
                 self.define_variable(decl);
             }
             Stmt::FunctionDecl { decl } => {
-                self.define(&decl.name, decl.node_id);
+                self.define_function(decl);
                 self.resolve_function(decl);
             }
             Stmt::Expression { expr, .. } => self.resolve_expr(expr),
@@ -136,6 +162,7 @@ impl<'a> Resolver<'a> {
             }
             Stmt::For {
                 variable,
+                variable_span,
                 iterable,
                 body,
                 node_id,
@@ -143,7 +170,13 @@ impl<'a> Resolver<'a> {
             } => {
                 self.resolve_expr(iterable);
                 self.push_scope();
-                self.define(variable, *node_id);
+                self.define(
+                    variable,
+                    SymbolKind::LoopVariable,
+                    variable_span.clone(),
+                    Type::Inferred,
+                    *node_id,
+                );
                 self.resolve_stmt(body);
                 self.pop_scope();
             }
@@ -207,8 +240,12 @@ impl<'a> Resolver<'a> {
                 message: Some(expr),
                 ..
             } => self.resolve_expr(expr),
+            Stmt::ExportDecl { decl } => {
+                if let ExportItem::Symbol { name, .. } = &decl.item {
+                    self.slots.dynamic(name.as_str());
+                }
+            }
             Stmt::ImportDecl { .. }
-            | Stmt::ExportDecl { .. }
             | Stmt::Break { .. }
             | Stmt::Continue { .. }
             | Stmt::Panic { .. } => {}
@@ -218,11 +255,18 @@ impl<'a> Resolver<'a> {
 
     fn resolve_function(&mut self, decl: &FunctionDecl) {
         self.push_scope();
+        self.slots.enter_frame(self.symbols.current_scope());
         for param in &decl.params {
-            let param_id = next_node_id();
-            self.define(&param.name, param_id);
+            self.define(
+                &param.name,
+                SymbolKind::Parameter,
+                Span::point(param.position.clone()),
+                param.param_type.clone(),
+                next_node_id(),
+            );
         }
         self.resolve_statements(&decl.body);
+        self.slots.exit_frame();
         self.pop_scope();
     }
 
@@ -230,15 +274,21 @@ impl<'a> Resolver<'a> {
         match expr {
             Expr::Identifier {
                 name,
-                position,
+                span,
                 node_id,
                 ..
             } => {
-                self.resolve_identifier(name.as_str(), *node_id, position.clone());
+                self.resolve_identifier(name.as_str(), *node_id, span.start.clone());
             }
-            Expr::Binary { left, right, .. } => {
+            Expr::Binary {
+                left,
+                operator,
+                right,
+                ..
+            } => {
                 self.resolve_expr(left);
                 self.resolve_expr(right);
+                self.check_operator(operator, left, right);
             }
             Expr::Unary { operand, .. } => self.resolve_expr(operand),
             Expr::Call { callee, args, .. } => {
@@ -279,10 +329,10 @@ impl<'a> Resolver<'a> {
             Expr::Assign {
                 target,
                 value,
-                position,
-                ..
+                span,
+                node_id,
             } => {
-                self.resolve_assign_target(target, position);
+                self.resolve_assign_target(target, *node_id, &span.start);
                 self.resolve_expr(value);
             }
             Expr::If {
@@ -328,23 +378,42 @@ impl<'a> Resolver<'a> {
                 iterable,
                 condition,
                 variable,
-                ..
+                span,
             } => {
                 self.resolve_expr(iterable);
                 self.push_scope();
-                self.define(variable, next_node_id());
+                self.define(
+                    variable,
+                    SymbolKind::LoopVariable,
+                    span.clone(),
+                    Type::Inferred,
+                    next_node_id(),
+                );
                 self.resolve_expr(element);
                 if let Some(cond) = condition {
                     self.resolve_expr(cond);
                 }
                 self.pop_scope();
             }
-            Expr::Lambda { params, body, .. } => {
+            Expr::Lambda {
+                params,
+                param_spans,
+                body,
+                ..
+            } => {
                 self.push_scope();
-                for param in params {
-                    self.define(param, next_node_id());
+                self.slots.enter_frame(self.symbols.current_scope());
+                for (param, param_span) in params.iter().zip(param_spans) {
+                    self.define(
+                        param,
+                        SymbolKind::Parameter,
+                        param_span.clone(),
+                        Type::Inferred,
+                        next_node_id(),
+                    );
                 }
                 self.resolve_expr(body);
+                self.slots.exit_frame();
                 self.pop_scope();
             }
             Expr::Match { expr, arms, .. } => {
@@ -371,14 +440,16 @@ impl<'a> Resolver<'a> {
         }
     }
 
-    fn resolve_assign_target(&mut self, target: &AssignTarget, position: &Position) {
+    fn resolve_assign_target(&mut self, target: &AssignTarget, site: NodeId, position: &Position) {
         match target {
-            AssignTarget::Variable(symbol) => {
-                if self.lookup(symbol.as_str()).is_none() {
+            AssignTarget::Variable(symbol) => match self.lookup(symbol.as_str()) {
+                Some(def_id) => self.slots.reference(site, def_id),
+                None => {
                     self.diagnostics
                         .record_unresolved(symbol.as_str(), position.clone());
+                    self.slots.dynamic(symbol.as_str());
                 }
-            }
+            },
             AssignTarget::Index { array, index } => {
                 self.resolve_expr(array);
                 self.resolve_expr(index);
@@ -389,51 +460,109 @@ impl<'a> Resolver<'a> {
         }
     }
 
+    fn define_function(&mut self, decl: &FunctionDecl) {
+        let ty = Type::Function {
+            params: decl
+                .params
+                .iter()
+                .map(|param| param.param_type.clone())
+                .collect(),
+            return_type: Box::new(decl.return_type.clone()),
+        };
+        self.define(
+            &decl.name,
+            SymbolKind::Function,
+            decl.span.clone(),
+            ty,
+            decl.node_id,
+        );
+    }
+
     fn define_variable(&mut self, decl: &VariableDecl) {
-        self.define(&decl.name, decl.node_id);
+        let kind = match decl.binding {
+            BindingKind::Let => SymbolKind::Variable,
+            BindingKind::Const => SymbolKind::Constant,
+        };
+        self.define(
+            &decl.name,
+            kind,
+            decl.span.clone(),
+            decl.var_type.clone(),
+            decl.node_id,
+        );
         if let Some(init) = &decl.initializer {
             self.resolve_expr(init);
+            self.check_annotation(decl, init);
+        }
+    }
+
+    fn check_annotation(&mut self, decl: &VariableDecl, init: &Expr) {
+        let Some(found) = typecheck::literal_type(init) else {
+            return;
+        };
+        if typecheck::annotation_rejects(&decl.var_type, &found) {
+            self.diagnostics.record_type_mismatch(TypeMismatch {
+                context: format!("variable '{}'", decl.name),
+                expected: decl.var_type.clone(),
+                found,
+                span: init.span().clone(),
+            });
+        }
+    }
+
+    fn check_operator(&mut self, operator: &BinaryOp, left: &Expr, right: &Expr) {
+        if let Some(mismatch) = typecheck::operator_mismatch(operator, left, right) {
+            let operand = if mismatch.left_at_fault { left } else { right };
+            self.diagnostics.record_type_mismatch(TypeMismatch {
+                context: format!("operator '{}'", mismatch.symbol),
+                expected: mismatch.expected,
+                found: mismatch.found,
+                span: operand.span().clone(),
+            });
         }
     }
 
     fn resolve_identifier(&mut self, name: &str, use_id: NodeId, position: Position) {
         if let Some(def_id) = self.lookup(name) {
             self.resolutions.insert(use_id, def_id);
+            self.slots.reference(use_id, def_id);
         } else {
             self.diagnostics.record_unresolved(name, position);
+            self.slots.dynamic(name);
         }
     }
 
-    fn define(&mut self, name: &str, node_id: NodeId) {
-        if let Some(scope) = self.scopes.get_mut(self.current_scope) {
-            scope.bindings.insert(name.to_string(), node_id);
+    fn define(&mut self, name: &str, kind: SymbolKind, span: Span, ty: Type, node_id: NodeId) {
+        match kind {
+            SymbolKind::Variable | SymbolKind::Constant => {
+                self.slots
+                    .declare_local(name, node_id, self.symbols.current_scope());
+            }
+            _ => self.slots.declare_other(name),
         }
+        self.symbols.define(
+            name,
+            SymbolInfo {
+                kind,
+                declaration_span: span,
+                ty,
+                node_id,
+            },
+        );
     }
 
     fn lookup(&self, name: &str) -> Option<NodeId> {
-        let mut scope_index = Some(self.current_scope);
-        while let Some(index) = scope_index {
-            if let Some(id) = self.scopes[index].bindings.get(name) {
-                return Some(*id);
-            }
-            scope_index = self.scopes[index].parent;
-        }
-        None
+        self.symbols
+            .resolve(name, self.symbols.current_scope())
+            .map(|info| info.node_id)
     }
 
     fn push_scope(&mut self) {
-        let parent = Some(self.current_scope);
-        self.scopes.push(Scope {
-            parent,
-            bindings: HashMap::new(),
-        });
-        self.current_scope = self.scopes.len() - 1;
+        self.symbols.enter_scope();
     }
 
     fn pop_scope(&mut self) {
-        if let Some(parent) = self.scopes[self.current_scope].parent {
-            self.current_scope = parent;
-        }
+        self.symbols.exit_scope();
     }
 }
 
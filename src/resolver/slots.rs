@@ -0,0 +1,153 @@
+//=====================================================
+// File: resolver/slots.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Frame slot assignment for function-local bindings
+// Objective: Give each local that is only touched from its own function body
+//            a fixed index so the interpreter can skip name lookups
+//=====================================================
+
+use crate::ast::NodeId;
+use crate::symbol::ScopeId;
+use std::collections::{HashMap, HashSet};
+
+/// Frame slot indices keyed by AST node. Declarations, identifier uses, and
+/// assignments that share a slot all map to the same index; any node absent
+/// from the map must be resolved by name.
+#[derive(Debug, Clone, Default)]
+pub struct SlotMap {
+    slots: HashMap<NodeId, u32>,
+}
+
+impl SlotMap {
+    pub fn slot(&self, node: NodeId) -> Option<u32> {
+        self.slots.get(&node).copied()
+    }
+
+    /// Merge slots computed for another program. Node ids are unique per
+    /// process, so maps never collide.
+    pub fn extend(&mut self, other: SlotMap) {
+        self.slots.extend(other.slots);
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+}
+
+struct Frame {
+    serial: usize,
+    scope: ScopeId,
+    next_slot: u32,
+}
+
+struct Candidate {
+    frame: usize,
+    name: String,
+    slot: u32,
+}
+
+/// Collects slot candidates while the resolver walks a module.
+///
+/// Only `let`/`const` bindings declared directly in a function body are
+/// candidates. A name loses its slot in a frame when anything could reach it
+/// without going through its own function body: a use from a nested function
+/// or lambda, a declaration of the same name in a nested scope or as a
+/// parameter, or an unresolved (dynamic) use of the name anywhere.
+#[derive(Default)]
+pub(crate) struct SlotAllocator {
+    frames: Vec<Frame>,
+    next_serial: usize,
+    candidates: HashMap<NodeId, Candidate>,
+    references: Vec<(NodeId, NodeId)>,
+    unslottable: HashSet<(usize, String)>,
+    dynamic_names: HashSet<String>,
+}
+
+impl SlotAllocator {
+    /// Start a new call frame whose body is `scope`.
+    pub(crate) fn enter_frame(&mut self, scope: ScopeId) {
+        self.frames.push(Frame {
+            serial: self.next_serial,
+            scope,
+            next_slot: 0,
+        });
+        self.next_serial += 1;
+    }
+
+    pub(crate) fn exit_frame(&mut self) {
+        self.frames.pop();
+    }
+
+    /// Record a `let`/`const` declaration made in `scope`.
+    pub(crate) fn declare_local(&mut self, name: &str, decl: NodeId, scope: ScopeId) {
+        let Some(frame) = self.frames.last_mut() else {
+            return;
+        };
+        if frame.scope != scope {
+            self.unslottable.insert((frame.serial, name.to_string()));
+            return;
+        }
+        self.candidates.insert(
+            decl,
+            Candidate {
+                frame: frame.serial,
+                name: name.to_string(),
+                slot: frame.next_slot,
+            },
+        );
+        frame.next_slot += 1;
+    }
+
+    /// Record any other binding (parameter, function, loop variable) so it
+    /// is never confused with a slotted name in the same frame.
+    pub(crate) fn declare_other(&mut self, name: &str) {
+        if let Some(frame) = self.frames.last() {
+            self.unslottable.insert((frame.serial, name.to_string()));
+        }
+    }
+
+    /// `site` (an identifier use or assignment) resolved to `decl`.
+    pub(crate) fn reference(&mut self, site: NodeId, decl: NodeId) {
+        let Some(candidate) = self.candidates.get(&decl) else {
+            return;
+        };
+        if self.frames.last().map(|frame| frame.serial) == Some(candidate.frame) {
+            self.references.push((site, decl));
+        } else {
+            self.unslottable
+                .insert((candidate.frame, candidate.name.clone()));
+        }
+    }
+
+    /// `name` is looked up dynamically somewhere, so no binding of that name
+    /// may be moved out of the name-based environment.
+    pub(crate) fn dynamic(&mut self, name: &str) {
+        self.dynamic_names.insert(name.to_string());
+    }
+
+    pub(crate) fn finish(self) -> SlotMap {
+        let mut slots = HashMap::new();
+        for (decl, candidate) in &self.candidates {
+            let key = (candidate.frame, candidate.name.clone());
+            if !self.unslottable.contains(&key) && !self.dynamic_names.contains(&candidate.name) {
+                slots.insert(*decl, candidate.slot);
+            }
+        }
+        for (site, decl) in &self.references {
+            if let Some(slot) = slots.get(decl).copied() {
+                slots.insert(*site, slot);
+            }
+        }
+        SlotMap { slots }
+    }
+}
+
+//=====================================================
+// End of file
+//=====================================================
@@ -0,0 +1,89 @@
+//=====================================================
+// File: resolver/typecheck.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Lightweight static type checks run during resolution
+// Objective: Flag mismatches that are certain from literals alone, such as
+//            `let x: int = "text"` or `"a" - 1`, without full inference
+//=====================================================
+
+use crate::ast::{BinaryOp, Expr, Literal, Type, UnaryOp};
+
+/// The type of `expr` when it is known without looking at any bindings.
+/// Anything that depends on a variable, call, or container yields `None`.
+pub(crate) fn literal_type(expr: &Expr) -> Option<Type> {
+    match expr {
+        Expr::Literal { value, .. } => match value {
+            Literal::Integer(_) => Some(Type::Int),
+            Literal::Float(_) => Some(Type::Float),
+            Literal::String(_) => Some(Type::String),
+            Literal::Boolean(_) => Some(Type::Bool),
+            Literal::Null | Literal::Array(_) | Literal::Object(_) => None,
+        },
+        Expr::StringTemplate { .. } | Expr::StringInterpolation { .. } => Some(Type::String),
+        Expr::Unary {
+            operator, operand, ..
+        } => match (operator, literal_type(operand)?) {
+            (UnaryOp::Minus | UnaryOp::Plus, ty @ (Type::Int | Type::Float)) => Some(ty),
+            (UnaryOp::Not, Type::Bool) => Some(Type::Bool),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether a value of type `found` cannot be stored in a binding annotated
+/// `expected`. Only primitive annotations are checked; integers widen to
+/// floats as they do at runtime.
+pub(crate) fn annotation_rejects(expected: &Type, found: &Type) -> bool {
+    let checked = matches!(
+        expected,
+        Type::Int | Type::Float | Type::String | Type::Bool
+    );
+    checked && expected != found && !(*expected == Type::Float && *found == Type::Int)
+}
+
+/// For an arithmetic operator other than `+`, find an operand that can never
+/// be valid: a string or boolean literal paired with a numeric literal.
+pub(crate) fn operator_mismatch(
+    operator: &BinaryOp,
+    left: &Expr,
+    right: &Expr,
+) -> Option<OperatorMismatch> {
+    let symbol = match operator {
+        BinaryOp::Subtract => "-",
+        BinaryOp::Multiply => "*",
+        BinaryOp::Divide => "/",
+        BinaryOp::Modulo => "%",
+        _ => return None,
+    };
+    let is_numeric = |ty: &Type| matches!(ty, Type::Int | Type::Float);
+    let (left_ty, right_ty) = (literal_type(left)?, literal_type(right)?);
+    let (expected, found, left_at_fault) = if is_numeric(&right_ty) && !is_numeric(&left_ty) {
+        (right_ty, left_ty, true)
+    } else if is_numeric(&left_ty) && !is_numeric(&right_ty) {
+        (left_ty, right_ty, false)
+    } else {
+        return None;
+    };
+    Some(OperatorMismatch {
+        symbol,
+        expected,
+        found,
+        left_at_fault,
+    })
+}
+
+/// An operand of `symbol` whose type can never satisfy the operator.
+pub(crate) struct OperatorMismatch {
+    pub symbol: &'static str,
+    pub expected: Type,
+    pub found: Type,
+    /// `true` when the offending operand is the left one.
+    pub left_at_fault: bool,
+}
+
+//=====================================================
+// End of file
+//=====================================================
@@ -11,17 +11,24 @@
 //==============================================
 
 use std::fs;
-use std::path::PathBuf;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
 use std::time::{Duration, Instant};
 
-use crate::interpreter::{Interpreter, Value};
+use crate::ast::{ExportItem, Program, Stmt};
+use crate::interpreter::{CoverageReport, Interpreter, Value};
 use crate::parser::Parser;
 use crate::tokenizer::Tokenizer;
 
 //==============================================
 // Section 1.0 - SVS Test Harness
 //==============================================
-// Run an .svs script relative to the crate root and assert it returns `true`.
+// Run an .svs script relative to the crate root, assert it returns `true`,
+// and compare what it printed against the adjacent `.expected` file.
 pub fn run_svs_test(relative_path: &str) {
     let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     let script_path = manifest_dir.join(relative_path);
@@ -38,6 +45,7 @@ pub fn run_svs_test(relative_path: &str) {
         .unwrap_or_else(|err| panic!("parse {}: {:?}", script_path.display(), err));
 
     let mut interpreter = Interpreter::with_std();
+    interpreter.capture_output();
     interpreter.reset_execution_timer();
     let search_paths = interpreter.script_search_paths();
     println!(
@@ -59,13 +67,307 @@ pub fn run_svs_test(relative_path: &str) {
         );
     }
 
+    let output = interpreter.take_output();
     match result {
         Value::Bool(true) => {}
         other => panic!(
-            "SVS test {} failed: expected boolean true, got {other:?}",
+            "SVS test {} failed: expected boolean true, got {other:?}\noutput:\n{output}",
             script_path.display()
         ),
     }
+    check_expected_output(&script_path, &output);
+}
+
+//==============================================
+// Section 1.1 - Expected Output
+//==============================================
+// Compare `actual` with `<fixture>.expected`. A missing file is written out
+// as `<fixture>.actual` for review so new fixtures are quick to bless.
+fn check_expected_output(script_path: &Path, actual: &str) {
+    let expected_path = script_path.with_extension("expected");
+    let expected = match fs::read_to_string(&expected_path) {
+        Ok(expected) => expected,
+        Err(_) => {
+            let actual_path = script_path.with_extension("actual");
+            fs::write(&actual_path, actual)
+                .unwrap_or_else(|err| panic!("write {} failed: {}", actual_path.display(), err));
+            panic!(
+                "SVS test {} has no expected output; wrote {}. Review it and rename it to {} to accept.",
+                script_path.display(),
+                actual_path.display(),
+                expected_path.display()
+            );
+        }
+    };
+    if expected != actual {
+        panic!(
+            "SVS test {} output differs from {}:\n{}",
+            script_path.display(),
+            expected_path.display(),
+            unified_diff(&expected, actual)
+        );
+    }
+}
+
+// Line-by-line diff: ` ` common, `-` only expected, `+` only actual.
+fn unified_diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // lcs[i][j] = longest common subsequence of old[i..] and new[j..].
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::from("--- expected\n+++ actual\n");
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff.push_str(&format!(" {}\n", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            diff.push_str(&format!("-{}\n", old[i]));
+            i += 1;
+        } else {
+            diff.push_str(&format!("+{}\n", new[j]));
+            j += 1;
+        }
+    }
+    if old == new && expected.ends_with('\n') != actual.ends_with('\n') {
+        diff.push_str("\\ trailing newline differs\n");
+    }
+    diff
+}
+
+//==============================================
+// Section 2.0 - Test Discovery
+//==============================================
+// `solvrascript test <dir>` runs every top-level, zero-argument function whose
+// name starts with `test_` in the directory's .svs files, several at a time.
+// Each test gets a fresh interpreter that runs the file's top-level code
+// first, so tests cannot observe each other's state. A test fails when it
+// raises any runtime error, including a failed `assert`/`assert_eq`.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TestOutcome {
+    Passed,
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct TestCaseResult {
+    pub file: PathBuf,
+    pub name: String,
+    pub outcome: TestOutcome,
+    pub elapsed: Duration,
+    /// Lines this test ran, when the run collected coverage.
+    pub coverage: Option<CoverageReport>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TestSummary {
+    pub results: Vec<TestCaseResult>,
+}
+
+impl TestSummary {
+    pub fn passed(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|result| result.outcome == TestOutcome::Passed)
+            .count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.passed()
+    }
+
+    /// Every test's coverage merged into one report, or `None` when the run
+    /// did not collect coverage.
+    pub fn coverage(&self) -> Option<CoverageReport> {
+        let mut reports = self
+            .results
+            .iter()
+            .filter_map(|result| result.coverage.as_ref())
+            .peekable();
+        reports.peek()?;
+        let mut merged = CoverageReport::default();
+        for report in reports {
+            merged.merge(report);
+        }
+        Some(merged)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TestRunOptions {
+    /// Worker threads; defaults to one per available core.
+    pub jobs: usize,
+    /// Record line coverage for each test.
+    pub coverage: bool,
+}
+
+impl Default for TestRunOptions {
+    fn default() -> Self {
+        Self {
+            jobs: thread::available_parallelism().map_or(1, usize::from),
+            coverage: false,
+        }
+    }
+}
+
+/// Stack size for test worker threads, matching the main thread so deeply
+/// recursive tests behave as they do under `run_svs_test`.
+const TEST_THREAD_STACK_BYTES: usize = 8 * 1024 * 1024;
+
+/// Run the tests in every .svs file under `dir` with the default options.
+pub fn run_test_dir(dir: &Path) -> io::Result<TestSummary> {
+    run_test_dir_with(dir, &TestRunOptions::default())
+}
+
+/// Run the tests in every .svs file under `dir` on up to `options.jobs`
+/// threads. Results are ordered by file path and then declaration order,
+/// regardless of which test finishes first. A file that does not parse is
+/// reported as one failed `<load>` case.
+pub fn run_test_dir_with(dir: &Path, options: &TestRunOptions) -> io::Result<TestSummary> {
+    let mut files = Vec::new();
+    collect_svs_files(dir, &mut files)?;
+    files.sort();
+
+    let mut slots: Vec<Option<TestCaseResult>> = Vec::new();
+    let mut programs = Vec::new();
+    let mut pending = Vec::new();
+    for file in files {
+        let start = Instant::now();
+        match load_test_program(&file) {
+            Ok(program) => {
+                for name in discover_tests(&program) {
+                    pending.push((slots.len(), programs.len(), name));
+                    slots.push(None);
+                }
+                programs.push((file, program));
+            }
+            Err(message) => slots.push(Some(TestCaseResult {
+                file,
+                name: "<load>".to_string(),
+                outcome: TestOutcome::Failed(message),
+                elapsed: start.elapsed(),
+                coverage: None,
+            })),
+        }
+    }
+
+    let next = AtomicUsize::new(0);
+    let finished = Mutex::new(Vec::with_capacity(pending.len()));
+    let worker = || {
+        while let Some((slot, program_index, name)) =
+            pending.get(next.fetch_add(1, Ordering::Relaxed))
+        {
+            let (file, program) = &programs[*program_index];
+            let start = Instant::now();
+            let (outcome, coverage) = run_test_case(file, program, name, options.coverage);
+            let result = TestCaseResult {
+                file: file.clone(),
+                name: name.clone(),
+                outcome,
+                elapsed: start.elapsed(),
+                coverage,
+            };
+            finished.lock().unwrap().push((*slot, result));
+        }
+    };
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.clamp(1, pending.len().max(1)) {
+            thread::Builder::new()
+                .stack_size(TEST_THREAD_STACK_BYTES)
+                .spawn_scoped(scope, worker)?;
+        }
+        Ok::<_, io::Error>(())
+    })?;
+
+    for (slot, result) in finished.into_inner().unwrap() {
+        slots[slot] = Some(result);
+    }
+    Ok(TestSummary {
+        results: slots.into_iter().flatten().collect(),
+    })
+}
+
+/// Names of the `test_` functions declared at the top level of `program`.
+pub fn discover_tests(program: &Program) -> Vec<String> {
+    program
+        .statements
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Stmt::FunctionDecl { decl } => Some(decl),
+            Stmt::ExportDecl { decl } => match &decl.item {
+                ExportItem::Function(function) => Some(function),
+                _ => None,
+            },
+            _ => None,
+        })
+        .filter(|decl| decl.name.as_str().starts_with("test_") && decl.params.is_empty())
+        .map(|decl| decl.name.as_str().to_string())
+        .collect()
+}
+
+fn collect_svs_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_svs_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "svs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn load_test_program(path: &Path) -> Result<Program, String> {
+    let source = fs::read_to_string(path).map_err(|err| format!("read failed: {err}"))?;
+    let tokens = Tokenizer::new(&source)
+        .tokenize()
+        .map_err(|err| format!("tokenize failed: {err}"))?;
+    let mut program = Parser::new(tokens)
+        .parse()
+        .map_err(|err| format!("parse failed: {err}"))?;
+    // Top-level bindings must be globals the tests can see, not locals of
+    // an implicit `main` that never runs.
+    program.lift_implicit_entry();
+    Ok(program)
+}
+
+fn run_test_case(
+    path: &Path,
+    program: &Program,
+    name: &str,
+    coverage: bool,
+) -> (TestOutcome, Option<CoverageReport>) {
+    // Every test gets its own interpreter, module loader, and globals; a
+    // panic is contained to the test that raised it.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut interpreter = Interpreter::with_std();
+        interpreter.capture_output();
+        if coverage {
+            interpreter.enable_coverage();
+        }
+        let result = interpreter
+            .eval_program_with_origin(program, Some(path))
+            .and_then(|_| interpreter.call_global_function(name, Vec::new()));
+        (result, coverage.then(|| interpreter.take_coverage()))
+    }));
+    match result {
+        Ok((Ok(_), report)) => (TestOutcome::Passed, report),
+        Ok((Err(err), report)) => (TestOutcome::Failed(err.to_string()), report),
+        Err(_) => (TestOutcome::Failed("test panicked".to_string()), None),
+    }
 }
 
 //==============================================
@@ -7,6 +7,10 @@
 // Objective: Provide Symbol struct and intern_symbol()
 //==================================================
 
+pub mod table;
+
+pub use table::{ScopeId, SymbolInfo, SymbolKind, SymbolTable};
+
 use std::borrow::Borrow;
 use std::fmt;
 use std::ops::Deref;
@@ -0,0 +1,199 @@
+//==================================================
+// File: symbol/table.rs
+//==================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Scoped symbol table shared by the resolver and tooling
+// Objective: Track nested scopes and answer "what does this name refer to
+//            here?" queries with kind, declaration span, and type
+//==================================================
+
+use crate::ast::{NodeId, Span, Type};
+use std::collections::HashMap;
+
+/// Handle to a scope inside a [`SymbolTable`]. Scopes are never freed, so a
+/// handle stays valid after the scope is exited and can be queried later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScopeId(usize);
+
+impl ScopeId {
+    /// The module-level scope every table starts in.
+    pub const ROOT: ScopeId = ScopeId(0);
+}
+
+/// What introduced a binding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    Variable,
+    Constant,
+    Function,
+    Parameter,
+    LoopVariable,
+}
+
+/// Everything known about a declared name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymbolInfo {
+    pub kind: SymbolKind,
+    pub declaration_span: Span,
+    pub ty: Type,
+    /// AST node of the declaration, used to link identifier uses to it.
+    pub node_id: NodeId,
+}
+
+#[derive(Debug, Clone)]
+struct Scope {
+    parent: Option<ScopeId>,
+    bindings: HashMap<String, SymbolInfo>,
+}
+
+/// A tree of lexical scopes. `enter_scope`/`exit_scope` move a cursor through
+/// the tree while declarations are collected; `resolve` can then be asked
+/// about any scope, current or not.
+#[derive(Debug, Clone)]
+pub struct SymbolTable {
+    scopes: Vec<Scope>,
+    current: ScopeId,
+}
+
+impl SymbolTable {
+    pub fn new() -> Self {
+        Self {
+            scopes: vec![Scope {
+                parent: None,
+                bindings: HashMap::new(),
+            }],
+            current: ScopeId::ROOT,
+        }
+    }
+
+    /// The scope new declarations are added to.
+    pub fn current_scope(&self) -> ScopeId {
+        self.current
+    }
+
+    /// Open a child of the current scope and make it current.
+    pub fn enter_scope(&mut self) -> ScopeId {
+        let id = ScopeId(self.scopes.len());
+        self.scopes.push(Scope {
+            parent: Some(self.current),
+            bindings: HashMap::new(),
+        });
+        self.current = id;
+        id
+    }
+
+    /// Return to the parent of the current scope. Exiting the root scope is a
+    /// no-op.
+    pub fn exit_scope(&mut self) {
+        if let Some(parent) = self.scopes[self.current.0].parent {
+            self.current = parent;
+        }
+    }
+
+    /// Declare `name` in the current scope, replacing any earlier declaration
+    /// of the same name in that scope.
+    pub fn define(&mut self, name: impl Into<String>, info: SymbolInfo) {
+        self.scopes[self.current.0]
+            .bindings
+            .insert(name.into(), info);
+    }
+
+    /// Look `name` up from `scope` outwards, returning the innermost
+    /// declaration visible there.
+    pub fn resolve(&self, name: &str, scope: ScopeId) -> Option<&SymbolInfo> {
+        let mut scope = self.scopes.get(scope.0);
+        while let Some(current) = scope {
+            if let Some(info) = current.bindings.get(name) {
+                return Some(info);
+            }
+            scope = current.parent.map(|parent| &self.scopes[parent.0]);
+        }
+        None
+    }
+
+    /// The declaration whose AST node is `node_id`, in whichever scope it
+    /// was made. Pairs with the use-to-definition map the resolver builds.
+    pub fn declaration(&self, node_id: NodeId) -> Option<&SymbolInfo> {
+        self.scopes
+            .iter()
+            .flat_map(|scope| scope.bindings.values())
+            .find(|info| info.node_id == node_id)
+    }
+
+    /// Parent of `scope`, or `None` for the root scope.
+    pub fn parent(&self, scope: ScopeId) -> Option<ScopeId> {
+        self.scopes.get(scope.0).and_then(|scope| scope.parent)
+    }
+}
+
+impl Default for SymbolTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::next_node_id;
+    use crate::tokenizer::Position;
+
+    fn info(kind: SymbolKind, line: usize, ty: Type) -> SymbolInfo {
+        SymbolInfo {
+            kind,
+            declaration_span: Span::point(Position::new(line, 1, 0)),
+            ty,
+            node_id: next_node_id(),
+        }
+    }
+
+    #[test]
+    fn resolves_outer_variable_from_inner_scope() {
+        let mut table = SymbolTable::new();
+        table.define("count", info(SymbolKind::Variable, 1, Type::Int));
+        let inner = table.enter_scope();
+        table.define("step", info(SymbolKind::Constant, 2, Type::Int));
+
+        let found = table.resolve("count", inner).expect("count is visible");
+        assert_eq!(found.kind, SymbolKind::Variable);
+        assert_eq!(found.declaration_span.start.line, 1);
+        assert_eq!(found.ty, Type::Int);
+        assert_eq!(
+            table.resolve("step", inner).map(|info| info.kind),
+            Some(SymbolKind::Constant)
+        );
+
+        table.exit_scope();
+        assert_eq!(table.current_scope(), ScopeId::ROOT);
+        assert!(table.resolve("step", ScopeId::ROOT).is_none());
+    }
+
+    #[test]
+    fn inner_declaration_shadows_outer_one() {
+        let mut table = SymbolTable::new();
+        table.define("value", info(SymbolKind::Variable, 1, Type::Int));
+        let inner = table.enter_scope();
+        table.define("value", info(SymbolKind::Variable, 3, Type::String));
+        table.exit_scope();
+
+        let shadowed = table.resolve("value", inner).unwrap();
+        assert_eq!(shadowed.declaration_span.start.line, 3);
+        assert_eq!(shadowed.ty, Type::String);
+        let outer = table.resolve("value", ScopeId::ROOT).unwrap();
+        assert_eq!(outer.declaration_span.start.line, 1);
+        assert_eq!(outer.ty, Type::Int);
+    }
+
+    #[test]
+    fn undefined_name_resolves_to_none() {
+        let mut table = SymbolTable::new();
+        let inner = table.enter_scope();
+        assert!(table.resolve("missing", inner).is_none());
+        assert!(table.resolve("missing", ScopeId::ROOT).is_none());
+    }
+}
+
+//==================================================
+// End of file
+//==================================================
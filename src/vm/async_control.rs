@@ -10,6 +10,7 @@ pub struct AsyncControl {
 struct TaskState {
     cancelled: bool,
     deadline: Option<Instant>,
+    timed_out: bool,
 }
 
 impl AsyncControl {
@@ -24,6 +25,7 @@ impl AsyncControl {
             TaskState {
                 cancelled: false,
                 deadline: None,
+                timed_out: false,
             },
         );
     }
@@ -75,6 +77,24 @@ impl AsyncControl {
             .unwrap_or(false)
     }
 
+    /// Record that `task_id` ended by hitting a deadline, so whoever awaits
+    /// it reports a timeout rather than a task failure.
+    pub fn mark_timed_out(&self, task_id: u64) {
+        let mut state = self.state.lock().expect("async control state poisoned");
+        if let Some(entry) = state.get_mut(&task_id) {
+            entry.timed_out = true;
+        }
+    }
+
+    pub fn timed_out(&self, task_id: u64) -> bool {
+        self.state
+            .lock()
+            .expect("async control state poisoned")
+            .get(&task_id)
+            .map(|entry| entry.timed_out)
+            .unwrap_or(false)
+    }
+
     pub fn complete(&self, task_id: u64) {
         self.state
             .lock()
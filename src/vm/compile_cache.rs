@@ -0,0 +1,202 @@
+//=====================================================
+// File: vm/compile_cache.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Persistent cache for compiled VM bytecode
+// Objective: Skip recompiling unchanged .svs sources by keying bytecode on a
+//            hash of the source text and the compiler options used
+//=====================================================
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+use super::compiler::{CompiledProgram, CompilerOptions};
+use super::debug_info::DebugInfo;
+use crate::ir::ir::SolvraIrModule;
+use crate::ir::opt::PassManager;
+use crate::ir::serialize::{IR_FORMAT_VERSION, decode_module, encode_module};
+
+/// Bumped whenever the compiler output format changes so stale entries from
+/// older builds are never reused.
+const CACHE_FORMAT_VERSION: u32 = 2;
+const CACHE_EXTENSION: &str = "svcache";
+const IR_CACHE_EXTENSION: &str = "svir";
+
+/// Whether a lookup was served from disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheStatus {
+    Hit,
+    Miss,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    bytecode: Vec<u8>,
+    debug_info: Option<DebugInfo>,
+}
+
+/// On-disk compile cache rooted at a directory, one file per cache key.
+#[derive(Debug, Clone)]
+pub struct CompileCache {
+    dir: PathBuf,
+}
+
+impl CompileCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Platform cache directory, e.g. `~/.cache/solvrascript/bytecode`.
+    pub fn default_dir() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("solvrascript").join("bytecode"))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Stable cache key for `source` compiled with `options`.
+    pub fn key(source: &str, options: CompilerOptions) -> String {
+        let mut hash = Fnv1a::new();
+        hash.write(&CACHE_FORMAT_VERSION.to_le_bytes());
+        hash.write(env!("CARGO_PKG_VERSION").as_bytes());
+        hash.write(&[
+            options.debug_info as u8,
+            options.optimize as u8,
+            options.fold_constants as u8,
+            options.licm as u8,
+        ]);
+        hash.write(source.as_bytes());
+        format!("{:016x}", hash.finish())
+    }
+
+    /// Stable cache key for the SolvraIR lowered from `source` and optimized
+    /// with `passes`.
+    pub fn ir_key(source: &str, passes: Option<&PassManager>) -> String {
+        let mut hash = Fnv1a::new();
+        hash.write(&IR_FORMAT_VERSION.to_le_bytes());
+        hash.write(env!("CARGO_PKG_VERSION").as_bytes());
+        for pass in passes.map(PassManager::passes).unwrap_or_default() {
+            hash.write(pass.name().as_bytes());
+            hash.write(b",");
+        }
+        hash.write(source.as_bytes());
+        format!("{:016x}", hash.finish())
+    }
+
+    /// Return the cached SolvraIR module for `source`, or run `lower` and
+    /// persist its output. Modules from another IR format version fail to
+    /// decode and are treated as a miss, like corrupt entries.
+    pub fn get_or_lower<F>(
+        &self,
+        source: &str,
+        passes: Option<&PassManager>,
+        lower: F,
+    ) -> Result<(SolvraIrModule, CacheStatus)>
+    where
+        F: FnOnce() -> Result<SolvraIrModule>,
+    {
+        let path = self.ir_entry_path(&Self::ir_key(source, passes));
+        if let Some(module) = fs::read(&path)
+            .ok()
+            .and_then(|bytes| decode_module(&bytes).ok())
+        {
+            return Ok((module, CacheStatus::Hit));
+        }
+        let module = lower()?;
+        let encoded = encode_module(&module).map_err(|err| anyhow!("cache encode error: {err}"))?;
+        self.write_entry(&path, encoded)?;
+        Ok((module, CacheStatus::Miss))
+    }
+
+    /// Return cached bytecode for `source`, or run `compile` and persist its
+    /// output. A corrupt or unreadable entry is treated as a miss.
+    pub fn get_or_compile<F>(
+        &self,
+        source: &str,
+        options: CompilerOptions,
+        compile: F,
+    ) -> Result<(CompiledProgram, CacheStatus)>
+    where
+        F: FnOnce() -> Result<CompiledProgram>,
+    {
+        let key = Self::key(source, options);
+        if let Some(compiled) = self.load(&key) {
+            return Ok((compiled, CacheStatus::Hit));
+        }
+        let compiled = compile()?;
+        self.store(&key, &compiled)?;
+        Ok((compiled, CacheStatus::Miss))
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.{CACHE_EXTENSION}"))
+    }
+
+    fn ir_entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.{IR_CACHE_EXTENSION}"))
+    }
+
+    fn load(&self, key: &str) -> Option<CompiledProgram> {
+        let bytes = fs::read(self.entry_path(key)).ok()?;
+        let entry: CacheEntry = bincode::deserialize(&bytes).ok()?;
+        Some(CompiledProgram {
+            bytecode: entry.bytecode,
+            debug_info: entry.debug_info,
+        })
+    }
+
+    fn store(&self, key: &str, compiled: &CompiledProgram) -> Result<()> {
+        let entry = CacheEntry {
+            bytecode: compiled.bytecode.clone(),
+            debug_info: compiled.debug_info.clone(),
+        };
+        let encoded =
+            bincode::serialize(&entry).map_err(|err| anyhow!("cache encode error: {err}"))?;
+        self.write_entry(&self.entry_path(key), encoded)
+    }
+
+    fn write_entry(&self, path: &Path, encoded: Vec<u8>) -> Result<()> {
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("failed to create cache dir {}", self.dir.display()))?;
+        // Write to a temporary name and rename so readers never see a partial entry.
+        let mut temp = path.as_os_str().to_owned();
+        temp.push(".tmp");
+        let temp = PathBuf::from(temp);
+        fs::write(&temp, encoded).with_context(|| format!("failed to write {}", temp.display()))?;
+        match fs::rename(&temp, path) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::AlreadyExists => Ok(()),
+            Err(err) => Err(err).with_context(|| format!("failed to write {}", path.display())),
+        }
+    }
+}
+
+/// 64-bit FNV-1a; unlike `DefaultHasher` its output is stable across builds.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+//=====================================================
+// End of file
+//=====================================================
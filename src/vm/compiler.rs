@@ -1,9 +1,12 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use super::const_fold;
 use super::core_builtins::is_core_builtin_name;
+use super::debug_info::{DebugInfo, LineEntry, LocalEntry};
+use super::licm;
 use crate::ast::{
-    AssignTarget, BinaryOp, Expr, FunctionDecl, Literal, MemberKind, Parameter, Program, Stmt,
-    StringPart, Type, UnaryOp, VariableDecl, Visibility, next_node_id,
+    AssignTarget, BinaryOp, Expr, FunctionDecl, Literal, MemberKind, Parameter, Program, Span,
+    Stmt, StringPart, Type, UnaryOp, VariableDecl, Visibility, next_node_id,
 };
 use crate::bytecode::peephole;
 use crate::symbol::Symbol;
@@ -15,13 +18,74 @@ use solvra_core::vm::instruction::{Instruction, Opcode};
 
 const DYNAMIC_CALL_TARGET: u32 = u32::MAX;
 
+/// Selects between debug and release bytecode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompilerOptions {
+    /// Record a source line table for every function.
+    pub debug_info: bool,
+    /// Run bytecode optimizations (peephole). Optimizations rewrite
+    /// instruction indices, so they are skipped when `debug_info` is set.
+    pub optimize: bool,
+    /// Evaluate pure literal subexpressions (e.g. `2 + 3 * 4`) at compile time.
+    pub fold_constants: bool,
+    /// Hoist pure loop-invariant subexpressions out of `while` loops.
+    pub licm: bool,
+}
+
+impl CompilerOptions {
+    pub fn debug() -> Self {
+        Self {
+            debug_info: true,
+            optimize: false,
+            fold_constants: false,
+            licm: false,
+        }
+    }
+
+    pub fn release() -> Self {
+        Self {
+            debug_info: false,
+            optimize: true,
+            fold_constants: true,
+            licm: true,
+        }
+    }
+}
+
+impl Default for CompilerOptions {
+    fn default() -> Self {
+        Self::release()
+    }
+}
+
+/// Serialized bytecode plus the optional debug line tables produced with it.
+#[derive(Debug, Clone)]
+pub struct CompiledProgram {
+    pub bytecode: Vec<u8>,
+    pub debug_info: Option<DebugInfo>,
+}
+
 pub fn compile_program(program: &Program) -> Result<Vec<u8>> {
-    let mut compiler = Compiler::default();
+    compile_program_with_options(program, CompilerOptions::default())
+        .map(|compiled| compiled.bytecode)
+}
+
+pub fn compile_program_with_options(
+    program: &Program,
+    options: CompilerOptions,
+) -> Result<CompiledProgram> {
+    let mut compiler = Compiler::new(options);
     compiler.index_functions(program)?;
     compiler.compile_program(program)?;
+    let debug_info = compiler.take_debug_info();
     let mut bytecode = compiler.into_bytecode()?;
-    peephole::optimize(&mut bytecode);
-    vm_to_bytes(bytecode)
+    if options.optimize && !options.debug_info {
+        peephole::optimize(&mut bytecode);
+    }
+    Ok(CompiledProgram {
+        bytecode: vm_to_bytes(bytecode)?,
+        debug_info,
+    })
 }
 
 #[allow(dead_code)] // Retained for external callers that compile single functions.
@@ -35,8 +99,8 @@ pub fn compile_function(stmt: &Stmt) -> Result<Vec<u8>> {
 fn compile_function_decl(decl: &FunctionDecl) -> Result<Vec<u8>> {
     let mut compiler = Compiler::default();
     compiler.register_function(&decl.name);
-    let function = compiler.build_function(decl)?;
-    compiler.store_function(&decl.name, function)?;
+    let (function, tables) = compiler.build_function(decl)?;
+    compiler.store_function(&decl.name, function, tables)?;
     let mut bytecode = compiler.into_bytecode()?;
     peephole::optimize(&mut bytecode);
     vm_to_bytes(bytecode)
@@ -66,7 +130,7 @@ pub fn compile_function_from_parts(
         body: body.to_vec(),
         is_async: false,
         visibility: Visibility::Private,
-        position: Position::new(0, 0, 0),
+        span: Span::point(Position::new(0, 0, 0)),
         node_id: next_node_id(),
     };
 
@@ -74,8 +138,10 @@ pub fn compile_function_from_parts(
 }
 
 struct Compiler {
+    options: CompilerOptions,
     constants: Vec<Constant>,
     functions: Vec<Option<Function>>,
+    debug_tables: Vec<FunctionDebug>,
     function_indices: HashMap<String, usize>,
     lambda_counter: usize,
     constant_cache: HashMap<ConstantKey, u32>,
@@ -83,17 +149,34 @@ struct Compiler {
 
 impl Default for Compiler {
     fn default() -> Self {
+        Self::new(CompilerOptions::default())
+    }
+}
+
+impl Compiler {
+    fn new(options: CompilerOptions) -> Self {
         Self {
+            options,
             constants: Vec::new(),
             functions: Vec::new(),
+            debug_tables: Vec::new(),
             function_indices: HashMap::new(),
             lambda_counter: 0,
             constant_cache: HashMap::new(),
         }
     }
-}
 
-impl Compiler {
+    fn take_debug_info(&mut self) -> Option<DebugInfo> {
+        if !self.options.debug_info {
+            return None;
+        }
+        let (functions, locals) = std::mem::take(&mut self.debug_tables)
+            .into_iter()
+            .map(|tables| (tables.lines, tables.locals))
+            .unzip();
+        Some(DebugInfo { functions, locals })
+    }
+
     fn index_functions(&mut self, program: &Program) -> Result<()> {
         for stmt in &program.statements {
             if let Stmt::FunctionDecl { decl } = stmt {
@@ -109,8 +192,8 @@ impl Compiler {
     fn compile_program(&mut self, program: &Program) -> Result<()> {
         for stmt in &program.statements {
             if let Stmt::FunctionDecl { decl } = stmt {
-                let function = self.build_function(decl)?;
-                self.store_function(&decl.name, function)?;
+                let (function, tables) = self.build_function(decl)?;
+                self.store_function(&decl.name, function, tables)?;
             }
         }
         Ok(())
@@ -123,9 +206,15 @@ impl Compiler {
         let index = self.functions.len();
         self.function_indices.insert(name.to_string(), index);
         self.functions.push(None);
+        self.debug_tables.push(FunctionDebug::default());
     }
 
-    fn store_function(&mut self, name: &str, function: Function) -> Result<()> {
+    fn store_function(
+        &mut self,
+        name: &str,
+        function: Function,
+        tables: FunctionDebug,
+    ) -> Result<()> {
         let index = *self
             .function_indices
             .get(name)
@@ -134,6 +223,7 @@ impl Compiler {
             bail!("function '{name}' already compiled");
         }
         self.functions[index] = Some(function);
+        self.debug_tables[index] = tables;
         Ok(())
     }
 
@@ -152,7 +242,7 @@ impl Compiler {
         ))
     }
 
-    fn build_function(&mut self, decl: &FunctionDecl) -> Result<Function> {
+    fn build_function(&mut self, decl: &FunctionDecl) -> Result<(Function, FunctionDebug)> {
         let mut builder = FunctionCompiler::new(self, decl)?;
         builder.compile_statements(&decl.body)?;
         builder.finish(&decl.name)
@@ -186,12 +276,7 @@ impl Compiler {
         index
     }
 
-    fn compile_lambda(
-        &mut self,
-        params: &[Symbol],
-        body: &Expr,
-        position: &Position,
-    ) -> Result<u32> {
+    fn compile_lambda(&mut self, params: &[Symbol], body: &Expr, span: &Span) -> Result<u32> {
         let lambda_index = self.lambda_counter;
         self.lambda_counter += 1;
         let name = format!("__lambda{}", lambda_index);
@@ -202,12 +287,12 @@ impl Compiler {
                 param_type: Type::Inferred,
                 type_annotation: None,
                 default_value: None,
-                position: position.clone(),
+                position: span.start.clone(),
             })
             .collect::<Vec<_>>();
         let lambda_body = vec![Stmt::Return {
             value: Some(body.clone()),
-            position: position.clone(),
+            span: span.clone(),
         }];
         let decl = FunctionDecl {
             name: Symbol::from(name),
@@ -217,14 +302,15 @@ impl Compiler {
             body: lambda_body,
             is_async: false,
             visibility: Visibility::Private,
-            position: position.clone(),
+            span: span.clone(),
             node_id: next_node_id(),
         };
         let mut builder = FunctionCompiler::new(self, &decl)?;
         builder.compile_statements(&decl.body)?;
-        let function = builder.finish(&decl.name)?;
+        let (function, tables) = builder.finish(&decl.name)?;
         let index = self.functions.len();
         self.functions.push(Some(function));
+        self.debug_tables.push(tables);
         Ok(index as u32)
     }
 
@@ -254,6 +340,13 @@ impl From<&Constant> for ConstantKey {
     }
 }
 
+/// Debug tables gathered while compiling one function.
+#[derive(Default)]
+struct FunctionDebug {
+    lines: Vec<LineEntry>,
+    locals: Vec<LocalEntry>,
+}
+
 struct FunctionCompiler<'a> {
     program: &'a mut Compiler,
     instructions: Vec<Instruction>,
@@ -262,6 +355,11 @@ struct FunctionCompiler<'a> {
     max_slot: u32,
     param_count: u16,
     loop_stack: Vec<LoopFrame>,
+    lines: Vec<LineEntry>,
+    locals: Vec<LocalEntry>,
+    /// Loop-invariant expressions already computed into a slot, keyed by
+    /// node address within the function body being compiled.
+    hoisted: HashMap<*const Expr, u32>,
 }
 
 impl<'a> FunctionCompiler<'a> {
@@ -278,6 +376,9 @@ impl<'a> FunctionCompiler<'a> {
             max_slot: 0,
             param_count: decl.params.len() as u16,
             loop_stack: Vec::new(),
+            lines: Vec::new(),
+            locals: Vec::new(),
+            hoisted: HashMap::new(),
         };
 
         compiler.begin_scope();
@@ -306,21 +407,35 @@ impl<'a> FunctionCompiler<'a> {
         Ok(())
     }
 
-    fn finish(mut self, name: &str) -> Result<Function> {
+    fn finish(mut self, name: &str) -> Result<(Function, FunctionDebug)> {
         if !matches!(self.instructions.last(), Some(inst) if inst.opcode == Opcode::Return) {
             let null_index = self.program.constant_index(Constant::Null);
             self.emit_instruction(Opcode::LoadConst, &[null_index]);
             self.emit_op(Opcode::Return);
         }
         self.end_scope();
-        Ok(Function::new(
-            name.to_string(),
-            self.param_count,
-            self.instructions,
-        ))
+        let function = Function::new(name.to_string(), self.param_count, self.instructions);
+        let tables = FunctionDebug {
+            lines: self.lines,
+            locals: self.locals,
+        };
+        Ok((function, tables))
+    }
+
+    fn record_line(&mut self, line: usize) {
+        if !self.program.options.debug_info {
+            return;
+        }
+        let ip = self.instructions.len();
+        match self.lines.last_mut() {
+            Some(last) if last.line == line => {}
+            Some(last) if last.ip == ip => last.line = line,
+            _ => self.lines.push(LineEntry { ip, line }),
+        }
     }
 
     fn compile_stmt(&mut self, stmt: &Stmt) -> Result<()> {
+        self.record_line(stmt.position().line);
         match stmt {
             Stmt::Expression { expr, .. } => {
                 self.compile_expr(expr)?;
@@ -414,17 +529,72 @@ impl<'a> FunctionCompiler<'a> {
     }
 
     fn compile_while_stmt(&mut self, condition: &Expr, body: &Stmt) -> Result<()> {
+        if self.program.options.licm {
+            let skip: HashSet<*const Expr> = self.hoisted.keys().copied().collect();
+            let invariants =
+                licm::invariants(condition, body, self.program.options.fold_constants, &skip);
+            if !invariants.is_empty() {
+                return self.compile_hoisted_while(condition, body, &invariants);
+            }
+        }
+        let loop_start = self.instructions.len();
+        self.compile_expr(condition)?;
+        let exit_jump = self.emit_jump(Opcode::JumpIfFalse);
+        self.begin_loop(loop_start);
+        self.begin_scope();
+        self.compile_stmt(body)?;
+        self.end_scope();
+        self.emit_instruction(Opcode::Jump, &[loop_start as u32]);
+        self.patch_jump(exit_jump);
+        let break_target = self.instructions.len();
+        self.end_loop(break_target);
+        Ok(())
+    }
+
+    /// Compile a `while` loop whose `invariants` are evaluated once, after
+    /// the first successful condition test, so a loop that never runs never
+    /// evaluates them:
+    ///
+    /// ```text
+    ///     condition; JumpIfFalse exit
+    ///     invariants -> slots; Jump body
+    /// top:
+    ///     condition (using slots); JumpIfFalse exit
+    /// body:
+    ///     body; Jump top
+    /// exit:
+    /// ```
+    fn compile_hoisted_while(
+        &mut self,
+        condition: &Expr,
+        body: &Stmt,
+        invariants: &[&Expr],
+    ) -> Result<()> {
+        self.compile_expr(condition)?;
+        let first_exit = self.emit_jump(Opcode::JumpIfFalse);
+        for expr in invariants {
+            self.compile_expr(expr)?;
+            let slot = self.allocate_hidden_slot();
+            self.emit_instruction(Opcode::StoreVar, &[slot]);
+            self.hoisted.insert(*expr as *const Expr, slot);
+        }
+        let enter_body = self.emit_jump(Opcode::Jump);
         let loop_start = self.instructions.len();
         self.compile_expr(condition)?;
         let exit_jump = self.emit_jump(Opcode::JumpIfFalse);
+        self.patch_jump(enter_body);
         self.begin_loop(loop_start);
         self.begin_scope();
         self.compile_stmt(body)?;
         self.end_scope();
         self.emit_instruction(Opcode::Jump, &[loop_start as u32]);
+        self.patch_jump(first_exit);
         self.patch_jump(exit_jump);
         let break_target = self.instructions.len();
         self.end_loop(break_target);
+        for expr in invariants {
+            self.hoisted.remove(&(*expr as *const Expr));
+        }
         Ok(())
     }
 
@@ -450,6 +620,18 @@ impl<'a> FunctionCompiler<'a> {
     }
 
     fn compile_expr(&mut self, expr: &Expr) -> Result<()> {
+        if self.program.options.fold_constants
+            && matches!(expr, Expr::Binary { .. } | Expr::Unary { .. })
+            && let Some(value) = const_fold::fold(expr)
+        {
+            let index = self.program.literal_constant(&value)?;
+            self.emit_instruction(Opcode::LoadConst, &[index]);
+            return Ok(());
+        }
+        if let Some(slot) = self.hoisted.get(&(expr as *const Expr)).copied() {
+            self.emit_instruction(Opcode::LoadVar, &[slot]);
+            return Ok(());
+        }
         match expr {
             Expr::Literal { value, .. } => match value {
                 Literal::Array(elements) => {
@@ -568,12 +750,8 @@ impl<'a> FunctionCompiler<'a> {
                     bail!("string templates with embedded expressions are not supported")
                 }
             }
-            Expr::Lambda {
-                params,
-                body,
-                position,
-            } => {
-                let function_index = self.program.compile_lambda(params, body, position)?;
+            Expr::Lambda { params, body, span, .. } => {
+                let function_index = self.program.compile_lambda(params, body, span)?;
                 self.emit_instruction(Opcode::LoadLambda, &[function_index]);
                 Ok(())
             }
@@ -837,7 +1015,32 @@ impl<'a> FunctionCompiler<'a> {
     }
 
     fn end_scope(&mut self) {
-        self.scopes.pop();
+        let Some(scope) = self.scopes.pop() else {
+            return;
+        };
+        let end = self.instructions.len();
+        for binding in scope.values() {
+            if let Some(entry) = self
+                .locals
+                .iter_mut()
+                .find(|entry| entry.slot == binding.slot && entry.end == usize::MAX)
+            {
+                entry.end = end;
+            }
+        }
+    }
+
+    /// Note that `name` refers to `slot` from the current instruction until
+    /// its scope ends.
+    fn record_local_name(&mut self, name: &str, slot: u32) {
+        if self.program.options.debug_info {
+            self.locals.push(LocalEntry {
+                name: name.to_string(),
+                slot,
+                start: self.instructions.len(),
+                end: usize::MAX,
+            });
+        }
     }
 
     fn declare_parameter(&mut self, name: &str, slot: u32) -> Result<()> {
@@ -849,6 +1052,7 @@ impl<'a> FunctionCompiler<'a> {
             bail!("parameter '{name}' already declared in this scope");
         }
         scope.insert(name.to_string(), LocalBinding { slot });
+        self.record_local_name(name, slot);
         Ok(())
     }
 
@@ -864,9 +1068,18 @@ impl<'a> FunctionCompiler<'a> {
         scope.insert(name.to_string(), LocalBinding { slot });
         self.next_slot += 1;
         self.max_slot = self.max_slot.max(self.next_slot);
+        self.record_local_name(name, slot);
         Ok(slot)
     }
 
+    /// Reserve a slot no source name refers to for the rest of the function.
+    fn allocate_hidden_slot(&mut self) -> u32 {
+        let slot = self.next_slot;
+        self.next_slot += 1;
+        self.max_slot = self.max_slot.max(self.next_slot);
+        slot
+    }
+
     fn acquire_temp_slot(&mut self) -> (u32, u32) {
         let previous_next = self.next_slot;
         let slot = self.next_slot;
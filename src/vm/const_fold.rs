@@ -0,0 +1,117 @@
+//=====================================================
+// File: vm/const_fold.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Compile-time evaluation of constant subexpressions
+// Objective: Collapse pure literal arithmetic, comparisons, and boolean logic
+//            into a single constant before bytecode emission
+//=====================================================
+
+use crate::ast::{BinaryOp, Expr, Literal, UnaryOp};
+use crate::symbol::intern_symbol;
+
+/// Evaluate `expr` if it is built only from literals and pure operators.
+///
+/// Returns `None` whenever folding could change behaviour: identifiers,
+/// calls, and any other expression with possible side effects are never
+/// folded, and neither are operations the VM would reject or whose result
+/// depends on runtime options (division by zero, integer overflow).
+pub(crate) fn fold(expr: &Expr) -> Option<Literal> {
+    match expr {
+        Expr::Literal { value, .. } => match value {
+            Literal::Array(_) | Literal::Object(_) => None,
+            scalar => Some(scalar.clone()),
+        },
+        Expr::Unary {
+            operator, operand, ..
+        } => fold_unary(operator, fold(operand)?),
+        Expr::Binary {
+            left,
+            operator,
+            right,
+            ..
+        } => fold_binary(operator, fold(left)?, fold(right)?),
+        _ => None,
+    }
+}
+
+fn fold_unary(operator: &UnaryOp, operand: Literal) -> Option<Literal> {
+    match (operator, operand) {
+        (UnaryOp::Minus, Literal::Integer(value)) => value.checked_neg().map(Literal::Integer),
+        (UnaryOp::Minus, Literal::Float(value)) => Some(Literal::Float(-value)),
+        (UnaryOp::Plus, value @ (Literal::Integer(_) | Literal::Float(_))) => Some(value),
+        (UnaryOp::Not, Literal::Boolean(value)) => Some(Literal::Boolean(!value)),
+        _ => None,
+    }
+}
+
+fn fold_binary(operator: &BinaryOp, lhs: Literal, rhs: Literal) -> Option<Literal> {
+    use Literal::{Boolean, Float, Integer};
+    match (lhs, rhs) {
+        (Integer(a), Integer(b)) => match operator {
+            BinaryOp::Add => a.checked_add(b).map(Integer),
+            BinaryOp::Subtract => a.checked_sub(b).map(Integer),
+            BinaryOp::Multiply => a.checked_mul(b).map(Integer),
+            BinaryOp::Divide if b != 0 => a.checked_div(b).map(Integer),
+            BinaryOp::Modulo if b != 0 => a.checked_rem(b).map(Integer),
+            BinaryOp::Equal => Some(Boolean(a == b)),
+            BinaryOp::NotEqual => Some(Boolean(a != b)),
+            _ => fold_ordering(operator, a as f64, b as f64),
+        },
+        (Float(a), Float(b)) => match operator {
+            BinaryOp::Equal => Some(Boolean(a == b)),
+            BinaryOp::NotEqual => Some(Boolean(a != b)),
+            _ => fold_float(operator, a, b),
+        },
+        // Equality between an integer and a float is left to the VM, which
+        // compares values by variant.
+        (Integer(a), Float(b)) => fold_float(operator, a as f64, b),
+        (Float(a), Integer(b)) => fold_float(operator, a, b as f64),
+        (Boolean(a), Boolean(b)) => match operator {
+            BinaryOp::And => Some(Boolean(a && b)),
+            BinaryOp::Or => Some(Boolean(a || b)),
+            BinaryOp::Equal => Some(Boolean(a == b)),
+            BinaryOp::NotEqual => Some(Boolean(a != b)),
+            _ => None,
+        },
+        (Literal::String(a), Literal::String(b)) => match operator {
+            BinaryOp::Add => Some(Literal::String(intern_symbol(&format!(
+                "{}{}",
+                a.as_str(),
+                b.as_str()
+            )))),
+            BinaryOp::Equal => Some(Boolean(a == b)),
+            BinaryOp::NotEqual => Some(Boolean(a != b)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn fold_float(operator: &BinaryOp, a: f64, b: f64) -> Option<Literal> {
+    match operator {
+        BinaryOp::Add => Some(Literal::Float(a + b)),
+        BinaryOp::Subtract => Some(Literal::Float(a - b)),
+        BinaryOp::Multiply => Some(Literal::Float(a * b)),
+        BinaryOp::Divide if b != 0.0 => Some(Literal::Float(a / b)),
+        BinaryOp::Modulo if b != 0.0 => Some(Literal::Float(a % b)),
+        _ => fold_ordering(operator, a, b),
+    }
+}
+
+/// Ordering comparisons, evaluated on `f64` exactly as the VM does.
+fn fold_ordering(operator: &BinaryOp, a: f64, b: f64) -> Option<Literal> {
+    let result = match operator {
+        BinaryOp::Less => a < b,
+        BinaryOp::Greater => a > b,
+        BinaryOp::LessEqual => a <= b,
+        BinaryOp::GreaterEqual => a >= b,
+        _ => return None,
+    };
+    Some(Literal::Boolean(result))
+}
+
+//=====================================================
+// End of file
+//=====================================================
@@ -0,0 +1,64 @@
+//=====================================================
+// File: vm/debug_info.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Source line and local name tables for debug builds of VM bytecode
+// Objective: Map (function, instruction) pairs back to source lines so runtime
+//            stack traces can report where each frame was executing, and
+//            local names back to their slots for debugger watches
+//=====================================================
+
+use serde::{Deserialize, Serialize};
+
+/// One row of a line table: instructions from `ip` onward belong to `line`
+/// until the next entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineEntry {
+    pub ip: usize,
+    pub line: usize,
+}
+
+/// A named local: `name` refers to `slot` for instructions in
+/// `start..end`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocalEntry {
+    pub name: String,
+    pub slot: u32,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Per-function line and local tables, indexed like `VmBytecode::functions`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DebugInfo {
+    pub functions: Vec<Vec<LineEntry>>,
+    pub locals: Vec<Vec<LocalEntry>>,
+}
+
+impl DebugInfo {
+    /// Source line for the instruction at `ip` in `function_index`.
+    pub fn line_for(&self, function_index: usize, ip: usize) -> Option<usize> {
+        let table = self.functions.get(function_index)?;
+        let position = table.partition_point(|entry| entry.ip <= ip);
+        position
+            .checked_sub(1)
+            .and_then(|index| table.get(index))
+            .map(|entry| entry.line)
+    }
+
+    /// Slot that `name` refers to at `ip` in `function_index`. When scopes
+    /// nest, the innermost declaration wins.
+    pub fn local_slot(&self, function_index: usize, ip: usize, name: &str) -> Option<u32> {
+        self.locals
+            .get(function_index)?
+            .iter()
+            .filter(|entry| entry.name == name && (entry.start..entry.end).contains(&ip))
+            .max_by_key(|entry| entry.start)
+            .map(|entry| entry.slot)
+    }
+}
+
+//=====================================================
+// End of file
+//=====================================================
@@ -0,0 +1,100 @@
+//=====================================================
+// File: vm/disasm.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Human-readable listings of VM bytecode
+// Objective: Print the constant pool and every function's instructions with
+//            their operands so compiler output can be inspected
+//=====================================================
+
+use std::fmt::Write;
+
+use solvra_core::vm::bytecode::{VmBytecode, VmConstant};
+use solvra_core::vm::instruction::{Instruction, Opcode};
+
+use super::runtime::{DYNAMIC_CALL_TARGET, opcode_name};
+
+/// Render `program` as a text listing: the constant pool followed by each
+/// function, its arity and local count, and one line per instruction.
+/// Operands that refer to constants or functions are annotated with what
+/// they point at.
+pub fn disassemble(program: &VmBytecode) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "constants ({}):", program.constants.len());
+    for (index, constant) in program.constants.iter().enumerate() {
+        let _ = writeln!(out, "  #{index:<4} {}", format_constant(constant));
+    }
+    for (index, function) in program.functions.iter().enumerate() {
+        let entry = if index == program.entry {
+            " [entry]"
+        } else {
+            ""
+        };
+        let _ = writeln!(
+            out,
+            "\nfn {} (#{index}, arity {}, locals {}){entry}:",
+            function.name, function.arity, function.locals
+        );
+        for (ip, instruction) in function.instructions.iter().enumerate() {
+            let operands = format_operands(program, instruction);
+            let line = format!(
+                "  {ip:04}  {:<14} {operands}",
+                opcode_name(instruction.opcode)
+            );
+            let _ = writeln!(out, "{}", line.trim_end());
+        }
+    }
+    out
+}
+
+fn format_constant(constant: &VmConstant) -> String {
+    match constant {
+        VmConstant::Null => "null".to_string(),
+        VmConstant::Bool(flag) => flag.to_string(),
+        VmConstant::Int(value) => value.to_string(),
+        VmConstant::Float(value) => format!("{value:?}"),
+        VmConstant::String(value) => format!("{value:?}"),
+    }
+}
+
+fn format_operands(program: &VmBytecode, instruction: &Instruction) -> String {
+    let constant = |index: u32| {
+        program
+            .constants
+            .get(index as usize)
+            .map(format_constant)
+            .unwrap_or_else(|| "<invalid>".to_string())
+    };
+    let function = |index: u32| {
+        program
+            .functions
+            .get(index as usize)
+            .map(|function| function.name.clone())
+            .unwrap_or_else(|| "<dynamic>".to_string())
+    };
+    let (a, b, c) = (
+        instruction.operand_a,
+        instruction.operand_b,
+        instruction.operand_c,
+    );
+    match instruction.opcode {
+        Opcode::LoadConst => format!("#{a} ; {}", constant(a)),
+        Opcode::LoadVar | Opcode::StoreVar => format!("slot {a}"),
+        Opcode::Jump | Opcode::JumpIfFalse => format!("-> {a:04}"),
+        Opcode::MakeList | Opcode::MakeArray | Opcode::MakeObject => a.to_string(),
+        Opcode::LoadMember => format!("#{a} ; {}", constant(a)),
+        Opcode::LoadLambda => format!("#{a} ; {}", function(a)),
+        Opcode::Call if a == DYNAMIC_CALL_TARGET => format!("<dynamic> ({b} args)"),
+        Opcode::Call | Opcode::CallAsync => format!("#{a} ; {} ({b} args)", function(a)),
+        Opcode::CallBuiltin | Opcode::CoreCall => {
+            format!("#{a} ; {} ({b} args)", constant(a))
+        }
+        _ if a != 0 || b != 0 || c != 0 => format!("{a} {b} {c}"),
+        _ => String::new(),
+    }
+}
+
+//=====================================================
+// End of file
+//=====================================================
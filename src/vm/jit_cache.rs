@@ -0,0 +1,203 @@
+//=====================================================
+// File: vm/jit_cache.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Bound the memory held by JIT-compiled functions
+// Objective: Keep Tier-0 artifacts under a size budget, evicting the least
+//            recently used function back to the interpreter when it is full
+//=====================================================
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use solvra_core::jit::tier0_codegen::Tier0Artifact;
+
+/// Evicted functions run interpreted for this many calls before they may
+/// be compiled again.
+pub const DEFAULT_READMIT_AFTER_CALLS: u32 = 1000;
+
+/// Counters describing how the code budget has been spent. Sizes are in
+/// bytes of Tier-0 listing, the only size solvra_core reports per artifact.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JitCacheStats {
+    /// Functions admitted to the cache, counting readmissions.
+    pub compiled: usize,
+    /// Functions pushed out to make room for newer ones.
+    pub evictions: usize,
+    /// Listing bytes released by those evictions.
+    pub evicted_listing_bytes: usize,
+    /// Evicted functions compiled again after their cooldown.
+    pub readmitted: usize,
+    /// Functions that alone exceed the budget and were never cached.
+    pub rejected: usize,
+    /// Listing bytes currently held.
+    pub resident_listing_bytes: usize,
+}
+
+struct Resident {
+    artifact: Tier0Artifact,
+    size: usize,
+    last_used: u64,
+}
+
+struct CacheState {
+    capacity: usize,
+    readmit_after: u32,
+    clock: u64,
+    resident: HashMap<String, Resident>,
+    /// Evicted functions and how many calls they have run interpreted since.
+    evicted: HashMap<String, u32>,
+    rejected: HashSet<String>,
+    stats: JitCacheStats,
+}
+
+/// Size-capped LRU of Tier-0 artifacts shared by every executor of a run.
+///
+/// A function's size is the length of its Tier-0 listing. An evicted
+/// function runs interpreted for a cooldown of calls before it is compiled
+/// again, so a full cache does not recompile on every call. Functions
+/// rejected for being larger than the whole budget stay interpreted.
+#[derive(Clone)]
+pub struct JitCodeCache {
+    inner: Arc<Mutex<CacheState>>,
+}
+
+impl JitCodeCache {
+    /// A cache holding at most `capacity` bytes of Tier-0 listing.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(CacheState {
+                capacity,
+                readmit_after: DEFAULT_READMIT_AFTER_CALLS,
+                clock: 0,
+                resident: HashMap::new(),
+                evicted: HashMap::new(),
+                rejected: HashSet::new(),
+                stats: JitCacheStats::default(),
+            })),
+        }
+    }
+
+    /// Let evicted functions be compiled again after `calls` interpreted calls.
+    pub fn with_readmit_after(self, calls: u32) -> Self {
+        if let Ok(mut state) = self.inner.lock() {
+            state.readmit_after = calls;
+        }
+        self
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.inner.lock().map(|state| state.capacity).unwrap_or(0)
+    }
+
+    pub fn stats(&self) -> JitCacheStats {
+        self.inner
+            .lock()
+            .map(|state| state.stats.clone())
+            .unwrap_or_default()
+    }
+
+    /// Cached functions, least recently used first.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn resident_functions(&self) -> Vec<String> {
+        let Ok(state) = self.inner.lock() else {
+            return Vec::new();
+        };
+        let mut resident: Vec<(&String, u64)> = state
+            .resident
+            .iter()
+            .map(|(name, entry)| (name, entry.last_used))
+            .collect();
+        resident.sort_by_key(|(_, last_used)| *last_used);
+        resident.into_iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    /// Whether `name` was evicted or rejected and now runs interpreted.
+    pub fn is_evicted(&self, name: &str) -> bool {
+        self.inner
+            .lock()
+            .map(|state| state.evicted.contains_key(name) || state.rejected.contains(name))
+            .unwrap_or(false)
+    }
+
+    /// Count a call to `name` and report whether it must stay interpreted.
+    /// An evicted function is released for recompilation once it has made
+    /// the cooldown's worth of interpreted calls.
+    pub(crate) fn should_interpret(&self, name: &str) -> bool {
+        let Ok(mut state) = self.inner.lock() else {
+            return false;
+        };
+        if state.rejected.contains(name) {
+            return true;
+        }
+        let readmit_after = state.readmit_after;
+        let Some(calls) = state.evicted.get_mut(name) else {
+            return false;
+        };
+        if *calls < readmit_after {
+            *calls += 1;
+            return true;
+        }
+        state.evicted.remove(name);
+        state.stats.readmitted += 1;
+        false
+    }
+
+    /// Fetch the artifact for `name`, marking it most recently used.
+    pub(crate) fn get(&self, name: &str) -> Option<Tier0Artifact> {
+        let mut state = self.inner.lock().ok()?;
+        state.clock += 1;
+        let now = state.clock;
+        let entry = state.resident.get_mut(name)?;
+        entry.last_used = now;
+        Some(entry.artifact.clone())
+    }
+
+    /// Admit a freshly compiled artifact, evicting least recently used
+    /// functions until it fits. Returns false when the artifact alone is
+    /// larger than the budget.
+    pub(crate) fn insert(&self, name: &str, artifact: Tier0Artifact) -> bool {
+        let Ok(mut state) = self.inner.lock() else {
+            return false;
+        };
+        let size = artifact.listing.len();
+        if size > state.capacity {
+            state.rejected.insert(name.to_string());
+            state.stats.rejected += 1;
+            return false;
+        }
+        while state.stats.resident_listing_bytes + size > state.capacity {
+            let Some(victim) = state
+                .resident
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(name, _)| name.clone())
+            else {
+                break;
+            };
+            let evicted = state.resident.remove(&victim).expect("victim is resident");
+            state.stats.resident_listing_bytes -= evicted.size;
+            state.stats.evictions += 1;
+            state.stats.evicted_listing_bytes += evicted.size;
+            state.evicted.insert(victim, 0);
+        }
+        state.clock += 1;
+        let last_used = state.clock;
+        state.resident.insert(
+            name.to_string(),
+            Resident {
+                artifact,
+                size,
+                last_used,
+            },
+        );
+        state.stats.resident_listing_bytes += size;
+        state.stats.compiled += 1;
+        true
+    }
+}
+
+//=====================================================
+// End of file
+//=====================================================
@@ -0,0 +1,248 @@
+//=====================================================
+// File: vm/licm.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Loop-invariant code motion for `while` loops
+// Objective: Find pure subexpressions whose operands never change inside a
+//            loop so the compiler can evaluate them once before entering it
+//=====================================================
+
+use std::collections::HashSet;
+
+use super::const_fold;
+use crate::ast::visit::{self, Visitor};
+use crate::ast::{AssignTarget, BinaryOp, Expr, Literal, Pattern, Stmt, UnaryOp, VariableDecl};
+
+/// Collect the maximal loop-invariant subexpressions of a `while` loop, in
+/// evaluation order.
+///
+/// An expression is hoisted only when it is provably pure: a unary or binary
+/// operator applied to scalar literals and locals that nothing inside the
+/// loop can write. Calls, member and index access, lambdas, and anything else
+/// that could observe or cause a side effect stay in the loop, as do
+/// division and modulo, which can fail for values the loop never reaches.
+/// Expressions that `fold_constants` would already collapse, and those in
+/// `skip` (hoisted by an enclosing loop), are left alone.
+///
+/// Only code that runs on every iteration is searched: the condition and the
+/// body up to the first statement that may leave the iteration early.
+/// Branches of `if`, the right of `&&` and `||`, and nested loop bodies are
+/// skipped, because a hoisted multiply can still overflow where the original
+/// was never evaluated.
+pub(crate) fn invariants<'e>(
+    condition: &'e Expr,
+    body: &'e Stmt,
+    fold_constants: bool,
+    skip: &HashSet<*const Expr>,
+) -> Vec<&'e Expr> {
+    let mut writes = Writes::default();
+    writes.visit_expr(condition);
+    writes.visit_stmt(body);
+    let mut finder = Finder {
+        written: writes.written,
+        fold_constants,
+        skip,
+        found: Vec::new(),
+    };
+    finder.visit_expr(condition);
+    finder.visit_stmt(body);
+    finder.found
+}
+
+struct Finder<'s, 'e> {
+    written: HashSet<String>,
+    fold_constants: bool,
+    skip: &'s HashSet<*const Expr>,
+    found: Vec<&'e Expr>,
+}
+
+impl<'e> Visitor<'e> for Finder<'_, 'e> {
+    fn visit_stmt(&mut self, stmt: &'e Stmt) {
+        match stmt {
+            Stmt::Expression { .. } | Stmt::VariableDecl { .. } | Stmt::Return { .. } => {
+                visit::walk_stmt(self, stmt)
+            }
+            Stmt::Block { statements, .. } => {
+                for stmt in statements {
+                    self.visit_stmt(stmt);
+                    if may_leave_iteration(stmt) {
+                        break;
+                    }
+                }
+            }
+            Stmt::If { condition, .. } | Stmt::While { condition, .. } => {
+                self.visit_expr(condition)
+            }
+            Stmt::For { iterable, .. } => self.visit_expr(iterable),
+            _ => {}
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'e Expr) {
+        if self.skip.contains(&(expr as *const Expr)) {
+            return;
+        }
+        if self.is_candidate(expr) {
+            self.found.push(expr);
+            return;
+        }
+        match expr {
+            Expr::Binary {
+                left,
+                operator: BinaryOp::And | BinaryOp::Or,
+                ..
+            } => self.visit_expr(left),
+            Expr::If { condition, .. } | Expr::Conditional { condition, .. } => {
+                self.visit_expr(condition)
+            }
+            Expr::Match { expr, .. } => self.visit_expr(expr),
+            Expr::Comprehension { iterable, .. } => self.visit_expr(iterable),
+            // Lambda and async bodies are compiled as separate functions.
+            Expr::Lambda { .. } | Expr::Async { .. } => {}
+            _ => visit::walk_expr(self, expr),
+        }
+    }
+}
+
+impl Finder<'_, '_> {
+    fn is_candidate(&self, expr: &Expr) -> bool {
+        if !matches!(expr, Expr::Binary { .. } | Expr::Unary { .. }) || !self.is_pure(expr) {
+            return false;
+        }
+        !(self.fold_constants && const_fold::fold(expr).is_some())
+    }
+
+    fn is_pure(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Literal { value, .. } => !matches!(value, Literal::Array(_) | Literal::Object(_)),
+            Expr::Identifier { name, .. } => !self.written.contains(name.as_str()),
+            Expr::Unary {
+                operator, operand, ..
+            } => {
+                matches!(operator, UnaryOp::Minus | UnaryOp::Plus | UnaryOp::Not)
+                    && self.is_pure(operand)
+            }
+            Expr::Binary {
+                left,
+                operator,
+                right,
+                ..
+            } => is_hoistable_operator(operator) && self.is_pure(left) && self.is_pure(right),
+            _ => false,
+        }
+    }
+}
+
+/// Whether `stmt` may end the current iteration before the statements after
+/// it run. Anything that can hold a `break`, `continue`, or `return` counts,
+/// along with statements the search does not look inside.
+fn may_leave_iteration(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Expression { .. } | Stmt::VariableDecl { .. } => false,
+        Stmt::Block { statements, .. } => statements.iter().any(may_leave_iteration),
+        Stmt::If {
+            then_branch,
+            else_branch,
+            ..
+        } => {
+            may_leave_iteration(then_branch)
+                || else_branch.as_deref().is_some_and(may_leave_iteration)
+        }
+        Stmt::While { body, .. } | Stmt::For { body, .. } => may_leave_iteration(body),
+        _ => true,
+    }
+}
+
+/// Operators the VM compiles to a single instruction that cannot fail on a
+/// zero operand. Division and modulo are excluded for that reason.
+fn is_hoistable_operator(operator: &BinaryOp) -> bool {
+    matches!(
+        operator,
+        BinaryOp::Add
+            | BinaryOp::Subtract
+            | BinaryOp::Multiply
+            | BinaryOp::Equal
+            | BinaryOp::NotEqual
+            | BinaryOp::Less
+            | BinaryOp::Greater
+            | BinaryOp::LessEqual
+            | BinaryOp::GreaterEqual
+            | BinaryOp::And
+            | BinaryOp::Or
+    )
+}
+
+/// Every local the loop may rebind or mutate. Besides plain assignments
+/// this covers names bound inside the loop, which take a new value on each
+/// iteration, and locals handed to calls or used as method receivers, which
+/// may be containers changed in place.
+#[derive(Default)]
+struct Writes {
+    written: HashSet<String>,
+}
+
+impl<'e> Visitor<'e> for Writes {
+    fn visit_stmt(&mut self, stmt: &'e Stmt) {
+        if let Stmt::For { variable, .. }
+        | Stmt::ForIn { variable, .. }
+        | Stmt::ForOf { variable, .. } = stmt
+        {
+            self.written.insert(variable.to_string());
+        }
+        visit::walk_stmt(self, stmt);
+    }
+
+    fn visit_variable_decl(&mut self, decl: &'e VariableDecl) {
+        self.written.insert(decl.name.to_string());
+        visit::walk_variable_decl(self, decl);
+    }
+
+    fn visit_pattern(&mut self, pattern: &'e Pattern) {
+        if let Pattern::Identifier(name) = pattern {
+            self.written.insert(name.to_string());
+        }
+        visit::walk_pattern(self, pattern);
+    }
+
+    fn visit_expr(&mut self, expr: &'e Expr) {
+        match expr {
+            Expr::Assign { target, .. } => match target {
+                AssignTarget::Variable(name) => {
+                    self.written.insert(name.to_string());
+                }
+                AssignTarget::Index { array, .. } => mark_root(array, &mut self.written),
+                AssignTarget::Member { object, .. } => mark_root(object, &mut self.written),
+            },
+            Expr::Call { args, .. } => {
+                for arg in args {
+                    mark_root(arg, &mut self.written);
+                }
+            }
+            Expr::MethodCall { receiver, args, .. } => {
+                mark_root(receiver, &mut self.written);
+                for arg in args {
+                    mark_root(arg, &mut self.written);
+                }
+            }
+            _ => {}
+        }
+        visit::walk_expr(self, expr);
+    }
+}
+
+/// Mark the local at the root of an access chain such as `a`, `a.b`, or
+/// `a[i]` as possibly written.
+fn mark_root(expr: &Expr, written: &mut HashSet<String>) {
+    match expr {
+        Expr::Identifier { name, .. } => {
+            written.insert(name.to_string());
+        }
+        Expr::Member { object, .. } | Expr::Index { object, .. } => mark_root(object, written),
+        _ => {}
+    }
+}
+
+//=====================================================
+// End of file
+//=====================================================
@@ -2,6 +2,7 @@
 
 use chrono::{SecondsFormat, Utc};
 use serde::Serialize;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
@@ -29,6 +30,16 @@ pub enum TelemetryEventKind {
     TaskPanic,
     TaskCancel,
     RuntimeSummary,
+    FunctionEnter { name: String },
+    FunctionExit { name: String, elapsed_nanos: u64 },
+}
+
+/// Aggregated call statistics for one function.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FunctionStat {
+    pub name: String,
+    pub calls: u64,
+    pub total_nanos: u64,
 }
 
 /// JSON-serialisable view of telemetry emitted by the runtime.
@@ -85,4 +96,37 @@ impl TelemetryCollector {
             .map(|events| events.clone())
             .unwrap_or_default()
     }
+
+    /// Per-function call counts and total time, hottest first. Calls are
+    /// counted on entry; time is only known for calls that returned.
+    pub fn call_summary(&self) -> Vec<FunctionStat> {
+        let mut stats: HashMap<String, FunctionStat> = HashMap::new();
+        let Ok(events) = self.events.lock() else {
+            return Vec::new();
+        };
+        for record in events.iter() {
+            let (name, calls, nanos) = match &record.kind {
+                TelemetryEventKind::FunctionEnter { name } => (name, 1, 0),
+                TelemetryEventKind::FunctionExit {
+                    name,
+                    elapsed_nanos,
+                } => (name, 0, *elapsed_nanos),
+                _ => continue,
+            };
+            let stat = stats.entry(name.clone()).or_insert_with(|| FunctionStat {
+                name: name.clone(),
+                calls: 0,
+                total_nanos: 0,
+            });
+            stat.calls += calls;
+            stat.total_nanos += nanos;
+        }
+        let mut summary: Vec<FunctionStat> = stats.into_values().collect();
+        summary.sort_by(|a, b| {
+            b.total_nanos
+                .cmp(&a.total_nanos)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        summary
+    }
 }
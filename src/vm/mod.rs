@@ -1,18 +1,27 @@
 mod async_control;
 mod builtins;
+pub mod compile_cache;
 pub mod compiler;
+mod const_fold;
 mod core_builtins;
+pub mod debug_info;
+pub mod disasm;
+pub mod jit_cache;
 mod legacy_builtins;
+mod licm;
 mod metrics;
 pub mod profiling;
 pub mod runtime;
+pub mod session;
+pub mod svc;
 
 #[allow(unused_imports)]
 pub use solvra_core::vm::{bytecode, instruction, stack_vm};
 
 #[allow(unused_imports)]
 pub use metrics::{
-    TelemetryCollector, TelemetryEvent, TelemetryEventKind, TelemetryHook, TelemetryRecord,
+    FunctionStat, TelemetryCollector, TelemetryEvent, TelemetryEventKind, TelemetryHook,
+    TelemetryRecord,
 };
 
 #[cfg(test)]
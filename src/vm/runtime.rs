@@ -8,6 +8,7 @@ use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
+use regex::Regex;
 use tokio::task::{JoinHandle, LocalSet};
 use tokio::time::sleep;
 
@@ -16,30 +17,34 @@ use crate::ir::ir::SolvraIrModule;
 use solvra_core::concurrency::executor::{TaskExecutor, TaskHandle};
 use solvra_core::jit::dispatcher::{DeoptEvent, JitDispatcher};
 use solvra_core::jit::execute_tier0::execute_tier0;
-use solvra_core::jit::tier0_codegen::{Tier0Artifact, Tier0FunctionId};
+use solvra_core::jit::tier0_codegen::{Tier0Artifact, Tier0Compiler, Tier0FunctionId};
 use solvra_core::jit::tier1_mir::{MirFunctionId, MirModule};
 use solvra_core::jit::tier1_osr::Tier1OsrRegistry;
 use solvra_core::memory::deterministic::{ArenaAllocator, Handle, HeapObject};
 use solvra_core::vm::bytecode::{VmBytecode, VmConstant};
 use solvra_core::vm::instruction::{Instruction, Opcode};
-use solvra_core::{SolvraError, SolvraResult, StackFrame, Value};
+use solvra_core::{DebugSymbol, SolvraError, SolvraResult, StackFrame, Value};
 
 use super::async_control::AsyncControl;
 use super::builtins::{BuiltinContext, Builtins};
 use super::core_builtins::{core_stub_message, is_core_stub_call};
+use super::debug_info::DebugInfo;
+use super::jit_cache::JitCodeCache;
 use super::profiling::RuntimeProfile;
 use serde::Serialize;
 
 /// Shared bytecode handle passed into the runtime.
 pub type SolvraProgram = Arc<VmBytecode>;
 
-const DYNAMIC_CALL_TARGET: u32 = u32::MAX;
+pub(super) const DYNAMIC_CALL_TARGET: u32 = u32::MAX;
 type ObjectHandle = Handle<HeapObject>;
 
 /// Runtime flags controlling tracing and diagnostics.
 #[derive(Clone)]
 pub struct RuntimeOptions {
     pub trace: bool,
+    pub trace_filter: Option<TraceFilter>,
+    pub trace_hook: Option<TraceHook>,
     pub async_timeout_ms: Option<u64>,
     pub memory_tracker: Option<MemoryTracker>,
     pub telemetry_hook: Option<TelemetryHook>,
@@ -49,6 +54,7 @@ pub struct RuntimeOptions {
     pub jit_tier1: bool,
     pub jit_stats: bool,
     pub jit_ir_module: Option<Arc<SolvraIrModule>>,
+    pub jit_code_cache: Option<JitCodeCache>,
     pub tier1_mir_module: Option<Arc<MirModule>>,
     pub tier1_osr_registry: Option<Arc<Tier1OsrRegistry>>,
     pub jit_deopt_debug: bool,
@@ -58,12 +64,101 @@ pub struct RuntimeOptions {
     pub jit_osr_validate: bool,
     pub jit_tier2: bool,
     pub jit_osr_tier2_debug: bool,
+    pub debug_info: Option<Arc<DebugInfo>>,
+    /// File name reported in stack frame locations.
+    pub source_name: Option<String>,
+    pub max_call_depth: usize,
+    pub integer_overflow: OverflowMode,
+    pub gc_threshold: usize,
+    pub memory_pressure: Option<MemoryPressureWatch>,
+    pub native_functions: HashMap<String, NativeFunction>,
+    pub host_constructors: HashMap<String, HostConstructor>,
 }
 
+/// Receives each `[TRACE]` line in place of stdout.
+pub type TraceHook = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Limits opcode tracing to functions whose whole name matches a regex, so
+/// a plain name selects exactly that function.
+#[derive(Clone, Debug)]
+pub struct TraceFilter {
+    pattern: Regex,
+}
+
+impl TraceFilter {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Regex::new(&format!("^(?:{pattern})$")).map(|pattern| Self { pattern })
+    }
+
+    pub fn matches(&self, function: &str) -> bool {
+        self.pattern.is_match(function)
+    }
+}
+
+/// Host function callable from scripts by name, like a builtin.
+pub type NativeFunction = Arc<dyn Fn(&[Value]) -> Value + Send + Sync>;
+
+/// Cleanup run once when a host object is collected.
+pub type Finalizer = Box<dyn FnOnce() + Send>;
+
+/// Object a host constructor hands to the VM: its script-visible fields and
+/// an optional finalizer for resources it stands for.
+pub struct HostObject {
+    pub fields: HashMap<String, Value>,
+    pub finalizer: Option<Finalizer>,
+}
+
+/// Host function that creates a GC-managed object when called from scripts.
+pub type HostConstructor = Arc<dyn Fn(&[Value]) -> HostObject + Send + Sync>;
+
+/// How integer `+`, `-`, and `*` behave when the result does not fit in an `i64`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverflowMode {
+    /// Two's-complement wraparound.
+    #[default]
+    Wrap,
+    /// Clamp to `i64::MIN` / `i64::MAX`.
+    Saturate,
+    /// Raise a runtime exception.
+    Error,
+}
+
+/// Heap usage reported to a memory-pressure callback.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MemoryPressure {
+    /// Objects still live after the collection.
+    pub live_objects: usize,
+    /// The configured GC threshold the ratio is measured against.
+    pub budget: usize,
+    /// `live_objects / budget`.
+    pub ratio: f64,
+}
+
+/// Callback invoked when live heap usage stays high after a collection.
+pub type MemoryPressureHook = Arc<dyn Fn(&MemoryPressure) + Send + Sync>;
+
+/// A memory-pressure callback and the usage ratio that triggers it.
+#[derive(Clone)]
+pub struct MemoryPressureWatch {
+    pub threshold_ratio: f64,
+    pub callback: MemoryPressureHook,
+}
+
+/// Default call-frame limit; deep enough for ordinary recursion while still
+/// stopping runaway scripts before they exhaust memory.
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 4096;
+
+/// Live object count that triggers the first garbage collection. After each
+/// collection the threshold moves to twice the surviving count, but never
+/// below this value.
+pub const DEFAULT_GC_THRESHOLD: usize = 1024;
+
 impl Default for RuntimeOptions {
     fn default() -> Self {
         Self {
             trace: false,
+            trace_filter: None,
+            trace_hook: None,
             async_timeout_ms: None,
             memory_tracker: None,
             telemetry_hook: None,
@@ -73,6 +168,7 @@ impl Default for RuntimeOptions {
             jit_tier1: false,
             jit_stats: false,
             jit_ir_module: None,
+            jit_code_cache: None,
             tier1_mir_module: None,
             tier1_osr_registry: None,
             jit_deopt_debug: false,
@@ -82,6 +178,14 @@ impl Default for RuntimeOptions {
             jit_osr_validate: false,
             jit_tier2: false,
             jit_osr_tier2_debug: false,
+            debug_info: None,
+            source_name: None,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            integer_overflow: OverflowMode::default(),
+            gc_threshold: DEFAULT_GC_THRESHOLD,
+            memory_pressure: None,
+            native_functions: HashMap::new(),
+            host_constructors: HashMap::new(),
         }
     }
 }
@@ -94,6 +198,21 @@ impl RuntimeOptions {
         }
     }
 
+    /// Trace only opcodes executed inside functions matching `filter`.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn with_trace_filter(mut self, filter: TraceFilter) -> Self {
+        self.trace = true;
+        self.trace_filter = Some(filter);
+        self
+    }
+
+    /// Send trace lines to `hook` instead of printing them.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn with_trace_hook(mut self, hook: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.trace_hook = Some(Arc::new(hook));
+        self
+    }
+
     /// Configure an async/await timeout that aborts tasks exceeding `timeout`.
     #[cfg_attr(not(test), allow(dead_code))]
     pub fn with_async_timeout(mut self, timeout_ms: u64) -> Self {
@@ -128,6 +247,90 @@ impl RuntimeOptions {
         self.executor = executor;
         self
     }
+
+    /// Attach line tables from a debug build so stack traces report source lines.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn with_debug_info(mut self, debug_info: Arc<DebugInfo>) -> Self {
+        self.debug_info = Some(debug_info);
+        self
+    }
+
+    /// Name the source file that stack frame locations point into.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn with_source_name(mut self, name: impl Into<String>) -> Self {
+        self.source_name = Some(name.into());
+        self
+    }
+
+    /// Limit how many call frames may be live before a call is rejected.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn with_max_call_depth(mut self, depth: usize) -> Self {
+        self.max_call_depth = depth;
+        self
+    }
+
+    /// Select how integer arithmetic handles overflow.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn with_integer_overflow(mut self, mode: OverflowMode) -> Self {
+        self.integer_overflow = mode;
+        self
+    }
+
+    /// Hold Tier-0 code in `cache`, which caps its size and evicts the least
+    /// recently used functions back to the interpreter.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn with_jit_code_cache(mut self, cache: JitCodeCache) -> Self {
+        self.jit_code_cache = Some(cache);
+        self
+    }
+
+    /// Collect garbage once this many objects are live.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn with_gc_threshold(mut self, threshold: usize) -> Self {
+        self.gc_threshold = threshold;
+        self
+    }
+
+    /// Call `callback` after any collection that leaves more live objects
+    /// than `threshold_ratio` of the configured GC threshold, so embedders
+    /// can release caches before the heap keeps growing.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn on_memory_pressure(
+        mut self,
+        threshold_ratio: f64,
+        callback: impl Fn(&MemoryPressure) + Send + Sync + 'static,
+    ) -> Self {
+        self.memory_pressure = Some(MemoryPressureWatch {
+            threshold_ratio,
+            callback: Arc::new(callback),
+        });
+        self
+    }
+
+    /// Expose a host function to scripts under `name`. Host functions are
+    /// looked up before the builtin table.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn with_native_function(
+        mut self,
+        name: impl Into<String>,
+        function: NativeFunction,
+    ) -> Self {
+        self.native_functions.insert(name.into(), function);
+        self
+    }
+
+    /// Expose `constructor` to scripts under `name`; each call allocates the
+    /// returned object on the VM heap, finalizer included. See
+    /// `ObjectCollector` for when finalizers run.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn with_host_constructor(
+        mut self,
+        name: impl Into<String>,
+        constructor: HostConstructor,
+    ) -> Self {
+        self.host_constructors.insert(name.into(), constructor);
+        self
+    }
 }
 
 /// Telemetry callback signature for SolvraAI integration.
@@ -190,6 +393,26 @@ impl MemoryTracker {
         }
     }
 
+    fn record_integer_fast_path(&self) {
+        if let Ok(mut stats) = self.inner.lock() {
+            stats.integer_fast_path_ops += 1;
+        }
+    }
+
+    fn record_allocation(&self, live: usize) {
+        if let Ok(mut stats) = self.inner.lock() {
+            stats.objects_allocated += 1;
+            stats.peak_objects = stats.peak_objects.max(live);
+        }
+    }
+
+    fn record_collection(&self, freed: usize) {
+        if let Ok(mut stats) = self.inner.lock() {
+            stats.collections += 1;
+            stats.objects_freed += freed;
+        }
+    }
+
     /// Return a point-in-time view of collected statistics.
     #[cfg_attr(not(test), allow(dead_code))]
     pub fn snapshot(&self) -> MemoryStats {
@@ -223,32 +446,152 @@ pub struct MemoryStats {
     pub scheduler_ticks: usize,
     pub last_tick_tasks: Vec<TaskSnapshot>,
     pub peak_task_elapsed_ms: u64,
+    pub integer_fast_path_ops: usize,
+    pub collections: usize,
+    pub objects_allocated: usize,
+    pub objects_freed: usize,
+    pub peak_objects: usize,
+}
+
+/// Mark-sweep bookkeeping for objects allocated in the runtime arena.
+///
+/// Collection is deterministic: it runs only at instruction boundaries, once
+/// the number of tracked objects reaches `threshold`, and frees exactly the
+/// objects unreachable from the roots it is given.
+///
+/// Finalizers of freed objects are queued during `collect` and run by the
+/// caller once the arena and collector locks are released, oldest object
+/// first. A finalizer has no access to the VM, so it cannot resurrect its
+/// object or allocate; it runs exactly once, either when its object is
+/// collected or, for objects still live at the end, when the runtime is
+/// torn down.
+struct ObjectCollector {
+    live: Vec<ObjectHandle>,
+    threshold: usize,
+    min_threshold: usize,
+    finalizers: HashMap<ObjectHandle, Finalizer>,
+    pending_finalizers: Vec<Finalizer>,
+}
+
+impl ObjectCollector {
+    fn new(threshold: usize) -> Self {
+        let threshold = threshold.max(1);
+        Self {
+            live: Vec::new(),
+            threshold,
+            min_threshold: threshold,
+            finalizers: HashMap::new(),
+            pending_finalizers: Vec::new(),
+        }
+    }
+
+    fn track(&mut self, handle: ObjectHandle) -> usize {
+        self.live.push(handle);
+        self.live.len()
+    }
+
+    fn track_with_finalizer(&mut self, handle: ObjectHandle, finalizer: Finalizer) -> usize {
+        self.finalizers.insert(handle, finalizer);
+        self.track(handle)
+    }
+
+    /// Finalizers of objects freed by earlier collections, in the order the
+    /// objects were allocated.
+    fn take_finalizers(&mut self) -> Vec<Finalizer> {
+        std::mem::take(&mut self.pending_finalizers)
+    }
+
+    fn is_due(&self) -> bool {
+        self.live.len() >= self.threshold
+    }
+
+    fn live_count(&self) -> usize {
+        self.live.len()
+    }
+
+    /// Free every tracked object that cannot be reached from `roots`,
+    /// following object fields, list entries, and array elements. Returns
+    /// the number of objects freed.
+    fn collect<'a>(
+        &mut self,
+        arena: &mut ArenaAllocator,
+        roots: impl IntoIterator<Item = &'a Value>,
+    ) -> usize {
+        let mut pending = Vec::new();
+        for root in roots {
+            push_object_handles(root, &mut pending);
+        }
+        let mut marked = HashSet::new();
+        while let Some(handle) = pending.pop() {
+            if !marked.insert(handle) {
+                continue;
+            }
+            match arena.get(handle) {
+                Some(HeapObject::Map(map)) => {
+                    for value in map.values() {
+                        push_object_handles(value, &mut pending);
+                    }
+                }
+                Some(HeapObject::List(items)) => {
+                    for value in items {
+                        push_object_handles(value, &mut pending);
+                    }
+                }
+                Some(HeapObject::Native(_)) | None => {}
+            }
+        }
+
+        let before = self.live.len();
+        let finalizers = &mut self.finalizers;
+        let pending = &mut self.pending_finalizers;
+        self.live.retain(|handle| {
+            let reachable = marked.contains(handle);
+            if !reachable {
+                arena.free(*handle);
+                if let Some(finalizer) = finalizers.remove(handle) {
+                    pending.push(finalizer);
+                }
+            }
+            reachable
+        });
+        self.threshold = self.min_threshold.max(self.live.len() * 2);
+        before - self.live.len()
+    }
+}
+
+impl Drop for ObjectCollector {
+    fn drop(&mut self) {
+        for finalizer in self.take_finalizers() {
+            finalizer();
+        }
+        for handle in &self.live {
+            if let Some(finalizer) = self.finalizers.remove(handle) {
+                finalizer();
+            }
+        }
+    }
+}
+
+fn push_object_handles(value: &Value, pending: &mut Vec<ObjectHandle>) {
+    match value {
+        Value::Object(handle) => pending.push(*handle),
+        Value::Array(items) => {
+            for item in items {
+                push_object_handles(item, pending);
+            }
+        }
+        _ => {}
+    }
 }
 
 /// Execute a compiled SolvraScript program to completion.
 ///
 /// @ZNOTE[Phase6 Complete]: Runtime loop upgraded for bytecode + async execution.
 pub fn run_bytecode(program: SolvraProgram, options: RuntimeOptions) -> SolvraResult<Value> {
-    let context = Arc::new(RuntimeContext::new(program, options));
-    let runtime = tokio::runtime::Builder::new_current_thread()
-        .enable_all()
-        .build()
-        .map_err(|err| SolvraError::Internal(format!("tokio runtime init failed: {err}")))?;
+    let runtime = build_tokio_runtime()?;
     let local = LocalSet::new();
     local.block_on(&runtime, async move {
-        let entry_label = context
-            .program
-            .functions
-            .get(context.program.entry)
-            .map(|func| func.name.clone());
-        let mut executor = RuntimeExecutor::new(
-            Arc::clone(&context),
-            context.program.entry,
-            Vec::new(),
-            entry_label,
-            None,
-            Vec::new(),
-        )?;
+        let mut executor = entry_executor(program, options)?;
         let result = executor.run().await;
         if executor.ctx.options.jit_deopt_debug {
             if let Some(events) = executor.drain_deopt_events() {
@@ -264,12 +607,42 @@ pub fn run_bytecode(program: SolvraProgram, options: RuntimeOptions) -> SolvraRe
     })
 }
 
+pub(super) fn build_tokio_runtime() -> SolvraResult<tokio::runtime::Runtime> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|err| SolvraError::Internal(format!("tokio runtime init failed: {err}")))
+}
+
+/// Executor for the entry function of `program`, positioned at its first
+/// instruction. Must be created inside the `LocalSet` that will drive it.
+pub(super) fn entry_executor(
+    program: SolvraProgram,
+    options: RuntimeOptions,
+) -> SolvraResult<RuntimeExecutor> {
+    let context = Arc::new(RuntimeContext::new(program, options));
+    let entry_label = context
+        .program
+        .functions
+        .get(context.program.entry)
+        .map(|func| func.name.clone());
+    RuntimeExecutor::new(
+        Arc::clone(&context),
+        context.program.entry,
+        Vec::new(),
+        entry_label,
+        None,
+        Vec::new(),
+    )
+}
+
 struct RuntimeContext {
     program: SolvraProgram,
     builtins: Arc<Builtins>,
     options: RuntimeOptions,
     async_control: AsyncControl,
     arena: Arc<Mutex<ArenaAllocator>>,
+    collector: Mutex<ObjectCollector>,
     jit_dispatcher: Option<Mutex<JitDispatcher>>,
 }
 
@@ -286,12 +659,14 @@ impl RuntimeContext {
         } else {
             None
         };
+        let collector = Mutex::new(ObjectCollector::new(options.gc_threshold));
         Self {
             program,
             builtins: Arc::new(Builtins::with_context(builtin_context)),
             options,
             async_control,
             arena: Arc::new(Mutex::new(ArenaAllocator::new())),
+            collector,
             jit_dispatcher,
         }
     }
@@ -303,9 +678,11 @@ struct AsyncTask {
     started_at: Instant,
     core_completion: Arc<AtomicBool>,
     core_task: TaskHandle,
+    /// Set when the task stopped on a deadline rather than failing.
+    timed_out: Arc<AtomicBool>,
 }
 
-struct RuntimeExecutor {
+pub(super) struct RuntimeExecutor {
     ctx: Arc<RuntimeContext>,
     frames: Vec<CallFrame>,
     stack: Vec<Value>,
@@ -373,6 +750,45 @@ impl RuntimeExecutor {
         result
     }
 
+    /// Start profiling for execution driven through `step_instruction`
+    /// instead of `run`.
+    pub(super) fn begin_stepping(&mut self) {
+        self.profile.begin();
+        self.record_stack_depth();
+        self.record_scheduler_snapshot();
+    }
+
+    pub(super) fn finish_stepping(&mut self) {
+        self.finish_profile();
+    }
+
+    /// `(function_index, ip)` of every active frame, outermost first.
+    pub(super) fn frame_positions(&self) -> Vec<(usize, usize)> {
+        self.frames
+            .iter()
+            .map(|frame| (frame.function_index, frame.ip))
+            .collect()
+    }
+
+    pub(super) fn top_frame_locals(&self) -> &[Value] {
+        self.frames
+            .last()
+            .map(|frame| frame.locals.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub(super) fn operand_stack(&self) -> &[Value] {
+        &self.stack
+    }
+
+    pub(super) fn program(&self) -> &VmBytecode {
+        &self.ctx.program
+    }
+
+    pub(super) fn debug_info(&self) -> Option<&DebugInfo> {
+        self.ctx.options.debug_info.as_deref()
+    }
+
     fn initialize_mir_function_map(&mut self) {
         self.mir_function_map.clear();
         let Some(module_arc) = self.ctx.options.tier1_mir_module.as_ref() else {
@@ -394,405 +810,430 @@ impl RuntimeExecutor {
         self.record_stack_depth();
         self.record_scheduler_snapshot();
         loop {
-            if let Some(error) = self.enforce_timeouts() {
-                return Err(error);
+            if let Some(value) = self.step_instruction().await? {
+                return Ok(value);
             }
+        }
+    }
 
-            let frame_index = match self.frames.len().checked_sub(1) {
-                Some(index) => index,
-                None => return Ok(Value::Null),
-            };
+    /// Execute the instruction at the top frame's `ip`. Yields the program
+    /// result once the entry frame returns or the program halts.
+    pub(super) async fn step_instruction(&mut self) -> SolvraResult<Option<Value>> {
+        if let Some(error) = self.enforce_timeouts() {
+            return Err(error);
+        }
 
-            let instruction = self
-                .current_instruction(frame_index)
-                .map_err(|err| self.enrich_error(err))?
-                .clone();
-            if self.ctx.options.trace {
-                self.emit_trace(frame_index, &instruction);
-            }
+        let frame_index = match self.frames.len().checked_sub(1) {
+            Some(index) => index,
+            None => return Ok(Some(Value::Null)),
+        };
 
-            let mut advance_ip = true;
-            match instruction.opcode {
-                Opcode::Halt => {
-                    return Ok(self.stack.pop().unwrap_or(Value::Null));
-                }
-                Opcode::LoadConst => {
-                    let value = self.load_constant(&instruction)?;
-                    self.stack.push(value);
-                }
-                Opcode::LoadVar => {
-                    let slot = instruction.operand_a as usize;
-                    let value = self.frames[frame_index]
-                        .locals
-                        .get(slot)
-                        .cloned()
-                        .unwrap_or(Value::Null);
-                    self.stack.push(value);
+        let instruction = self
+            .current_instruction(frame_index)
+            .map_err(|err| self.enrich_error(err))?
+            .clone();
+        if self.ctx.options.trace {
+            self.emit_trace(frame_index, &instruction);
+        }
+
+        let mut advance_ip = true;
+        match instruction.opcode {
+            Opcode::Halt => {
+                return Ok(Some(self.stack.pop().unwrap_or(Value::Null)));
+            }
+            Opcode::LoadConst => {
+                let value = self.load_constant(&instruction)?;
+                self.stack.push(value);
+            }
+            Opcode::LoadVar => {
+                let slot = instruction.operand_a as usize;
+                let value = self.frames[frame_index]
+                    .locals
+                    .get(slot)
+                    .cloned()
+                    .unwrap_or(Value::Null);
+                self.stack.push(value);
+            }
+            Opcode::StoreVar => {
+                let slot = instruction.operand_a as usize;
+                let value = self.stack.pop().unwrap_or(Value::Null);
+                if let Some(local) = self.frames[frame_index].locals.get_mut(slot) {
+                    *local = value;
                 }
-                Opcode::StoreVar => {
-                    let slot = instruction.operand_a as usize;
-                    let value = self.stack.pop().unwrap_or(Value::Null);
-                    if let Some(local) = self.frames[frame_index].locals.get_mut(slot) {
-                        *local = value;
+            }
+            Opcode::Add | Opcode::Sub | Opcode::Mul | Opcode::Div | Opcode::Mod => {
+                // Integer operands are combined in place on the stack,
+                // skipping the string and mixed-type checks below.
+                if let [.., Value::Integer(lhs), Value::Integer(rhs)] = self.stack.as_slice() {
+                    let value = execute_integer_arithmetic(
+                        instruction.opcode,
+                        *lhs,
+                        *rhs,
+                        self.ctx.options.integer_overflow,
+                    )
+                    .map_err(|err| self.enrich_error(err))?;
+                    self.stack.pop();
+                    if let Some(top) = self.stack.last_mut() {
+                        *top = value;
                     }
-                }
-                Opcode::Add | Opcode::Sub | Opcode::Mul | Opcode::Div | Opcode::Mod => {
+                    self.record_integer_fast_path();
+                } else {
                     let rhs = self.stack.pop().unwrap_or(Value::Null);
                     let lhs = self.stack.pop().unwrap_or(Value::Null);
-                    let value = execute_arithmetic(instruction.opcode, lhs, rhs)
-                        .map_err(|err| self.enrich_error(err))?;
+                    let value = execute_arithmetic(
+                        instruction.opcode,
+                        lhs,
+                        rhs,
+                        self.ctx.options.integer_overflow,
+                    )
+                    .map_err(|err| self.enrich_error(err))?;
                     self.stack.push(value);
                 }
-                Opcode::Neg => {
-                    let value = self.stack.pop().unwrap_or(Value::Null);
-                    let negated = negate_value(value).map_err(|err| self.enrich_error(err))?;
-                    self.stack.push(negated);
-                }
-                Opcode::Not => {
-                    let value = self.stack.pop().unwrap_or(Value::Null);
-                    self.stack.push(Value::Boolean(!value.is_truthy()));
-                }
-                Opcode::Pop => {
-                    self.stack.pop();
-                }
-                Opcode::Jump => {
+            }
+            Opcode::Neg => {
+                let value = self.stack.pop().unwrap_or(Value::Null);
+                let negated = negate_value(value).map_err(|err| self.enrich_error(err))?;
+                self.stack.push(negated);
+            }
+            Opcode::Not => {
+                let value = self.stack.pop().unwrap_or(Value::Null);
+                self.stack.push(Value::Boolean(!value.is_truthy()));
+            }
+            Opcode::Pop => {
+                self.stack.pop();
+            }
+            Opcode::Jump => {
+                self.frames[frame_index].ip = instruction.operand_a as usize;
+                advance_ip = false;
+            }
+            Opcode::JumpIfFalse => {
+                let condition = self.stack.pop().unwrap_or(Value::Null);
+                if !condition.is_truthy() {
                     self.frames[frame_index].ip = instruction.operand_a as usize;
                     advance_ip = false;
                 }
-                Opcode::JumpIfFalse => {
-                    let condition = self.stack.pop().unwrap_or(Value::Null);
-                    if !condition.is_truthy() {
-                        self.frames[frame_index].ip = instruction.operand_a as usize;
-                        advance_ip = false;
-                    }
-                }
-                Opcode::MakeList => {
-                    let count = instruction.operand_a as usize;
-                    if count > self.stack.len() {
-                        return Err(self.runtime_exception("list construction underflow"));
-                    }
-                    let start = self.stack.len() - count;
-                    let values = self.stack.drain(start..).collect::<Vec<_>>();
-                    self.stack.push(Value::Array(values));
-                }
-                Opcode::MakeArray => {
-                    let capacity = instruction.operand_a as usize;
-                    let array = if capacity == 0 {
-                        Vec::new()
-                    } else {
-                        Vec::with_capacity(capacity)
-                    };
-                    self.stack.push(Value::Array(array));
+            }
+            Opcode::MakeList => {
+                let count = instruction.operand_a as usize;
+                if count > self.stack.len() {
+                    return Err(self.runtime_exception("list construction underflow"));
                 }
-                Opcode::MakeObject => {
-                    let field_count = instruction.operand_a as usize;
-                    if self.stack.len() < field_count * 2 {
-                        return Err(self.runtime_exception("object construction underflow"));
-                    }
-                    let mut map = HashMap::with_capacity(field_count);
-                    for _ in 0..field_count {
-                        let value = self.stack.pop().unwrap_or(Value::Null);
-                        let key_value = self.stack.pop().unwrap_or(Value::Null);
-                        let key = self.expect_string_key(key_value, "MakeObject")?;
-                        map.insert(key, value);
-                    }
-                    let object = self.allocate_object(map)?;
-                    self.stack.push(object);
+                let start = self.stack.len() - count;
+                let values = self.stack.drain(start..).collect::<Vec<_>>();
+                self.stack.push(Value::Array(values));
+            }
+            Opcode::MakeArray => {
+                let capacity = instruction.operand_a as usize;
+                let array = if capacity == 0 {
+                    Vec::new()
+                } else {
+                    Vec::with_capacity(capacity)
+                };
+                self.stack.push(Value::Array(array));
+            }
+            Opcode::MakeObject => {
+                let field_count = instruction.operand_a as usize;
+                if self.stack.len() < field_count * 2 {
+                    return Err(self.runtime_exception("object construction underflow"));
                 }
-                Opcode::Push => {
+                let mut map = HashMap::with_capacity(field_count);
+                for _ in 0..field_count {
                     let value = self.stack.pop().unwrap_or(Value::Null);
-                    let mut target = self.stack.pop().unwrap_or(Value::Null);
-                    if let Value::Array(ref mut arr) = target {
-                        arr.push(value);
-                        self.stack.push(target);
-                    } else {
-                        return Err(self.runtime_exception("Push called without array context"));
-                    }
+                    let key_value = self.stack.pop().unwrap_or(Value::Null);
+                    let key = self.expect_string_key(key_value, "MakeObject")?;
+                    map.insert(key, value);
                 }
-                Opcode::Index => {
-                    let index_value = self.stack.pop().unwrap_or(Value::Null);
-                    let collection = self.stack.pop().unwrap_or(Value::Null);
-                    match collection {
-                        Value::Array(items) => {
-                            let idx = self.expect_index(index_value, "Index")?;
-                            let value = items.get(idx).cloned().unwrap_or(Value::Null);
-                            self.stack.push(value);
-                        }
-                        other => {
-                            // Fallback to the generic core_index builtin for strings/objects.
-                            let value = self
-                                .ctx
-                                .builtins
-                                .invoke_sync("core_index", &[other, index_value])?;
-                            self.stack.push(value);
-                        }
-                    }
+                let object = self.allocate_object(map)?;
+                self.stack.push(object);
+            }
+            Opcode::Push => {
+                let value = self.stack.pop().unwrap_or(Value::Null);
+                let mut target = self.stack.pop().unwrap_or(Value::Null);
+                if let Value::Array(ref mut arr) = target {
+                    arr.push(value);
+                    self.stack.push(target);
+                } else {
+                    return Err(self.runtime_exception("Push called without array context"));
                 }
-                Opcode::SetIndex => {
-                    let value = self.stack.pop().unwrap_or(Value::Null);
-                    let index_value = self.stack.pop().unwrap_or(Value::Null);
-                    let mut array = self.stack.pop().unwrap_or(Value::Null);
-                    let idx = self.expect_index(index_value, "SetIndex")?;
-                    if let Value::Array(ref mut items) = array {
-                        if let Some(slot) = items.get_mut(idx) {
-                            *slot = value;
-                            self.stack.push(array);
-                        } else {
-                            return Err(
-                                self.runtime_exception(format!("SetIndex out of bounds: {idx}"))
-                            );
-                        }
+            }
+            Opcode::Index => {
+                let index_value = self.stack.pop().unwrap_or(Value::Null);
+                let collection = self.stack.pop().unwrap_or(Value::Null);
+                let value = self.index_value(collection, index_value)?;
+                self.stack.push(value);
+            }
+            Opcode::SetIndex => {
+                let value = self.stack.pop().unwrap_or(Value::Null);
+                let index_value = self.stack.pop().unwrap_or(Value::Null);
+                let mut array = self.stack.pop().unwrap_or(Value::Null);
+                let idx = self.expect_index(index_value, "SetIndex")?;
+                if let Value::Array(ref mut items) = array {
+                    if let Some(slot) = items.get_mut(idx) {
+                        *slot = value;
+                        self.stack.push(array);
                     } else {
                         return Err(
-                            self.runtime_exception("SetIndex expects array source on stack")
+                            self.runtime_exception(format!("SetIndex out of bounds: {idx}"))
                         );
                     }
+                } else {
+                    return Err(self.runtime_exception("SetIndex expects array source on stack"));
                 }
-                Opcode::LoadMember => {
-                    let name = self
-                        .string_constant(instruction.operand_a as usize)
-                        .ok_or_else(|| {
-                            self.runtime_exception(format!(
-                                "invalid property name constant {}",
-                                instruction.operand_a
-                            ))
-                        })?;
-                    let target = self.stack.pop().unwrap_or(Value::Null);
-                    let value = self.load_member_value(target, &name)?;
-                    self.stack.push(value);
-                }
-                Opcode::SetMember => {
-                    let value = self.stack.pop().unwrap_or(Value::Null);
-                    let key_value = self.stack.pop().unwrap_or(Value::Null);
-                    let target = self.stack.pop().unwrap_or(Value::Null);
-                    let key = self.expect_string_key(key_value, "SetMember")?;
-                    let reference = self.expect_object_reference(target, "SetMember")?;
-                    self.set_object_field(reference, key, value.clone())?;
-                    self.stack.push(value);
-                }
-                Opcode::Print => {
-                    let value = self.stack.pop().unwrap_or(Value::Null);
-                    print!("{}", value.stringify());
-                }
-                Opcode::LoadLambda => {
-                    let id = instruction.operand_a as i64;
-                    self.stack.push(Value::Integer(id));
-                }
-                Opcode::Equal
-                | Opcode::NotEqual
-                | Opcode::Less
-                | Opcode::LessEqual
-                | Opcode::Greater
-                | Opcode::GreaterEqual => {
-                    let rhs = self.stack.pop().unwrap_or(Value::Null);
-                    let lhs = self.stack.pop().unwrap_or(Value::Null);
-                    let value = execute_comparison(instruction.opcode, lhs, rhs)
-                        .map_err(|err| self.enrich_error(err))?;
-                    self.stack.push(value);
-                }
-                Opcode::And | Opcode::Or => {
-                    let rhs = self.stack.pop().unwrap_or(Value::Null);
-                    let lhs = self.stack.pop().unwrap_or(Value::Null);
-                    self.stack
-                        .push(execute_logical(instruction.opcode, lhs, rhs));
-                }
-                Opcode::Call => {
-                    let arg_count = instruction.operand_b as usize;
-                    let args = self.collect_args(arg_count);
-                    if instruction.operand_a == DYNAMIC_CALL_TARGET {
-                        let callee_value = self.stack.pop().unwrap_or(Value::Null);
-                        let method_name = if instruction.operand_c != 0 {
-                            self.string_constant(instruction.operand_c as usize)
-                        } else {
-                            None
-                        };
-                        self.call_dynamic(callee_value, args, method_name)
-                            .map_err(|err| self.enrich_error(err))?;
-                        advance_ip = false;
+            }
+            Opcode::LoadMember => {
+                let name = self
+                    .string_constant(instruction.operand_a as usize)
+                    .ok_or_else(|| {
+                        self.runtime_exception(format!(
+                            "invalid property name constant {}",
+                            instruction.operand_a
+                        ))
+                    })?;
+                let target = self.stack.pop().unwrap_or(Value::Null);
+                let value = self.load_member_value(target, &name)?;
+                self.stack.push(value);
+            }
+            Opcode::SetMember => {
+                let value = self.stack.pop().unwrap_or(Value::Null);
+                let key_value = self.stack.pop().unwrap_or(Value::Null);
+                let target = self.stack.pop().unwrap_or(Value::Null);
+                let key = self.expect_string_key(key_value, "SetMember")?;
+                let reference = self.expect_object_reference(target, "SetMember")?;
+                self.set_object_field(reference, key, value.clone())?;
+                self.stack.push(value);
+            }
+            Opcode::Print => {
+                let value = self.stack.pop().unwrap_or(Value::Null);
+                print!("{}", value.stringify());
+            }
+            Opcode::LoadLambda => {
+                let id = instruction.operand_a as i64;
+                self.stack.push(Value::Integer(id));
+            }
+            Opcode::Equal
+            | Opcode::NotEqual
+            | Opcode::Less
+            | Opcode::LessEqual
+            | Opcode::Greater
+            | Opcode::GreaterEqual => {
+                let rhs = self.stack.pop().unwrap_or(Value::Null);
+                let lhs = self.stack.pop().unwrap_or(Value::Null);
+                let value = execute_comparison(instruction.opcode, lhs, rhs)
+                    .map_err(|err| self.enrich_error(err))?;
+                self.stack.push(value);
+            }
+            Opcode::And | Opcode::Or => {
+                let rhs = self.stack.pop().unwrap_or(Value::Null);
+                let lhs = self.stack.pop().unwrap_or(Value::Null);
+                self.stack
+                    .push(execute_logical(instruction.opcode, lhs, rhs));
+            }
+            Opcode::Call => {
+                let arg_count = instruction.operand_b as usize;
+                let args = self.collect_args(arg_count);
+                if instruction.operand_a == DYNAMIC_CALL_TARGET {
+                    let callee_value = self.stack.pop().unwrap_or(Value::Null);
+                    let method_name = if instruction.operand_c != 0 {
+                        self.string_constant(instruction.operand_c as usize)
                     } else {
-                        let function_index = instruction.operand_a as usize;
-                        if let Some(value) =
-                            self.execute_tier1_if_available(function_index, &args)?
-                        {
+                        None
+                    };
+                    self.call_dynamic(callee_value, args, method_name)
+                        .map_err(|err| self.enrich_error(err))?;
+                    advance_ip = false;
+                } else {
+                    let function_index = instruction.operand_a as usize;
+                    if let Some(value) = self.execute_tier1_if_available(function_index, &args)? {
+                        self.stack.push(value);
+                        return Ok(None);
+                    }
+                    if self.consume_pending_deopt_frame() {
+                        return Ok(None);
+                    }
+                    match self
+                        .execute_tier0_if_available(function_index, &args)
+                        .map_err(|err| self.enrich_error(err))?
+                    {
+                        Some(value) => {
                             self.stack.push(value);
-                            continue;
                         }
-                        if self.consume_pending_deopt_frame() {
-                            continue;
-                        }
-                        match self
-                            .execute_tier0_if_available(function_index, &args)
-                            .map_err(|err| self.enrich_error(err))?
-                        {
-                            Some(value) => {
-                                self.stack.push(value);
-                            }
-                            None => {
-                                self.call_function(function_index, args)
-                                    .map_err(|err| self.enrich_error(err))?;
-                                advance_ip = false;
-                            }
+                        None => {
+                            self.call_function(function_index, args)
+                                .map_err(|err| self.enrich_error(err))?;
+                            advance_ip = false;
                         }
                     }
                 }
-                Opcode::CallBuiltin => {
-                    let name = self
-                        .string_constant(instruction.operand_a as usize)
-                        .ok_or_else(|| {
-                            self.runtime_exception(format!(
-                                "invalid builtin name constant {}",
-                                instruction.operand_a
-                            ))
-                        })?;
-                    let arg_count = instruction.operand_b as usize;
-                    let args = self.collect_args(arg_count);
-                    let result = match name.as_str() {
-                        "keys" | "object::keys" | "std::object::keys" => {
-                            self.builtin_object_keys(&args)
-                        }
-                        "values" | "object::values" | "std::object::values" => {
-                            self.builtin_object_values(&args)
-                        }
-                        "has_key" | "object::has_key" | "std::object::has_key" => {
-                            self.builtin_object_has_key(&args)
-                        }
-                        "len" | "std::string::len" | "string::len" => {
-                            self.builtin_len_extended(&args)
+            }
+            Opcode::CallBuiltin => {
+                let name = self
+                    .string_constant(instruction.operand_a as usize)
+                    .ok_or_else(|| {
+                        self.runtime_exception(format!(
+                            "invalid builtin name constant {}",
+                            instruction.operand_a
+                        ))
+                    })?;
+                let arg_count = instruction.operand_b as usize;
+                let args = self.collect_args(arg_count);
+                let result = match name.as_str() {
+                    "keys" | "object::keys" | "std::object::keys" => {
+                        self.builtin_object_keys(&args)
+                    }
+                    "values" | "object::values" | "std::object::values" => {
+                        self.builtin_object_values(&args)
+                    }
+                    "has_key" | "object::has_key" | "std::object::has_key" => {
+                        self.builtin_object_has_key(&args)
+                    }
+                    "len" | "std::string::len" | "string::len" => self.builtin_len_extended(&args),
+                    _ => {
+                        if let Some(constructor) = self.ctx.options.host_constructors.get(&name) {
+                            self.allocate_host_object(constructor(&args))
+                        } else if let Some(native) = self.ctx.options.native_functions.get(&name) {
+                            Ok(native(&args))
+                        } else {
+                            self.ctx.builtins.invoke_sync(&name, &args)
                         }
-                        _ => self.ctx.builtins.invoke_sync(&name, &args),
                     }
-                    .map_err(|err| self.enrich_error(err))?;
-                    self.stack.push(result);
                 }
-                Opcode::CallAsync => {
-                    let function_index = instruction.operand_a as usize;
-                    let arg_count = instruction.operand_b as usize;
-                    let args = self.collect_args(arg_count);
-                    let task_id = self
-                        .spawn_async_function(function_index, args)
-                        .map_err(|err| self.enrich_error(err))?;
-                    self.stack.push(Value::Integer(task_id as i64));
-                }
-                Opcode::CoreCall => {
-                    let name = self
-                        .string_constant(instruction.operand_a as usize)
-                        .ok_or_else(|| {
-                            self.runtime_exception(format!(
-                                "invalid core builtin name constant {}",
-                                instruction.operand_a
-                            ))
-                        })?;
-                    let arg_count = instruction.operand_b as usize;
-                    let args = self.collect_args(arg_count);
-                    let result =
-                        invoke_core_builtin(&name, &args).map_err(|err| self.enrich_error(err))?;
-                    self.stack.push(result);
-                }
-                Opcode::Await => {
-                    let task_id_value = self.stack.pop().unwrap_or(Value::Null);
-                    let task_id =
-                        extract_task_id(task_id_value).map_err(|err| self.enrich_error(err))?;
-                    let AsyncTask {
-                        label,
-                        handle,
-                        started_at,
-                        core_completion,
-                        core_task,
-                    } = self.tasks.remove(&task_id).ok_or_else(|| {
-                        self.runtime_exception(format!("await on unknown task {task_id}"))
+                .map_err(|err| self.enrich_error(err))?;
+                self.stack.push(result);
+            }
+            Opcode::CallAsync => {
+                let function_index = instruction.operand_a as usize;
+                let arg_count = instruction.operand_b as usize;
+                let args = self.collect_args(arg_count);
+                let task_id = self
+                    .spawn_async_function(function_index, args)
+                    .map_err(|err| self.enrich_error(err))?;
+                self.stack.push(Value::Integer(task_id as i64));
+            }
+            Opcode::CoreCall => {
+                let name = self
+                    .string_constant(instruction.operand_a as usize)
+                    .ok_or_else(|| {
+                        self.runtime_exception(format!(
+                            "invalid core builtin name constant {}",
+                            instruction.operand_a
+                        ))
                     })?;
+                let arg_count = instruction.operand_b as usize;
+                let args = self.collect_args(arg_count);
+                let result =
+                    invoke_core_builtin(&name, &args).map_err(|err| self.enrich_error(err))?;
+                self.stack.push(result);
+            }
+            Opcode::Await => {
+                let task_id_value = self.stack.pop().unwrap_or(Value::Null);
+                let task_id =
+                    extract_task_id(task_id_value).map_err(|err| self.enrich_error(err))?;
+                let AsyncTask {
+                    label,
+                    handle,
+                    started_at,
+                    core_completion,
+                    core_task,
+                    timed_out,
+                } = self.tasks.remove(&task_id).ok_or_else(|| {
+                    self.runtime_exception(format!("await on unknown task {task_id}"))
+                })?;
+
+                if self.async_control.is_cancelled(task_id) {
+                    self.async_control.complete(task_id);
+                    handle.abort();
+                    self.abort_all_tasks();
+                    self.emit_telemetry_event(
+                        TelemetryEventKind::TaskCancel,
+                        Some(label.clone()),
+                        Some(started_at.elapsed().as_millis() as u64),
+                        self.ctx.options.async_timeout_ms,
+                    );
+                    core_task.cancel();
+                    core_completion.store(true, Ordering::SeqCst);
+                    core_task.wait();
+                    let error = self.cancellation_runtime_exception(&label);
+                    self.clear_state();
+                    return Err(error);
+                }
 
-                    if self.async_control.is_cancelled(task_id) {
+                let join_result = if let Some(deadline) = self.task_deadline(task_id, started_at) {
+                    let now = Instant::now();
+                    if now >= deadline {
                         self.async_control.complete(task_id);
-                        handle.abort();
                         self.abort_all_tasks();
                         self.emit_telemetry_event(
-                            TelemetryEventKind::TaskCancel,
+                            TelemetryEventKind::TaskTimeout,
                             Some(label.clone()),
-                            Some(started_at.elapsed().as_millis() as u64),
+                            Some(now.duration_since(started_at).as_millis() as u64),
                             self.ctx.options.async_timeout_ms,
                         );
+                        self.record_timeout_event(self.stack.len());
+                        let elapsed_ms = now.duration_since(started_at).as_millis() as u64;
                         core_task.cancel();
                         core_completion.store(true, Ordering::SeqCst);
                         core_task.wait();
-                        let error = self.cancellation_runtime_exception(&label);
+                        let error = self.timeout_runtime_exception(&label, elapsed_ms);
                         self.clear_state();
                         return Err(error);
                     }
-
-                    let join_result =
-                        if let Some(deadline) = self.task_deadline(task_id, started_at) {
-                            let now = Instant::now();
-                            if now >= deadline {
-                                self.async_control.complete(task_id);
-                                self.abort_all_tasks();
-                                self.emit_telemetry_event(
-                                    TelemetryEventKind::TaskTimeout,
-                                    Some(label.clone()),
-                                    Some(now.duration_since(started_at).as_millis() as u64),
-                                    self.ctx.options.async_timeout_ms,
-                                );
-                                self.record_timeout_event(self.stack.len());
-                                let elapsed_ms = now.duration_since(started_at).as_millis() as u64;
-                                core_task.cancel();
-                                core_completion.store(true, Ordering::SeqCst);
-                                core_task.wait();
-                                let error = self.timeout_runtime_exception(&label, elapsed_ms);
-                                self.clear_state();
-                                return Err(error);
-                            }
-                            let duration = deadline.saturating_duration_since(now);
-                            let handle_fut = handle;
-                            tokio::pin!(handle_fut);
-                            tokio::select! {
-                                res = &mut handle_fut => res,
-                                _ = sleep(duration) => {
-                                    handle_fut.as_ref().get_ref().abort();
-                                    self.async_control.complete(task_id);
-                                    self.abort_all_tasks();
-                                    self.emit_telemetry_event(
-                                        TelemetryEventKind::TaskTimeout,
-                                        Some(label.clone()),
-                                        Some(started_at.elapsed().as_millis() as u64),
-                                        self.ctx.options.async_timeout_ms,
-                                    );
-                                    self.record_timeout_event(self.stack.len());
-                                    let elapsed_ms = started_at.elapsed().as_millis() as u64;
-                                    core_task.cancel();
-                                    core_completion.store(true, Ordering::SeqCst);
-                                    core_task.wait();
-                                    let error = self.timeout_runtime_exception(&label, elapsed_ms);
-                                    self.clear_state();
-                                    return Err(error);
-                                }
-                            }
-                        } else {
-                            handle.await
-                        };
-                    self.async_control.complete(task_id);
-                    core_completion.store(true, Ordering::SeqCst);
-                    core_task.wait();
-                    match join_result {
-                        Ok(inner) => match inner {
-                            Ok(value) => {
-                                self.emit_telemetry_event(
-                                    TelemetryEventKind::TaskJoin,
-                                    Some(label.clone()),
-                                    Some(started_at.elapsed().as_millis() as u64),
-                                    self.ctx.options.async_timeout_ms,
-                                );
-                                self.stack.push(value);
-                            }
-                            Err(err) => {
-                                self.emit_telemetry_event(
-                                    TelemetryEventKind::TaskPanic,
-                                    Some(label.clone()),
-                                    Some(started_at.elapsed().as_millis() as u64),
-                                    self.ctx.options.async_timeout_ms,
-                                );
-                                return Err(self.attach_stack(err));
-                            }
-                        },
+                    let duration = deadline.saturating_duration_since(now);
+                    let handle_fut = handle;
+                    tokio::pin!(handle_fut);
+                    tokio::select! {
+                        res = &mut handle_fut => res,
+                        _ = sleep(duration) => {
+                            handle_fut.as_ref().get_ref().abort();
+                            self.async_control.complete(task_id);
+                            self.abort_all_tasks();
+                            self.emit_telemetry_event(
+                                TelemetryEventKind::TaskTimeout,
+                                Some(label.clone()),
+                                Some(started_at.elapsed().as_millis() as u64),
+                                self.ctx.options.async_timeout_ms,
+                            );
+                            self.record_timeout_event(self.stack.len());
+                            let elapsed_ms = started_at.elapsed().as_millis() as u64;
+                            core_task.cancel();
+                            core_completion.store(true, Ordering::SeqCst);
+                            core_task.wait();
+                            let error = self.timeout_runtime_exception(&label, elapsed_ms);
+                            self.clear_state();
+                            return Err(error);
+                        }
+                    }
+                } else {
+                    handle.await
+                };
+                self.async_control.complete(task_id);
+                core_completion.store(true, Ordering::SeqCst);
+                core_task.wait();
+                match join_result {
+                    Ok(inner) => match inner {
+                        Ok(value) => {
+                            self.emit_telemetry_event(
+                                TelemetryEventKind::TaskJoin,
+                                Some(label.clone()),
+                                Some(started_at.elapsed().as_millis() as u64),
+                                self.ctx.options.async_timeout_ms,
+                            );
+                            self.stack.push(value);
+                        }
+                        Err(err) if timed_out.load(Ordering::SeqCst) => {
+                            // The task hit its own deadline; report it as a
+                            // timeout of this await, not a task failure.
+                            self.mark_timed_out();
+                            self.abort_all_tasks();
+                            self.emit_telemetry_event(
+                                TelemetryEventKind::TaskTimeout,
+                                Some(label.clone()),
+                                Some(started_at.elapsed().as_millis() as u64),
+                                self.ctx.options.async_timeout_ms,
+                            );
+                            let error = self.attach_stack(err);
+                            self.clear_state();
+                            return Err(error);
+                        }
                         Err(err) => {
                             self.emit_telemetry_event(
                                 TelemetryEventKind::TaskPanic,
@@ -800,42 +1241,56 @@ impl RuntimeExecutor {
                                 Some(started_at.elapsed().as_millis() as u64),
                                 self.ctx.options.async_timeout_ms,
                             );
-                            return Err(
-                                self.runtime_exception(format!("async task {label} panic: {err}"))
-                            );
+                            return Err(self.attach_stack(err));
                         }
+                    },
+                    Err(err) => {
+                        self.emit_telemetry_event(
+                            TelemetryEventKind::TaskPanic,
+                            Some(label.clone()),
+                            Some(started_at.elapsed().as_millis() as u64),
+                            self.ctx.options.async_timeout_ms,
+                        );
+                        return Err(
+                            self.runtime_exception(format!("async task {label} panic: {err}"))
+                        );
                     }
                 }
-                Opcode::Return | Opcode::CoreReturn => {
-                    let return_value = self.stack.pop().unwrap_or(Value::Null);
-                    let frame = self.frames.pop().expect("frame must exist");
-                    self.stack.truncate(frame.stack_base);
-                    if self.frames.is_empty() {
-                        self.record_stack_depth();
-                        return Ok(return_value);
-                    } else {
-                        if let Some(parent) = self.frames.last_mut() {
-                            parent.ip += 1;
-                        }
-                        self.stack.push(return_value);
-                        continue;
-                    }
+            }
+            Opcode::Return | Opcode::CoreReturn => {
+                let return_value = self.stack.pop().unwrap_or(Value::Null);
+                let frame = self.frames.pop().expect("frame must exist");
+                self.stack.truncate(frame.stack_base);
+                if let Some(entered_at) = frame.entered_at {
+                    self.emit_function_exit(frame.function_index, entered_at);
                 }
-                Opcode::CoreYield => {
-                    eprintln!(
-                        "[solvrascript] warning: CoreYield opcode is not implemented; returning null"
-                    );
-                    return Ok(Value::Null);
+                if self.frames.is_empty() {
+                    self.record_stack_depth();
+                    return Ok(Some(return_value));
+                } else {
+                    if let Some(parent) = self.frames.last_mut() {
+                        parent.ip += 1;
+                    }
+                    self.stack.push(return_value);
+                    return Ok(None);
                 }
-                Opcode::Nop => {}
             }
-
-            if advance_ip && let Some(frame) = self.frames.last_mut() {
-                frame.ip += 1;
+            Opcode::CoreYield => {
+                eprintln!(
+                    "[solvrascript] warning: CoreYield opcode is not implemented; returning null"
+                );
+                return Ok(Some(Value::Null));
             }
-            self.record_stack_depth();
-            self.record_scheduler_snapshot();
+            Opcode::Nop => {}
         }
+
+        if advance_ip && let Some(frame) = self.frames.last_mut() {
+            frame.ip += 1;
+        }
+        self.record_stack_depth();
+        self.record_scheduler_snapshot();
+        self.maybe_collect_garbage()?;
+        Ok(None)
     }
 
     fn current_instruction(&self, frame_index: usize) -> SolvraResult<&Instruction> {
@@ -882,6 +1337,13 @@ impl RuntimeExecutor {
             )));
         }
 
+        if self.frames.len() >= self.ctx.options.max_call_depth {
+            return Err(self.runtime_exception(format!(
+                "maximum recursion depth exceeded ({}) calling '{}'",
+                self.ctx.options.max_call_depth, function.name
+            )));
+        }
+
         let mut locals = vec![Value::Null; function.locals as usize];
         for (index, arg) in args.into_iter().enumerate() {
             if let Some(slot) = locals.get_mut(index) {
@@ -889,6 +1351,19 @@ impl RuntimeExecutor {
             }
         }
 
+        // Only pay for timestamps when someone is listening.
+        let entered_at = self.telemetry.is_some().then(|| {
+            self.emit_telemetry_event(
+                TelemetryEventKind::FunctionEnter {
+                    name: function.name.clone(),
+                },
+                self.task_label.clone(),
+                None,
+                None,
+            );
+            Instant::now()
+        });
+
         let frame = CallFrame {
             function_index,
             ip: 0,
@@ -896,6 +1371,7 @@ impl RuntimeExecutor {
             stack_base: self.stack.len(),
             transfer_locals: None,
             transfer_debug: None,
+            entered_at,
         };
         self.frames.push(frame);
         Ok(())
@@ -1045,6 +1521,7 @@ impl RuntimeExecutor {
             stack_base,
             transfer_locals,
             transfer_debug,
+            entered_at: None,
         };
         self.frames.push(frame);
         self.pending_deopt_frame = true;
@@ -1133,6 +1610,20 @@ impl RuntimeExecutor {
         let Some(function) = module.function_by_name(function_name) else {
             return Ok(None);
         };
+        if let Some(cache) = &self.ctx.options.jit_code_cache {
+            if cache.should_interpret(function_name) {
+                return Ok(None);
+            }
+            if let Some(artifact) = cache.get(function_name) {
+                return Ok(Some(artifact));
+            }
+            // Compiled outside the dispatcher so eviction releases the code.
+            let artifact = Tier0Compiler::new().compile(function);
+            if !cache.insert(function_name, artifact.clone()) {
+                return Ok(None);
+            }
+            return Ok(Some(artifact));
+        }
         let artifact = dispatcher_mutex
             .lock()
             .map_err(|_| self.runtime_exception("Tier-0 dispatcher lock poisoned"))?
@@ -1265,7 +1756,8 @@ impl RuntimeExecutor {
     }
 
     fn request_tier0(&self, function_name: &str) {
-        if !self.ctx.options.jit_tier0 {
+        // A code cache compiles on first execution instead.
+        if !self.ctx.options.jit_tier0 || self.ctx.options.jit_code_cache.is_some() {
             return;
         }
         let module = match &self.ctx.options.jit_ir_module {
@@ -1315,11 +1807,87 @@ impl RuntimeExecutor {
     }
 
     fn allocate_object(&self, fields: HashMap<String, Value>) -> SolvraResult<Value> {
-        let mut arena = self.arena_lock()?;
-        let reference = arena.allocate(HeapObject::Map(fields));
+        let reference = self.arena_lock()?.allocate(HeapObject::Map(fields));
+        let live = self
+            .ctx
+            .collector
+            .lock()
+            .map_err(|_| self.runtime_exception("collector lock poisoned"))?
+            .track(reference);
+        if let Some(tracker) = &self.ctx.options.memory_tracker {
+            tracker.record_allocation(live);
+        }
+        Ok(Value::Object(reference))
+    }
+
+    fn allocate_host_object(&self, object: HostObject) -> SolvraResult<Value> {
+        let Some(finalizer) = object.finalizer else {
+            return self.allocate_object(object.fields);
+        };
+        let reference = self.arena_lock()?.allocate(HeapObject::Map(object.fields));
+        let live = self
+            .ctx
+            .collector
+            .lock()
+            .map_err(|_| self.runtime_exception("collector lock poisoned"))?
+            .track_with_finalizer(reference, finalizer);
+        if let Some(tracker) = &self.ctx.options.memory_tracker {
+            tracker.record_allocation(live);
+        }
         Ok(Value::Object(reference))
     }
 
+    /// Collect unreachable objects once the live count crosses the threshold.
+    /// Roots are the value stack and every frame's locals. Only the root
+    /// executor collects, and only while it has no outstanding tasks, because
+    /// task stacks and unawaited results are not visible from here.
+    fn maybe_collect_garbage(&self) -> SolvraResult<()> {
+        if self.executor_id.is_some() || !self.tasks.is_empty() {
+            return Ok(());
+        }
+        let mut collector = self
+            .ctx
+            .collector
+            .lock()
+            .map_err(|_| self.runtime_exception("collector lock poisoned"))?;
+        if !collector.is_due() {
+            return Ok(());
+        }
+        let mut arena = self.arena_lock()?;
+        let roots = self
+            .stack
+            .iter()
+            .chain(self.frames.iter().flat_map(|frame| {
+                frame
+                    .locals
+                    .iter()
+                    .chain(frame.transfer_locals.iter().flatten())
+            }));
+        let freed = collector.collect(&mut arena, roots);
+        let live_objects = collector.live_count();
+        let finalizers = collector.take_finalizers();
+        drop(arena);
+        drop(collector);
+        for finalizer in finalizers {
+            finalizer();
+        }
+        if let Some(tracker) = &self.ctx.options.memory_tracker {
+            tracker.record_collection(freed);
+        }
+        if let Some(watch) = &self.ctx.options.memory_pressure {
+            let budget = self.ctx.options.gc_threshold.max(1);
+            let ratio = live_objects as f64 / budget as f64;
+            if ratio > watch.threshold_ratio {
+                (watch.callback)(&MemoryPressure {
+                    live_objects,
+                    budget,
+                    ratio,
+                });
+            }
+        }
+        Ok(())
+    }
+
     fn expect_string_key(&self, value: Value, context: &str) -> SolvraResult<String> {
         match value {
             Value::String(text) => Ok(text),
@@ -1341,7 +1909,36 @@ impl RuntimeExecutor {
         }
     }
 
-    fn load_member_value(&self, target: Value, property: &str) -> SolvraResult<Value> {
+    /// Read `collection[index]` as `Opcode::Index` does.
+    pub(super) fn index_value(&self, collection: Value, index: Value) -> SolvraResult<Value> {
+        match collection {
+            Value::Array(items) => {
+                let idx = self.expect_index(index, "Index")?;
+                Ok(items.get(idx).cloned().unwrap_or(Value::Null))
+            }
+            // Fallback to the generic core_index builtin for strings/objects.
+            other => self.ctx.builtins.invoke_sync("core_index", &[other, index]),
+        }
+    }
+
+    /// Apply an arithmetic, comparison, or logical opcode to two values as
+    /// the instruction would, honoring the configured overflow mode.
+    pub(super) fn apply_binary(
+        &self,
+        opcode: Opcode,
+        lhs: Value,
+        rhs: Value,
+    ) -> SolvraResult<Value> {
+        match opcode {
+            Opcode::Add | Opcode::Sub | Opcode::Mul | Opcode::Div | Opcode::Mod => {
+                execute_arithmetic(opcode, lhs, rhs, self.ctx.options.integer_overflow)
+            }
+            Opcode::And | Opcode::Or => Ok(execute_logical(opcode, lhs, rhs)),
+            _ => execute_comparison(opcode, lhs, rhs),
+        }
+    }
+
+    pub(super) fn load_member_value(&self, target: Value, property: &str) -> SolvraResult<Value> {
         let reference = self.expect_object_reference(target, "LoadMember")?;
         let arena = self.arena_lock()?;
         let value = match arena.get(reference) {
@@ -1479,6 +2076,11 @@ impl RuntimeExecutor {
     fn emit_trace(&self, frame_index: usize, instruction: &Instruction) {
         let frame = &self.frames[frame_index];
         let function = &self.ctx.program.functions[frame.function_index];
+        if let Some(filter) = &self.ctx.options.trace_filter
+            && !filter.matches(&function.name)
+        {
+            return;
+        }
         let opcode = instruction.opcode;
         let operands = match opcode {
             Opcode::Call | Opcode::CallAsync => {
@@ -1518,7 +2120,10 @@ impl RuntimeExecutor {
             opcode_name(opcode),
             operands
         );
-        println!("{trace}");
+        match &self.ctx.options.trace_hook {
+            Some(hook) => hook(&trace),
+            None => println!("{trace}"),
+        }
     }
 
     fn spawn_async_function(
@@ -1562,6 +2167,8 @@ impl RuntimeExecutor {
         let label = format!("{}#{}", function_label, task_id);
         let control_clone = async_control.clone();
         let completion_for_task = completion_flag.clone();
+        let timed_out = Arc::new(AtomicBool::new(false));
+        let timed_out_for_task = timed_out.clone();
         let async_label = function_label.clone();
         let handle = tokio::task::spawn_local(async move {
             let mut executor = RuntimeExecutor::new(
@@ -1576,6 +2183,7 @@ impl RuntimeExecutor {
                 .run()
                 .await
                 .map_err(|err| executor.enrich_error(err));
+            timed_out_for_task.store(control_clone.timed_out(task_id), Ordering::SeqCst);
             control_clone.complete(task_id);
             completion_for_task.store(true, Ordering::SeqCst);
             result
@@ -1588,6 +2196,7 @@ impl RuntimeExecutor {
                 started_at,
                 core_completion: completion_flag,
                 core_task,
+                timed_out,
             },
         );
         self.emit_telemetry_event(
@@ -1727,6 +2336,7 @@ impl RuntimeExecutor {
     }
 
     fn timeout_runtime_exception(&self, task_label: &str, elapsed_ms: u64) -> SolvraError {
+        self.mark_timed_out();
         let lineage = self.lineage_string(task_label);
         SolvraError::RuntimeException {
             message: format!(
@@ -1736,6 +2346,13 @@ impl RuntimeExecutor {
         }
     }
 
+    /// Flag this executor's task as timed out for the executor awaiting it.
+    fn mark_timed_out(&self) {
+        if let Some(task_id) = self.executor_id {
+            self.async_control.mark_timed_out(task_id);
+        }
+    }
+
     fn cancellation_runtime_exception(&self, task_label: &str) -> SolvraError {
         let lineage = self.lineage_string(task_label);
         SolvraError::RuntimeException {
@@ -1773,10 +2390,24 @@ impl RuntimeExecutor {
                 .get(frame.function_index)
                 .map(|func| func.name.clone())
                 .unwrap_or_else(|| format!("#{}", frame.function_index));
-            trace.push(StackFrame {
-                function,
-                location: None,
-            });
+            let location = self
+                .ctx
+                .options
+                .debug_info
+                .as_ref()
+                .and_then(|info| info.line_for(frame.function_index, frame.ip))
+                .map(|line| DebugSymbol {
+                    file: self
+                        .ctx
+                        .options
+                        .source_name
+                        .clone()
+                        .unwrap_or_else(|| "<script>".into())
+                        .into(),
+                    line,
+                    column: 0,
+                });
+            trace.push(StackFrame { function, location });
         }
         if trace.is_empty() {
             let entry = self
@@ -1839,6 +2470,12 @@ impl RuntimeExecutor {
         Some(events)
     }
 
+    fn record_integer_fast_path(&self) {
+        if let Some(tracker) = &self.ctx.options.memory_tracker {
+            tracker.record_integer_fast_path();
+        }
+    }
+
     fn record_constant_load(&self, index: usize) {
         if let Some(tracker) = &self.ctx.options.memory_tracker {
             tracker.record_constant(index);
@@ -1939,12 +2576,27 @@ impl RuntimeExecutor {
             return;
         };
         println!();
+        if let Some(cache) = &self.ctx.options.jit_code_cache {
+            let stats = cache.stats();
+            println!(
+                "[JIT] code cache: {}/{} listing bytes, compiled: {}, evictions: {} ({} listing bytes), readmitted: {}, rejected: {}",
+                stats.resident_listing_bytes,
+                cache.capacity(),
+                stats.compiled,
+                stats.evictions,
+                stats.evicted_listing_bytes,
+                stats.readmitted,
+                stats.rejected
+            );
+        }
         if dispatcher.is_empty() {
             println!("[JIT] Tier-0 compiled functions: (none)");
             return;
         }
         println!("[JIT] Tier-0 compiled functions:");
-        for artifact in dispatcher.compiled_functions() {
+        let mut compiled: Vec<_> = dispatcher.compiled_functions().into_iter().collect();
+        compiled.sort_by(|a, b| a.name.cmp(&b.name));
+        for artifact in compiled {
             let calls = snapshot.get(&artifact.name).copied().unwrap_or(0);
             let hot = self.profile.hot_functions.is_hot(&artifact.name);
             let execs = dispatcher.execution_count(artifact.function_id);
@@ -1982,6 +2634,25 @@ impl RuntimeExecutor {
         }
     }
 
+    fn emit_function_exit(&self, function_index: usize, entered_at: Instant) {
+        let name = self
+            .ctx
+            .program
+            .functions
+            .get(function_index)
+            .map(|function| function.name.clone())
+            .unwrap_or_default();
+        self.emit_telemetry_event(
+            TelemetryEventKind::FunctionExit {
+                name,
+                elapsed_nanos: entered_at.elapsed().as_nanos() as u64,
+            },
+            self.task_label.clone(),
+            None,
+            None,
+        );
+    }
+
     fn record_scheduler_snapshot(&self) {
         if let Some(tracker) = &self.ctx.options.memory_tracker {
             let mut snapshots = Vec::new();
@@ -2009,6 +2680,8 @@ struct CallFrame {
     stack_base: usize,
     transfer_locals: Option<Vec<Value>>,
     transfer_debug: Option<(bool, usize)>,
+    /// Set when telemetry is attached, to time the call for `FunctionExit`.
+    entered_at: Option<Instant>,
 }
 
 fn extract_task_id(value: Value) -> SolvraResult<u64> {
@@ -2049,19 +2722,26 @@ fn invoke_core_builtin(name: &str, args: &[Value]) -> SolvraResult<Value> {
     }
 }
 
-fn execute_arithmetic(opcode: Opcode, lhs: Value, rhs: Value) -> SolvraResult<Value> {
+fn execute_arithmetic(
+    opcode: Opcode,
+    lhs: Value,
+    rhs: Value,
+    overflow: OverflowMode,
+) -> SolvraResult<Value> {
     if opcode == Opcode::Add {
         if let Some(result) = string_add(&lhs, &rhs) {
             return Ok(Value::String(result));
         }
     }
     match (lhs, rhs) {
-        (Value::Integer(a), Value::Integer(b)) => execute_integer_arithmetic(opcode, a, b),
+        (Value::Integer(a), Value::Integer(b)) => {
+            execute_integer_arithmetic(opcode, a, b, overflow)
+        }
         (Value::Float(a), Value::Float(b)) => execute_float_arithmetic(opcode, a, b),
         (Value::Integer(a), Value::Float(b)) => execute_float_arithmetic(opcode, a as f64, b),
         (Value::Float(a), Value::Integer(b)) => execute_float_arithmetic(opcode, a, b as f64),
         (Value::Null, Value::Integer(b)) if opcode == Opcode::Add => {
-            execute_integer_arithmetic(opcode, 0, b)
+            execute_integer_arithmetic(opcode, 0, b, overflow)
         }
         (Value::Null, Value::Float(b)) if opcode == Opcode::Add => {
             execute_float_arithmetic(opcode, 0.0, b)
@@ -2093,12 +2773,46 @@ fn string_add(lhs: &Value, rhs: &Value) -> Option<String> {
     }
 }
 
-fn execute_integer_arithmetic(opcode: Opcode, lhs: i64, rhs: i64) -> SolvraResult<Value> {
+fn execute_integer_arithmetic(
+    opcode: Opcode,
+    lhs: i64,
+    rhs: i64,
+    overflow: OverflowMode,
+) -> SolvraResult<Value> {
     use Opcode::*;
     let value = match opcode {
-        Add => Value::Integer(lhs + rhs),
-        Sub => Value::Integer(lhs - rhs),
-        Mul => Value::Integer(lhs * rhs),
+        Add | Sub | Mul => {
+            let (checked, wrapping, saturating, symbol) = match opcode {
+                Add => (
+                    lhs.checked_add(rhs),
+                    lhs.wrapping_add(rhs),
+                    lhs.saturating_add(rhs),
+                    "+",
+                ),
+                Sub => (
+                    lhs.checked_sub(rhs),
+                    lhs.wrapping_sub(rhs),
+                    lhs.saturating_sub(rhs),
+                    "-",
+                ),
+                _ => (
+                    lhs.checked_mul(rhs),
+                    lhs.wrapping_mul(rhs),
+                    lhs.saturating_mul(rhs),
+                    "*",
+                ),
+            };
+            match (checked, overflow) {
+                (Some(result), _) => Value::Integer(result),
+                (None, OverflowMode::Wrap) => Value::Integer(wrapping),
+                (None, OverflowMode::Saturate) => Value::Integer(saturating),
+                (None, OverflowMode::Error) => {
+                    return Err(SolvraError::Internal(format!(
+                        "integer overflow: {lhs} {symbol} {rhs}"
+                    )));
+                }
+            }
+        }
         Div => {
             if rhs == 0 {
                 return Err(SolvraError::Internal("integer division by zero".into()));
@@ -2147,7 +2861,7 @@ fn execute_float_arithmetic(opcode: Opcode, lhs: f64, rhs: f64) -> SolvraResult<
     Ok(value)
 }
 
-fn negate_value(value: Value) -> SolvraResult<Value> {
+pub(super) fn negate_value(value: Value) -> SolvraResult<Value> {
     match value {
         Value::Integer(int) => Ok(Value::Integer(-int)),
         Value::Float(float) => Ok(Value::Float(-float)),
@@ -2209,7 +2923,7 @@ fn value_to_string(value: &Value) -> String {
     value.stringify()
 }
 
-fn opcode_name(opcode: Opcode) -> &'static str {
+pub(super) fn opcode_name(opcode: Opcode) -> &'static str {
     match opcode {
         Opcode::Halt => "Halt",
         Opcode::LoadConst => "LoadConst",
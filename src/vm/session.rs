@@ -0,0 +1,353 @@
+//=====================================================
+// File: vm/session.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Instruction-level control of the VM for debuggers
+// Objective: Let a debugger step through a program, stop at breakpoints,
+//            inspect the paused frames, and evaluate watch expressions
+//            before resuming
+//=====================================================
+
+use tokio::runtime::Runtime;
+use tokio::task::LocalSet;
+
+use super::debug_info::DebugInfo;
+use super::runtime::{
+    RuntimeExecutor, RuntimeOptions, SolvraProgram, build_tokio_runtime, entry_executor,
+    negate_value,
+};
+use crate::ast::{BinaryOp, Expr, Literal, UnaryOp};
+use crate::parser::Parser;
+use crate::tokenizer::Tokenizer;
+use solvra_core::vm::instruction::Opcode;
+use solvra_core::{SolvraError, Value};
+
+/// Where a frame is paused: the instruction it will execute next.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub function: String,
+    pub function_index: usize,
+    pub ip: usize,
+    /// Source line, when the program was compiled with debug info.
+    pub line: Option<usize>,
+}
+
+/// A place `VmSession::resume` stops at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// The first instruction of a source line; needs debug info.
+    Line(usize),
+    /// A specific instruction of a function.
+    Instruction { function: String, ip: usize },
+}
+
+#[derive(Debug)]
+pub enum StepResult {
+    Paused(Location),
+    Finished(Value),
+    Error(SolvraError),
+}
+
+/// A program run that advances only when asked to.
+///
+/// A new session is paused before the entry function's first instruction.
+/// `step` executes one instruction; `resume` keeps stepping until a
+/// breakpoint is reached or the program ends. While paused, the frames,
+/// locals, and operand stack can be inspected and `evaluate` computes watch
+/// expressions over the innermost frame.
+pub struct VmSession {
+    runtime: Runtime,
+    local: LocalSet,
+    executor: Option<RuntimeExecutor>,
+    breakpoints: Vec<Breakpoint>,
+    result: Option<Value>,
+}
+
+impl VmSession {
+    pub fn new(program: SolvraProgram, options: RuntimeOptions) -> Result<Self, SolvraError> {
+        let runtime = build_tokio_runtime()?;
+        let local = LocalSet::new();
+        let mut executor =
+            local.block_on(&runtime, async move { entry_executor(program, options) })?;
+        executor.begin_stepping();
+        Ok(Self {
+            runtime,
+            local,
+            executor: Some(executor),
+            breakpoints: Vec::new(),
+            result: None,
+        })
+    }
+
+    pub fn set_breakpoint(&mut self, breakpoint: Breakpoint) {
+        if !self.breakpoints.contains(&breakpoint) {
+            self.breakpoints.push(breakpoint);
+        }
+    }
+
+    pub fn clear_breakpoint(&mut self, breakpoint: &Breakpoint) {
+        self.breakpoints.retain(|existing| existing != breakpoint);
+    }
+
+    /// Execute one instruction.
+    pub fn step(&mut self) -> StepResult {
+        let Some(executor) = self.executor.as_mut() else {
+            return match &self.result {
+                Some(value) => StepResult::Finished(value.clone()),
+                None => StepResult::Error(SolvraError::Internal(
+                    "debug session has already stopped".into(),
+                )),
+            };
+        };
+        match self
+            .local
+            .block_on(&self.runtime, executor.step_instruction())
+        {
+            Ok(None) => match self.location() {
+                Some(location) => StepResult::Paused(location),
+                None => self.finish(Value::Null),
+            },
+            Ok(Some(value)) => self.finish(value),
+            Err(error) => {
+                self.stop();
+                StepResult::Error(error)
+            }
+        }
+    }
+
+    /// Step until a breakpoint or the end of the program. The instruction at
+    /// the current location always runs first, so resuming from a breakpoint
+    /// moves past it.
+    pub fn resume(&mut self) -> StepResult {
+        loop {
+            let before = self.location();
+            match self.step() {
+                StepResult::Paused(location) if self.is_breakpoint(before.as_ref(), &location) => {
+                    return StepResult::Paused(location);
+                }
+                StepResult::Paused(_) => {}
+                other => return other,
+            }
+        }
+    }
+
+    /// Location of the innermost frame, or `None` once the program ended.
+    pub fn location(&self) -> Option<Location> {
+        self.call_stack().pop()
+    }
+
+    /// Locations of every active frame, outermost first.
+    pub fn call_stack(&self) -> Vec<Location> {
+        let Some(executor) = &self.executor else {
+            return Vec::new();
+        };
+        let program = executor.program();
+        let debug_info = executor.debug_info();
+        executor
+            .frame_positions()
+            .into_iter()
+            .map(|(function_index, ip)| Location {
+                function: program
+                    .functions
+                    .get(function_index)
+                    .map(|function| function.name.clone())
+                    .unwrap_or_default(),
+                function_index,
+                ip,
+                line: debug_info.and_then(|info| info.line_for(function_index, ip)),
+            })
+            .collect()
+    }
+
+    /// Local slots of the innermost frame.
+    pub fn locals(&self) -> &[Value] {
+        self.executor
+            .as_ref()
+            .map(RuntimeExecutor::top_frame_locals)
+            .unwrap_or(&[])
+    }
+
+    /// Operand stack shared by all frames, bottom first.
+    pub fn operand_stack(&self) -> &[Value] {
+        self.executor
+            .as_ref()
+            .map(RuntimeExecutor::operand_stack)
+            .unwrap_or(&[])
+    }
+
+    /// Evaluate `expr_source` against the paused frame's locals.
+    ///
+    /// Only forms that cannot change program state are accepted: literals,
+    /// locals, unary and binary operators, lists, indexing, and member reads.
+    /// Calls, assignments, and object literals are rejected instead of run.
+    /// Locals are found by name, so the program must carry debug info.
+    pub fn evaluate(&mut self, expr_source: &str) -> Result<Value, SolvraError> {
+        let executor = self
+            .executor
+            .as_ref()
+            .ok_or_else(|| watch_error("debug session has already stopped"))?;
+        let mut tokenizer = Tokenizer::new(expr_source);
+        let tokens = tokenizer
+            .tokenize()
+            .map_err(|err| watch_error(format!("invalid watch expression: {err}")))?;
+        let expr = Parser::new(tokens)
+            .parse_expression_only()
+            .map_err(|err| watch_error(format!("invalid watch expression: {err}")))?;
+        let (function_index, ip) = executor
+            .frame_positions()
+            .last()
+            .copied()
+            .ok_or_else(|| watch_error("no active frame"))?;
+        let debug_info = executor.debug_info().ok_or_else(|| {
+            watch_error("watch expressions need a program compiled with debug info")
+        })?;
+        Watch {
+            executor,
+            debug_info,
+            function_index,
+            ip,
+        }
+        .eval(&expr)
+    }
+
+    fn is_breakpoint(&self, before: Option<&Location>, location: &Location) -> bool {
+        self.breakpoints.iter().any(|breakpoint| match breakpoint {
+            Breakpoint::Line(line) => {
+                // Only the first instruction of the line in a given frame.
+                location.line == Some(*line)
+                    && before.is_none_or(|before| {
+                        before.line != location.line
+                            || before.function_index != location.function_index
+                    })
+            }
+            Breakpoint::Instruction { function, ip } => {
+                location.function == *function && location.ip == *ip
+            }
+        })
+    }
+
+    fn finish(&mut self, value: Value) -> StepResult {
+        self.stop();
+        self.result = Some(value.clone());
+        StepResult::Finished(value)
+    }
+
+    fn stop(&mut self) {
+        if let Some(mut executor) = self.executor.take() {
+            executor.finish_stepping();
+        }
+    }
+}
+
+/// Read-only evaluation of a watch expression in one paused frame.
+struct Watch<'a> {
+    executor: &'a RuntimeExecutor,
+    debug_info: &'a DebugInfo,
+    function_index: usize,
+    ip: usize,
+}
+
+impl Watch<'_> {
+    fn eval(&self, expr: &Expr) -> Result<Value, SolvraError> {
+        match expr {
+            Expr::Literal { value, .. } => match value {
+                Literal::Integer(value) => Ok(Value::Integer(*value)),
+                Literal::Float(value) => Ok(Value::Float(*value)),
+                Literal::String(value) => Ok(Value::String(value.to_string())),
+                Literal::Boolean(value) => Ok(Value::Boolean(*value)),
+                Literal::Null => Ok(Value::Null),
+                Literal::Array(elements) => self.eval_list(elements),
+                Literal::Object(_) => Err(watch_error(
+                    "object literals are not allowed in watch expressions",
+                )),
+            },
+            Expr::List { elements, .. } => self.eval_list(elements),
+            Expr::Identifier { name, .. } => {
+                let slot = self
+                    .debug_info
+                    .local_slot(self.function_index, self.ip, name.as_str())
+                    .ok_or_else(|| watch_error(format!("unknown local '{name}'")))?;
+                Ok(self
+                    .executor
+                    .top_frame_locals()
+                    .get(slot as usize)
+                    .cloned()
+                    .unwrap_or(Value::Null))
+            }
+            Expr::Unary {
+                operator, operand, ..
+            } => {
+                let value = self.eval(operand)?;
+                match operator {
+                    UnaryOp::Minus => negate_value(value),
+                    UnaryOp::Not => Ok(Value::Boolean(!value.is_truthy())),
+                    UnaryOp::Plus => Ok(value),
+                    other => Err(watch_error(format!("unsupported operator {other:?}"))),
+                }
+            }
+            Expr::Binary {
+                left,
+                operator,
+                right,
+                ..
+            } => {
+                let opcode = binary_opcode(operator)
+                    .ok_or_else(|| watch_error(format!("unsupported operator {operator:?}")))?;
+                let lhs = self.eval(left)?;
+                let rhs = self.eval(right)?;
+                self.executor.apply_binary(opcode, lhs, rhs)
+            }
+            Expr::Index { object, index, .. } => {
+                let collection = self.eval(object)?;
+                let index = self.eval(index)?;
+                self.executor.index_value(collection, index)
+            }
+            Expr::Member {
+                object, property, ..
+            } => {
+                let target = self.eval(object)?;
+                self.executor.load_member_value(target, property.as_str())
+            }
+            _ => Err(watch_error(
+                "only side-effect-free expressions can be watched",
+            )),
+        }
+    }
+
+    fn eval_list(&self, elements: &[Expr]) -> Result<Value, SolvraError> {
+        elements
+            .iter()
+            .map(|element| self.eval(element))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array)
+    }
+}
+
+/// Opcode the VM compiler emits for `operator`.
+fn binary_opcode(operator: &BinaryOp) -> Option<Opcode> {
+    Some(match operator {
+        BinaryOp::Add => Opcode::Add,
+        BinaryOp::Subtract => Opcode::Sub,
+        BinaryOp::Multiply => Opcode::Mul,
+        BinaryOp::Divide => Opcode::Div,
+        BinaryOp::Modulo => Opcode::Mod,
+        BinaryOp::Equal => Opcode::Equal,
+        BinaryOp::NotEqual => Opcode::NotEqual,
+        BinaryOp::Less => Opcode::Less,
+        BinaryOp::Greater => Opcode::Greater,
+        BinaryOp::LessEqual => Opcode::LessEqual,
+        BinaryOp::GreaterEqual => Opcode::GreaterEqual,
+        BinaryOp::And => Opcode::And,
+        BinaryOp::Or => Opcode::Or,
+        _ => return None,
+    })
+}
+
+fn watch_error(message: impl Into<String>) -> SolvraError {
+    SolvraError::Internal(message.into())
+}
+
+//=====================================================
+// End of file
+//=====================================================
@@ -0,0 +1,109 @@
+//=====================================================
+// File: vm/svc.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Safe loading of serialized .svc bytecode
+// Objective: Decode untrusted .svc bytes without panicking or over-allocating
+//            and reject programs whose operands point outside the program
+//=====================================================
+
+use std::fmt;
+
+use bincode::Options;
+use solvra_core::vm::bytecode::VmBytecode;
+use solvra_core::vm::instruction::Opcode;
+
+use super::runtime::DYNAMIC_CALL_TARGET;
+
+/// Why a `.svc` payload was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SvcError {
+    /// The bytes are not a serialized program.
+    Malformed(String),
+    /// The program decoded, but an operand refers past the end of a table.
+    Invalid {
+        function: String,
+        ip: usize,
+        message: String,
+    },
+    /// The entry function index is out of range.
+    MissingEntry(usize),
+}
+
+impl fmt::Display for SvcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SvcError::Malformed(reason) => write!(f, "not a valid .svc file: {reason}"),
+            SvcError::Invalid {
+                function,
+                ip,
+                message,
+            } => write!(f, "invalid bytecode in {function} at {ip:04}: {message}"),
+            SvcError::MissingEntry(index) => {
+                write!(
+                    f,
+                    "invalid bytecode: entry function #{index} does not exist"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SvcError {}
+
+/// Decode a `.svc` payload written by `bincode::serialize` and check that
+/// it is safe to run. Length prefixes may not claim more bytes than the
+/// input holds, so a short file cannot trigger a large allocation.
+pub fn decode_svc(bytes: &[u8]) -> Result<VmBytecode, SvcError> {
+    let program: VmBytecode = bincode::options()
+        .with_fixint_encoding()
+        .allow_trailing_bytes()
+        .with_limit(bytes.len() as u64)
+        .deserialize(bytes)
+        .map_err(|err| SvcError::Malformed(err.to_string()))?;
+    check_structure(&program)?;
+    Ok(program)
+}
+
+/// Verify that every constant, function, and jump operand is in range.
+pub fn check_structure(program: &VmBytecode) -> Result<(), SvcError> {
+    if program.entry >= program.functions.len() {
+        return Err(SvcError::MissingEntry(program.entry));
+    }
+    let constants = program.constants.len();
+    let functions = program.functions.len();
+    for function in &program.functions {
+        let length = function.instructions.len();
+        for (ip, instruction) in function.instructions.iter().enumerate() {
+            let a = instruction.operand_a as usize;
+            let problem = match instruction.opcode {
+                Opcode::LoadConst | Opcode::LoadMember | Opcode::CallBuiltin | Opcode::CoreCall
+                    if a >= constants =>
+                {
+                    Some(format!("constant #{a} does not exist"))
+                }
+                Opcode::Call if instruction.operand_a == DYNAMIC_CALL_TARGET => None,
+                Opcode::Call | Opcode::CallAsync | Opcode::LoadLambda if a >= functions => {
+                    Some(format!("function #{a} does not exist"))
+                }
+                Opcode::Jump | Opcode::JumpIfFalse if a > length => {
+                    Some(format!("jump target {a:04} is past the end"))
+                }
+                _ => None,
+            };
+            if let Some(message) = problem {
+                return Err(SvcError::Invalid {
+                    function: function.name.clone(),
+                    ip,
+                    message,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+//=====================================================
+// End of file
+//=====================================================
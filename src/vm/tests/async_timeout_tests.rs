@@ -11,9 +11,9 @@ mod util;
 
 use crate::parser::Parser;
 use crate::tokenizer::Tokenizer;
-use crate::vm::compiler as vm_compiler;
+use crate::vm::compiler::{self as vm_compiler, CompilerOptions};
 use crate::vm::runtime::{MemoryTracker, RuntimeOptions, run_bytecode};
-use crate::vm::{TelemetryEvent, TelemetryEventKind};
+use crate::vm::{TelemetryCollector, TelemetryEvent, TelemetryEventKind};
 use solvra_core::SolvraError;
 use solvra_core::vm::bytecode::VmBytecode;
 
@@ -117,6 +117,63 @@ fn async_timeout_emits_runtime_exception() {
     );
 }
 
+#[test]
+fn timeout_keeps_telemetry_and_points_at_the_pending_await() {
+    let source = r#"
+fn forever() {
+    while true {
+    }
+}
+
+fn main() {
+    let task = async forever();
+    let value = await task;
+    return value;
+}
+"#;
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse script");
+    let compiled = vm_compiler::compile_program_with_options(&program, CompilerOptions::debug())
+        .expect("compile script");
+    let vm = VmBytecode::decode(&compiled.bytecode[..]).expect("decode bytecode");
+    let collector = TelemetryCollector::new();
+    let options = RuntimeOptions::with_trace(false)
+        .with_async_timeout(100)
+        .with_debug_info(Arc::new(compiled.debug_info.expect("debug info")))
+        .with_telemetry_collector(collector.clone());
+
+    let err = run_bytecode(Arc::new(vm), options).expect_err("expected timeout");
+    let SolvraError::RuntimeException { message, stack } = err else {
+        panic!("expected runtime exception, received {err:?}");
+    };
+    assert!(
+        message.contains("RuntimeException::Timeout"),
+        "expected timeout label in message: {message}"
+    );
+    assert!(
+        stack.iter().any(|frame| frame.function == "main"
+            && frame.location.as_ref().map(|location| location.line) == Some(9)),
+        "expected the pending await in the stack trace: {stack:?}"
+    );
+
+    let events = collector.snapshot();
+    assert!(!events.is_empty(), "expected telemetry to be kept");
+    assert!(
+        events
+            .iter()
+            .any(|record| matches!(record.kind, TelemetryEventKind::TaskTimeout)),
+        "expected telemetry to include TaskTimeout"
+    );
+    assert!(
+        !events
+            .iter()
+            .any(|record| matches!(record.kind, TelemetryEventKind::TaskPanic)),
+        "a timeout should not be reported as a task panic"
+    );
+}
+
 #[test]
 fn deadline_builtin_triggers_timeout_with_lineage() {
     let src = r#"
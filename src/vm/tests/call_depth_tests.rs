@@ -0,0 +1,70 @@
+//=============================================
+// solvra_script/vm/tests/call_depth_tests.rs
+//=============================================
+// Purpose: Validate the VM call-depth limit for runaway recursion.
+//=============================================
+
+use std::sync::Arc;
+
+use crate::parser::Parser;
+use crate::tokenizer::Tokenizer;
+use crate::vm::compiler as vm_compiler;
+use crate::vm::runtime::{RuntimeOptions, run_bytecode};
+use solvra_core::vm::bytecode::VmBytecode;
+use solvra_core::{SolvraError, Value};
+
+fn compile_program(source: &str) -> Arc<VmBytecode> {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    let bytecode = vm_compiler::compile_program(&program).expect("compile program");
+    let vm_program = VmBytecode::decode(&bytecode[..]).expect("decode vm bytecode");
+    Arc::new(vm_program)
+}
+
+#[test]
+fn infinite_recursion_reports_depth_error() {
+    let program = compile_program(
+        r#"
+fn forever(n) {
+    return forever(n + 1);
+}
+
+fn main() {
+    return forever(0);
+}
+"#,
+    );
+    let options = RuntimeOptions::default().with_max_call_depth(64);
+    match run_bytecode(program, options) {
+        Err(SolvraError::RuntimeException { message, stack }) => {
+            assert!(
+                message.contains("maximum recursion depth exceeded"),
+                "unexpected message: {message}"
+            );
+            assert!(stack.iter().any(|frame| frame.function.contains("forever")));
+        }
+        other => panic!("expected recursion depth error, got {other:?}"),
+    }
+}
+
+#[test]
+fn default_depth_allows_ordinary_recursion() {
+    let program = compile_program(
+        r#"
+fn count(n) {
+    if n == 0 {
+        return 0;
+    }
+    return 1 + count(n - 1);
+}
+
+fn main() {
+    return count(500);
+}
+"#,
+    );
+    let result = run_bytecode(program, RuntimeOptions::default()).expect("run program");
+    assert_eq!(result, Value::Integer(500));
+}
@@ -0,0 +1,137 @@
+//=============================================
+// solvra_script/vm/tests/compile_cache_tests.rs
+//=============================================
+// Purpose: Validate source-hash keyed bytecode caching.
+//=============================================
+
+use std::cell::Cell;
+
+use crate::ir::ir::SolvraIrModule;
+use crate::ir::lowering::lower_program;
+use crate::ir::opt::PassManager;
+use crate::parser::Parser;
+use crate::resolver::{Diagnostics, resolve_module};
+use crate::tokenizer::Tokenizer;
+use crate::vm::compile_cache::{CacheStatus, CompileCache};
+use crate::vm::compiler::{self as vm_compiler, CompiledProgram, CompilerOptions};
+use anyhow::Result;
+use tempfile::tempdir;
+
+fn compile(source: &str, options: CompilerOptions) -> Result<CompiledProgram> {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let program = Parser::new(tokens).parse().expect("parse program");
+    vm_compiler::compile_program_with_options(&program, options)
+}
+
+fn lower(source: &str, passes: Option<&PassManager>) -> Result<SolvraIrModule> {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let program = Parser::new(tokens).parse().expect("parse program");
+    let resolutions = resolve_module(&program, &mut Diagnostics::new());
+    let mut module = lower_program(&program, &resolutions).expect("lower program");
+    if let Some(passes) = passes {
+        passes.run(&mut module);
+    }
+    Ok(module)
+}
+
+fn dumps(module: &SolvraIrModule) -> Vec<String> {
+    module
+        .functions()
+        .iter()
+        .map(|function| function.dump())
+        .collect()
+}
+
+#[test]
+fn unchanged_source_hits_cache() {
+    let dir = tempdir().expect("tempdir");
+    let cache = CompileCache::new(dir.path());
+    let source = "fn main() { return 1 + 2; }";
+    let options = CompilerOptions::release();
+    let compiles = Cell::new(0);
+    let run = || {
+        cache.get_or_compile(source, options, || {
+            compiles.set(compiles.get() + 1);
+            compile(source, options)
+        })
+    };
+
+    let (first, status) = run().expect("first compile");
+    assert_eq!(status, CacheStatus::Miss);
+    let (second, status) = run().expect("second compile");
+    assert_eq!(status, CacheStatus::Hit);
+    assert_eq!(first.bytecode, second.bytecode);
+    assert_eq!(compiles.get(), 1);
+}
+
+#[test]
+fn modified_source_misses_cache() {
+    let dir = tempdir().expect("tempdir");
+    let cache = CompileCache::new(dir.path());
+    let options = CompilerOptions::release();
+    let original = "fn main() { return 1; }";
+    let modified = "fn main() { return 2; }";
+
+    let (_, status) = cache
+        .get_or_compile(original, options, || compile(original, options))
+        .expect("compile original");
+    assert_eq!(status, CacheStatus::Miss);
+    let (_, status) = cache
+        .get_or_compile(modified, options, || compile(modified, options))
+        .expect("compile modified");
+    assert_eq!(status, CacheStatus::Miss);
+}
+
+#[test]
+fn option_change_invalidates_entry() {
+    let dir = tempdir().expect("tempdir");
+    let cache = CompileCache::new(dir.path());
+    let source = "fn main() { let x = 3; return x; }";
+
+    let release = CompilerOptions::release();
+    cache
+        .get_or_compile(source, release, || compile(source, release))
+        .expect("release compile");
+    let debug = CompilerOptions::debug();
+    let (compiled, status) = cache
+        .get_or_compile(source, debug, || compile(source, debug))
+        .expect("debug compile");
+    assert_eq!(status, CacheStatus::Miss);
+    assert!(compiled.debug_info.is_some());
+
+    let (cached, status) = cache
+        .get_or_compile(source, debug, || compile(source, debug))
+        .expect("debug recompile");
+    assert_eq!(status, CacheStatus::Hit);
+    assert_eq!(cached.debug_info, compiled.debug_info);
+}
+
+#[test]
+fn lowered_ir_is_reused_until_the_pass_list_changes() {
+    let dir = tempdir().expect("tempdir");
+    let cache = CompileCache::new(dir.path());
+    let source = "fn main() { let x = 2 * 3; return x + 1; }";
+    let passes = PassManager::default_pipeline();
+    let lowerings = Cell::new(0);
+    let run = |passes: Option<&PassManager>| {
+        cache.get_or_lower(source, passes, || {
+            lowerings.set(lowerings.get() + 1);
+            lower(source, passes)
+        })
+    };
+
+    let (first, status) = run(Some(&passes)).expect("first lowering");
+    assert_eq!(status, CacheStatus::Miss);
+    let (second, status) = run(Some(&passes)).expect("second lowering");
+    assert_eq!(status, CacheStatus::Hit);
+    assert_eq!(dumps(&first), dumps(&second));
+    assert_eq!(lowerings.get(), 1);
+
+    let (_, status) = run(None).expect("unoptimized lowering");
+    assert_eq!(status, CacheStatus::Miss);
+    assert_eq!(lowerings.get(), 2);
+}
+
+//=============================================
@@ -0,0 +1,89 @@
+//=============================================
+// solvra_script/vm/tests/compiler_options_tests.rs
+//=============================================
+// Purpose: Validate debug vs release compilation modes.
+//=============================================
+
+use std::sync::Arc;
+
+use crate::ast::Program;
+use crate::parser::Parser;
+use crate::tokenizer::Tokenizer;
+use crate::vm::compiler::{self as vm_compiler, CompiledProgram, CompilerOptions};
+use crate::vm::runtime::{RuntimeOptions, run_bytecode};
+use solvra_core::SolvraError;
+use solvra_core::vm::bytecode::VmBytecode;
+
+fn parse(source: &str) -> Program {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let mut parser = Parser::new(tokens);
+    parser.parse().expect("parse program")
+}
+
+fn compile(source: &str, options: CompilerOptions) -> CompiledProgram {
+    vm_compiler::compile_program_with_options(&parse(source), options).expect("compile program")
+}
+
+fn instruction_count(compiled: &CompiledProgram) -> usize {
+    let vm = VmBytecode::decode(&compiled.bytecode[..]).expect("decode vm bytecode");
+    vm.functions
+        .iter()
+        .map(|func| func.instructions.len())
+        .sum()
+}
+
+#[test]
+fn release_bytecode_is_smaller_than_debug() {
+    let source = r#"
+fn main() {
+    let x = 4;
+    return x + 0;
+}
+"#;
+    let debug = compile(source, CompilerOptions::debug());
+    let release = compile(source, CompilerOptions::release());
+    assert!(debug.debug_info.is_some());
+    assert!(release.debug_info.is_none());
+    assert!(instruction_count(&release) < instruction_count(&debug));
+    assert!(release.bytecode.len() < debug.bytecode.len());
+}
+
+#[test]
+fn debug_bytecode_reports_source_lines_in_stack_traces() {
+    let source = r#"
+fn explode() {
+    return magic_missing_builtin(1);
+}
+
+fn main() {
+    return explode();
+}
+"#;
+    let debug = compile(source, CompilerOptions::debug());
+    let program = Arc::new(VmBytecode::decode(&debug.bytecode[..]).expect("decode"));
+    let options =
+        RuntimeOptions::default().with_debug_info(Arc::new(debug.debug_info.expect("debug info")));
+    let debug_stack = match run_bytecode(program, options) {
+        Err(SolvraError::RuntimeException { stack, .. }) => stack,
+        other => panic!("expected runtime exception, got {other:?}"),
+    };
+    let frames: Vec<_> = debug_stack
+        .iter()
+        .map(|frame| {
+            let line = frame.location.as_ref().map(|location| location.line);
+            (frame.function.as_str(), line)
+        })
+        .collect();
+    assert_eq!(frames, [("main", Some(7)), ("explode", Some(3))]);
+
+    let release = compile(source, CompilerOptions::release());
+    let program = Arc::new(VmBytecode::decode(&release.bytecode[..]).expect("decode"));
+    let release_stack = match run_bytecode(program, RuntimeOptions::default()) {
+        Err(SolvraError::RuntimeException { stack, .. }) => stack,
+        other => panic!("expected runtime exception, got {other:?}"),
+    };
+    assert!(release_stack.iter().all(|frame| frame.location.is_none()));
+}
+
+//=============================================
@@ -0,0 +1,84 @@
+//=============================================
+// solvra_script/vm/tests/const_fold_tests.rs
+//=============================================
+// Purpose: Validate compile-time constant folding.
+//=============================================
+
+use std::sync::Arc;
+
+use crate::parser::Parser;
+use crate::tokenizer::Tokenizer;
+use crate::vm::compiler::{self as vm_compiler, CompilerOptions};
+use crate::vm::runtime::{RuntimeOptions, run_bytecode};
+use solvra_core::Value;
+use solvra_core::vm::bytecode::VmBytecode;
+use solvra_core::vm::instruction::Opcode;
+
+fn compile(source: &str, fold_constants: bool) -> VmBytecode {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    let options = CompilerOptions {
+        fold_constants,
+        ..CompilerOptions::release()
+    };
+    let compiled =
+        vm_compiler::compile_program_with_options(&program, options).expect("compile program");
+    VmBytecode::decode(&compiled.bytecode[..]).expect("decode vm bytecode")
+}
+
+fn main_opcodes(vm: &VmBytecode) -> Vec<Opcode> {
+    vm.functions
+        .iter()
+        .find(|func| func.name == "main")
+        .expect("main function")
+        .instructions
+        .iter()
+        .map(|inst| inst.opcode)
+        .collect()
+}
+
+#[test]
+fn literal_addition_becomes_single_constant_load() {
+    let source = "fn main() { return 1 + 2; }";
+    let folded = main_opcodes(&compile(source, true));
+    let unfolded = main_opcodes(&compile(source, false));
+
+    let loads = |ops: &[Opcode]| ops.iter().filter(|op| **op == Opcode::LoadConst).count();
+    assert_eq!(loads(&folded), 1);
+    assert!(!folded.contains(&Opcode::Add));
+    assert_eq!(folded.len() + 2, unfolded.len());
+}
+
+#[test]
+fn folded_program_produces_same_result() {
+    let source = "fn main() { return 2 + 3 * 4 - 10 / 5; }";
+    let vm = compile(source, true);
+    assert!(!main_opcodes(&vm).contains(&Opcode::Mul));
+    let result = run_bytecode(Arc::new(vm), RuntimeOptions::default()).expect("run program");
+    assert_eq!(result, Value::Integer(12));
+}
+
+#[test]
+fn calls_and_variables_are_not_folded() {
+    let source = r#"
+fn two() {
+    return 2;
+}
+
+fn main() {
+    let x = 1;
+    return x + two() + 3;
+}
+"#;
+    let folded = main_opcodes(&compile(source, true));
+    assert!(folded.contains(&Opcode::Call));
+    assert_eq!(folded.iter().filter(|op| **op == Opcode::Add).count(), 2);
+}
+
+#[test]
+fn division_by_zero_is_left_for_runtime() {
+    let folded = main_opcodes(&compile("fn main() { return 1 / 0; }", true));
+    assert!(folded.contains(&Opcode::Div));
+}
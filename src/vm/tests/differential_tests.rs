@@ -0,0 +1,510 @@
+//=============================================
+// solvra_script/vm/tests/differential_tests.rs
+//=============================================
+// Purpose: Run seeded random programs through the interpreter and the VM,
+//          shrinking any divergence to a minimal counterexample.
+//=============================================
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::parity_tests::{BackendResult, Outcome, run_both};
+
+const SEED: u64 = 0x5eed_d1ff;
+const CASES: usize = 300;
+const VARS: usize = 3;
+const EXPR_DEPTH: usize = 2;
+const COND_DEPTH: usize = 2;
+const BLOCK_DEPTH: usize = 2;
+
+const ARITH_OPS: &[&str] = &["+", "-", "*", "/", "%"];
+const COMPARE_OPS: &[&str] = &["<", "<=", ">", ">=", "==", "!="];
+
+/// Integer expressions over literals and the `v*` variables. Variables stay
+/// below 1000 in magnitude (every assignment is reduced `% 1000`), so with
+/// at most four leaves no product can overflow on either backend.
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Int(i64),
+    Var(usize),
+    Neg(Box<Expr>),
+    Binary(&'static str, Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Cond {
+    Literal(bool),
+    Compare(&'static str, Expr, Expr),
+    Not(Box<Cond>),
+    Logic(&'static str, Box<Cond>, Box<Cond>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Stmt {
+    Assign(usize, Expr),
+    If(Cond, Vec<Stmt>, Vec<Stmt>),
+    /// Runs `body` exactly `limit` times using a dedicated `c*` counter that
+    /// nothing else reads or writes.
+    While {
+        counter: usize,
+        limit: i64,
+        body: Vec<Stmt>,
+    },
+}
+
+/// The value a generated program evaluates to.
+#[derive(Debug, Clone, PartialEq)]
+enum Tail {
+    Value(Expr),
+    Test(Cond),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Case {
+    inits: [i64; VARS],
+    body: Vec<Stmt>,
+    tail: Tail,
+}
+
+/// Result of a run with backend-specific error text reduced to the failure
+/// both backends are expected to report.
+#[derive(Debug, PartialEq)]
+enum Failure {
+    DivisionByZero,
+    Other(String),
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Int(value) => write!(f, "{value}"),
+            Expr::Var(index) => write!(f, "v{index}"),
+            Expr::Neg(inner) => write!(f, "-({inner})"),
+            Expr::Binary(op, left, right) => write!(f, "({left} {op} {right})"),
+        }
+    }
+}
+
+impl fmt::Display for Cond {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cond::Literal(value) => write!(f, "{value}"),
+            Cond::Compare(op, left, right) => write!(f, "({left} {op} {right})"),
+            Cond::Not(inner) => write!(f, "!({inner})"),
+            Cond::Logic(op, left, right) => write!(f, "({left} {op} {right})"),
+        }
+    }
+}
+
+impl Case {
+    fn source(&self) -> String {
+        let mut counters = BTreeSet::new();
+        let mut body = String::new();
+        render_block(&self.body, 0, &mut body, &mut counters);
+
+        let mut out = String::new();
+        for (index, value) in self.inits.iter().enumerate() {
+            out.push_str(&format!("let v{index} = {value};\n"));
+        }
+        for counter in counters {
+            out.push_str(&format!("let c{counter} = 0;\n"));
+        }
+        out.push_str(&body);
+        match &self.tail {
+            Tail::Value(expr) => out.push_str(&expr.to_string()),
+            Tail::Test(cond) => out.push_str(&cond.to_string()),
+        }
+        out
+    }
+}
+
+fn render_block(stmts: &[Stmt], indent: usize, out: &mut String, counters: &mut BTreeSet<usize>) {
+    let pad = "    ".repeat(indent);
+    for stmt in stmts {
+        match stmt {
+            Stmt::Assign(var, expr) => out.push_str(&format!("{pad}v{var} = {expr} % 1000;\n")),
+            Stmt::If(cond, then_branch, else_branch) => {
+                out.push_str(&format!("{pad}if {cond} {{\n"));
+                render_block(then_branch, indent + 1, out, counters);
+                if else_branch.is_empty() {
+                    out.push_str(&format!("{pad}}}\n"));
+                } else {
+                    out.push_str(&format!("{pad}}} else {{\n"));
+                    render_block(else_branch, indent + 1, out, counters);
+                    out.push_str(&format!("{pad}}}\n"));
+                }
+            }
+            Stmt::While {
+                counter,
+                limit,
+                body,
+            } => {
+                counters.insert(*counter);
+                out.push_str(&format!("{pad}c{counter} = 0;\n"));
+                out.push_str(&format!("{pad}while c{counter} < {limit} {{\n"));
+                render_block(body, indent + 1, out, counters);
+                out.push_str(&format!("{pad}    c{counter} = c{counter} + 1;\n"));
+                out.push_str(&format!("{pad}}}\n"));
+            }
+        }
+    }
+}
+
+//=============================================
+//            Generation
+//=============================================
+struct Generator {
+    rng: StdRng,
+    counters: usize,
+}
+
+impl Generator {
+    fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+            counters: 0,
+        }
+    }
+
+    fn case(&mut self) -> Case {
+        self.counters = 0;
+        let inits = [(); VARS].map(|_| self.rng.gen_range(0..10));
+        let count = self.rng.gen_range(1..=4);
+        let body = self.block(BLOCK_DEPTH, count);
+        let tail = if self.rng.gen_bool(0.7) {
+            Tail::Value(self.expr(EXPR_DEPTH))
+        } else {
+            Tail::Test(self.cond(COND_DEPTH))
+        };
+        Case { inits, body, tail }
+    }
+
+    fn pick(&mut self, ops: &[&'static str]) -> &'static str {
+        ops[self.rng.gen_range(0..ops.len())]
+    }
+
+    fn expr(&mut self, depth: usize) -> Expr {
+        if depth == 0 || self.rng.gen_bool(0.3) {
+            return if self.rng.gen_bool(0.5) {
+                Expr::Int(self.rng.gen_range(0..10))
+            } else {
+                Expr::Var(self.rng.gen_range(0..VARS))
+            };
+        }
+        if self.rng.gen_bool(0.2) {
+            return Expr::Neg(Box::new(self.expr(depth - 1)));
+        }
+        let op = self.pick(ARITH_OPS);
+        Expr::Binary(
+            op,
+            Box::new(self.expr(depth - 1)),
+            Box::new(self.expr(depth - 1)),
+        )
+    }
+
+    fn cond(&mut self, depth: usize) -> Cond {
+        if depth == 0 || self.rng.gen_bool(0.5) {
+            let op = self.pick(COMPARE_OPS);
+            return Cond::Compare(op, self.expr(EXPR_DEPTH), self.expr(EXPR_DEPTH));
+        }
+        match self.rng.gen_range(0..4) {
+            0 => Cond::Literal(self.rng.gen_bool(0.5)),
+            1 => Cond::Not(Box::new(self.cond(depth - 1))),
+            other => Cond::Logic(
+                if other == 2 { "&&" } else { "||" },
+                Box::new(self.cond(depth - 1)),
+                Box::new(self.cond(depth - 1)),
+            ),
+        }
+    }
+
+    fn block(&mut self, depth: usize, count: usize) -> Vec<Stmt> {
+        (0..count).map(|_| self.stmt(depth)).collect()
+    }
+
+    fn stmt(&mut self, depth: usize) -> Stmt {
+        let kind = if depth == 0 {
+            2
+        } else {
+            self.rng.gen_range(0..5)
+        };
+        match kind {
+            0 => {
+                let cond = self.cond(COND_DEPTH);
+                let count = self.rng.gen_range(1..=3);
+                let then_branch = self.block(depth - 1, count);
+                let else_branch = if self.rng.gen_bool(0.5) {
+                    let count = self.rng.gen_range(1..=3);
+                    self.block(depth - 1, count)
+                } else {
+                    Vec::new()
+                };
+                Stmt::If(cond, then_branch, else_branch)
+            }
+            1 => {
+                let counter = self.counters;
+                self.counters += 1;
+                let limit = self.rng.gen_range(0..4);
+                let count = self.rng.gen_range(1..=3);
+                let body = self.block(depth - 1, count);
+                Stmt::While {
+                    counter,
+                    limit,
+                    body,
+                }
+            }
+            _ => Stmt::Assign(self.rng.gen_range(0..VARS), self.expr(EXPR_DEPTH)),
+        }
+    }
+}
+
+//=============================================
+//            Shrinking
+//=============================================
+/// Greedily replace `case` with the first strictly smaller variant that
+/// still satisfies `failing`, until no variant does.
+fn shrink(case: Case, failing: impl Fn(&Case) -> bool) -> Case {
+    let mut best = case;
+    'search: loop {
+        for candidate in case_shrinks(&best) {
+            if failing(&candidate) {
+                best = candidate;
+                continue 'search;
+            }
+        }
+        return best;
+    }
+}
+
+fn case_shrinks(case: &Case) -> Vec<Case> {
+    let mut out = Vec::new();
+    for body in block_shrinks(&case.body) {
+        out.push(Case {
+            body,
+            ..case.clone()
+        });
+    }
+    let tails: Vec<Tail> = match &case.tail {
+        Tail::Value(expr) => expr_shrinks(expr).into_iter().map(Tail::Value).collect(),
+        Tail::Test(cond) => cond_shrinks(cond).into_iter().map(Tail::Test).collect(),
+    };
+    for tail in tails {
+        out.push(Case {
+            tail,
+            ..case.clone()
+        });
+    }
+    for (index, value) in case.inits.iter().enumerate() {
+        if *value != 0 {
+            let mut smaller = case.clone();
+            smaller.inits[index] = 0;
+            out.push(smaller);
+        }
+    }
+    out
+}
+
+fn block_shrinks(stmts: &[Stmt]) -> Vec<Vec<Stmt>> {
+    let mut out = Vec::new();
+    for (index, stmt) in stmts.iter().enumerate() {
+        let replace = |with: Vec<Stmt>| {
+            let mut block = stmts[..index].to_vec();
+            block.extend(with);
+            block.extend_from_slice(&stmts[index + 1..]);
+            block
+        };
+        out.push(replace(Vec::new()));
+        match stmt {
+            Stmt::Assign(var, expr) => {
+                for expr in expr_shrinks(expr) {
+                    out.push(replace(vec![Stmt::Assign(*var, expr)]));
+                }
+            }
+            Stmt::If(cond, then_branch, else_branch) => {
+                out.push(replace(then_branch.clone()));
+                out.push(replace(else_branch.clone()));
+                for cond in cond_shrinks(cond) {
+                    out.push(replace(vec![Stmt::If(
+                        cond,
+                        then_branch.clone(),
+                        else_branch.clone(),
+                    )]));
+                }
+                for branch in block_shrinks(then_branch) {
+                    out.push(replace(vec![Stmt::If(
+                        cond.clone(),
+                        branch,
+                        else_branch.clone(),
+                    )]));
+                }
+                for branch in block_shrinks(else_branch) {
+                    out.push(replace(vec![Stmt::If(
+                        cond.clone(),
+                        then_branch.clone(),
+                        branch,
+                    )]));
+                }
+            }
+            Stmt::While {
+                counter,
+                limit,
+                body,
+            } => {
+                out.push(replace(body.clone()));
+                if *limit > 0 {
+                    out.push(replace(vec![Stmt::While {
+                        counter: *counter,
+                        limit: limit - 1,
+                        body: body.clone(),
+                    }]));
+                }
+                for body in block_shrinks(body) {
+                    out.push(replace(vec![Stmt::While {
+                        counter: *counter,
+                        limit: *limit,
+                        body,
+                    }]));
+                }
+            }
+        }
+    }
+    out
+}
+
+fn expr_shrinks(expr: &Expr) -> Vec<Expr> {
+    match expr {
+        Expr::Int(0) => Vec::new(),
+        Expr::Int(_) | Expr::Var(_) => vec![Expr::Int(0)],
+        Expr::Neg(inner) => {
+            let mut out = vec![(**inner).clone()];
+            out.extend(
+                expr_shrinks(inner)
+                    .into_iter()
+                    .map(|inner| Expr::Neg(Box::new(inner))),
+            );
+            out
+        }
+        Expr::Binary(op, left, right) => {
+            let mut out = vec![(**left).clone(), (**right).clone()];
+            for left in expr_shrinks(left) {
+                out.push(Expr::Binary(*op, Box::new(left), right.clone()));
+            }
+            for right in expr_shrinks(right) {
+                out.push(Expr::Binary(*op, left.clone(), Box::new(right)));
+            }
+            out
+        }
+    }
+}
+
+fn cond_shrinks(cond: &Cond) -> Vec<Cond> {
+    match cond {
+        Cond::Literal(_) => Vec::new(),
+        Cond::Compare(op, left, right) => {
+            let mut out = vec![Cond::Literal(false), Cond::Literal(true)];
+            for left in expr_shrinks(left) {
+                out.push(Cond::Compare(*op, left, right.clone()));
+            }
+            for right in expr_shrinks(right) {
+                out.push(Cond::Compare(*op, left.clone(), right));
+            }
+            out
+        }
+        Cond::Not(inner) => {
+            let mut out = vec![(**inner).clone()];
+            out.extend(
+                cond_shrinks(inner)
+                    .into_iter()
+                    .map(|inner| Cond::Not(Box::new(inner))),
+            );
+            out
+        }
+        Cond::Logic(op, left, right) => {
+            let mut out = vec![(**left).clone(), (**right).clone()];
+            for left in cond_shrinks(left) {
+                out.push(Cond::Logic(*op, Box::new(left), right.clone()));
+            }
+            for right in cond_shrinks(right) {
+                out.push(Cond::Logic(*op, left.clone(), Box::new(right)));
+            }
+            out
+        }
+    }
+}
+
+//=============================================
+//            Differential Runs
+//=============================================
+fn normalize(result: BackendResult) -> Result<Outcome, Failure> {
+    result.map_err(|message| {
+        if message.to_lowercase().contains("by zero") {
+            Failure::DivisionByZero
+        } else {
+            Failure::Other(message)
+        }
+    })
+}
+
+fn diverges(case: &Case) -> bool {
+    let (interpreted, compiled) = run_both(&case.source());
+    normalize(interpreted) != normalize(compiled)
+}
+
+#[test]
+fn generated_programs_agree_on_both_backends() {
+    let mut generator = Generator::new(SEED);
+    for index in 0..CASES {
+        let case = generator.case();
+        if diverges(&case) {
+            let minimal = shrink(case, diverges);
+            let source = minimal.source();
+            let (interpreted, compiled) = run_both(&source);
+            panic!(
+                "backends diverge on generated case {index}; minimal counterexample:\n\
+                 {source}\ninterpreter: {interpreted:?}\nvm: {compiled:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn shrinking_finds_a_minimal_counterexample() {
+    // Stand-in for a backend bug: any program that divides fails.
+    let divides = |case: &Case| case.source().contains(" / ");
+    let case = Case {
+        inits: [3, 5, 7],
+        body: vec![Stmt::While {
+            counter: 0,
+            limit: 3,
+            body: vec![
+                Stmt::Assign(
+                    1,
+                    Expr::Binary("+", Box::new(Expr::Var(0)), Box::new(Expr::Int(4))),
+                ),
+                Stmt::If(
+                    Cond::Compare("<", Expr::Var(1), Expr::Int(9)),
+                    vec![Stmt::Assign(
+                        0,
+                        Expr::Binary("/", Box::new(Expr::Var(1)), Box::new(Expr::Int(2))),
+                    )],
+                    Vec::new(),
+                ),
+            ],
+        }],
+        tail: Tail::Value(Expr::Binary(
+            "*",
+            Box::new(Expr::Var(0)),
+            Box::new(Expr::Var(1)),
+        )),
+    };
+    assert!(divides(&case));
+
+    let minimal = shrink(case, divides);
+    assert_eq!(
+        minimal.source(),
+        "let v0 = 0;\nlet v1 = 0;\nlet v2 = 0;\nv0 = (0 / 0) % 1000;\n0"
+    );
+}
@@ -0,0 +1,114 @@
+//=============================================
+// solvra_script/vm/tests/jit_cache_tests.rs
+//=============================================
+// Purpose: Validate the Tier-0 code budget and its LRU eviction.
+//=============================================
+
+use std::sync::Arc;
+
+use crate::ir::ir::SolvraIrModule;
+use crate::ir::lowering::lower_program;
+use crate::parser::Parser;
+use crate::resolver::{Diagnostics, resolve_module};
+use crate::tokenizer::Tokenizer;
+use crate::vm::compiler as vm_compiler;
+use crate::vm::jit_cache::JitCodeCache;
+use crate::vm::runtime::{RuntimeOptions, run_bytecode};
+use solvra_core::Value;
+use solvra_core::jit::tier0_codegen::Tier0Compiler;
+use solvra_core::vm::bytecode::VmBytecode;
+
+/// Six same-sized functions that all turn hot in the same loop iteration.
+const SOURCE: &str = r#"
+fn f0(x) { return x + 0; }
+fn f1(x) { return x + 1; }
+fn f2(x) { return x + 2; }
+fn f3(x) { return x + 3; }
+fn f4(x) { return x + 4; }
+fn f5(x) { return x + 5; }
+
+fn main() {
+    let mut total = 0;
+    let mut i = 0;
+    while i < 60 {
+        total = total + f0(i) + f1(i) + f2(i) + f3(i) + f4(i) + f5(i);
+        i = i + 1;
+    }
+    return total;
+}
+"#;
+
+/// Sum over `i` in `0..60` of `6 * i + 15`.
+const EXPECTED: i64 = 11520;
+
+fn compile_program(source: &str) -> (Arc<VmBytecode>, SolvraIrModule) {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    let bytecode = vm_compiler::compile_program(&program).expect("compile program");
+    let vm_program = VmBytecode::decode(&bytecode[..]).expect("decode vm bytecode");
+    let mut diagnostics = Diagnostics::new();
+    let resolutions = resolve_module(&program, &mut diagnostics);
+    let module = lower_program(&program, &resolutions).expect("lower program");
+    (Arc::new(vm_program), module)
+}
+
+fn run_with_cache(cache: &JitCodeCache) -> Value {
+    let (program, module) = compile_program(SOURCE);
+    let options = RuntimeOptions {
+        jit_tier0: true,
+        jit_ir_module: Some(Arc::new(module)),
+        ..Default::default()
+    }
+    .with_jit_code_cache(cache.clone());
+    run_bytecode(program, options).expect("run program")
+}
+
+#[test]
+fn tiny_cap_evicts_least_recently_used_functions() {
+    let (_, module) = compile_program(SOURCE);
+    let f0 = module.function_by_name("f0").expect("f0 lowered");
+    let size = Tier0Compiler::new().compile(f0).listing.len();
+
+    // Room for two functions, so each of the last four admissions evicts one.
+    let cache = JitCodeCache::new(size * 2);
+    assert_eq!(run_with_cache(&cache), Value::Integer(EXPECTED));
+
+    let stats = cache.stats();
+    assert_eq!(stats.compiled, 6);
+    assert_eq!(stats.evictions, 4);
+    assert_eq!(stats.evicted_listing_bytes, size * 4);
+    assert_eq!(stats.resident_listing_bytes, size * 2);
+    assert_eq!(cache.resident_functions(), ["f4", "f5"]);
+    for name in ["f0", "f1", "f2", "f3"] {
+        assert!(cache.is_evicted(name), "{name} should run interpreted");
+    }
+}
+
+#[test]
+fn evicted_functions_are_compiled_again_after_the_cooldown() {
+    let (_, module) = compile_program(SOURCE);
+    let f0 = module.function_by_name("f0").expect("f0 lowered");
+    let size = Tier0Compiler::new().compile(f0).listing.len();
+
+    let cache = JitCodeCache::new(size * 2).with_readmit_after(5);
+    assert_eq!(run_with_cache(&cache), Value::Integer(EXPECTED));
+
+    let stats = cache.stats();
+    assert!(stats.readmitted > 0, "stats: {stats:?}");
+    assert_eq!(stats.compiled, 6 + stats.readmitted);
+    assert!(stats.resident_listing_bytes <= size * 2);
+}
+
+#[test]
+fn functions_larger_than_the_cap_are_never_cached() {
+    let cache = JitCodeCache::new(1);
+    assert_eq!(run_with_cache(&cache), Value::Integer(EXPECTED));
+
+    let stats = cache.stats();
+    assert_eq!(stats.compiled, 0);
+    assert_eq!(stats.rejected, 6);
+    assert_eq!(stats.resident_listing_bytes, 0);
+    assert!(cache.resident_functions().is_empty());
+}
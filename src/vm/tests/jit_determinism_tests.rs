@@ -0,0 +1,74 @@
+//=============================================
+// solvra_script/vm/tests/jit_determinism_tests.rs
+//=============================================
+// Purpose: Ensure JIT listings are identical across compilations.
+// Scope: this crate fixes the order of IR lowering, the regalloc dump and
+// the --jit-stats function list. Tier-0 code generation, the Tier-1 MIR
+// passes and the fused-IC snapshot live in solvra_core; the Tier-0
+// listing below is checked, but their internal ordering is not fixed
+// here. Fused-IC hit counts and execution counts in --jit-stats depend
+// on inputs and timing.
+//=============================================
+
+use crate::ir::ir::SolvraIrModule;
+use crate::ir::lowering::lower_program;
+use crate::parser::Parser;
+use crate::resolver::{Diagnostics, resolve_module};
+use crate::tokenizer::Tokenizer;
+use solvra_core::jit::tier0_codegen::Tier0Compiler;
+
+/// A loop carrying enough variables that hash ordering of the loop phis
+/// would show up between compilations.
+const SOURCE: &str = r#"
+fn mix(n) {
+    let mut a = 1;
+    let mut b = 2;
+    let mut c = 3;
+    let mut d = 4;
+    let mut e = 5;
+    let mut f = 6;
+    let mut i = 0;
+    while i < n {
+        a = a + b;
+        b = b + c;
+        c = c + d;
+        d = d + e;
+        e = e + f;
+        f = f + a;
+        i = i + 1;
+    }
+    return a + b + c + d + e + f;
+}
+
+fn main() {
+    return mix(10);
+}
+"#;
+
+fn lower(source: &str) -> SolvraIrModule {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    let mut diagnostics = Diagnostics::new();
+    let resolutions = resolve_module(&program, &mut diagnostics);
+    lower_program(&program, &resolutions).expect("lower program")
+}
+
+/// IR dump and Tier-0 listing of `mix` from a fresh lowering.
+fn listings() -> (String, String) {
+    let module = lower(SOURCE);
+    let mix = module.function_by_name("mix").expect("mix lowered");
+    (mix.dump(), Tier0Compiler::new().compile(mix).listing)
+}
+
+#[test]
+fn compiling_the_same_function_twice_yields_identical_listings() {
+    let (first_ir, first_tier0) = listings();
+    assert!(!first_tier0.is_empty());
+    for _ in 0..8 {
+        let (ir, tier0) = listings();
+        assert_eq!(ir, first_ir);
+        assert_eq!(tier0, first_tier0);
+    }
+}
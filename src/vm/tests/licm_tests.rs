@@ -0,0 +1,202 @@
+//=============================================
+// solvra_script/vm/tests/licm_tests.rs
+//=============================================
+// Purpose: Validate loop-invariant code motion.
+//=============================================
+
+use std::sync::Arc;
+
+use crate::parser::Parser;
+use crate::tokenizer::Tokenizer;
+use crate::vm::compiler::{self as vm_compiler, CompilerOptions};
+use crate::vm::runtime::{OverflowMode, RuntimeOptions, run_bytecode};
+use solvra_core::Value;
+use solvra_core::vm::bytecode::VmBytecode;
+use solvra_core::vm::instruction::{Instruction, Opcode};
+
+const INVARIANT_PRODUCT: &str = r#"
+fn main() {
+    let i = 0;
+    let total = 0;
+    let k = 6;
+    while i < 10 {
+        total = total + k * 7;
+        i = i + 1;
+    }
+    return total;
+}
+"#;
+
+fn compile(source: &str, licm: bool, fold_constants: bool) -> VmBytecode {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    let options = CompilerOptions {
+        licm,
+        fold_constants,
+        ..CompilerOptions::release()
+    };
+    let compiled =
+        vm_compiler::compile_program_with_options(&program, options).expect("compile program");
+    VmBytecode::decode(&compiled.bytecode[..]).expect("decode vm bytecode")
+}
+
+fn main_instructions(vm: &VmBytecode) -> &[Instruction] {
+    &vm.functions
+        .iter()
+        .find(|func| func.name == "main")
+        .expect("main function")
+        .instructions
+}
+
+/// Count `opcode` inside the loop, i.e. between the target of the backward
+/// jump and the jump itself, and outside it.
+fn count_in_and_out_of_loop(vm: &VmBytecode, opcode: Opcode) -> (usize, usize) {
+    let instructions = main_instructions(vm);
+    let (back_edge, loop_start) = instructions
+        .iter()
+        .enumerate()
+        .find(|(index, inst)| inst.opcode == Opcode::Jump && (inst.operand_a as usize) < *index)
+        .map(|(index, inst)| (index, inst.operand_a as usize))
+        .expect("loop back edge");
+    instructions
+        .iter()
+        .enumerate()
+        .filter(|(_, inst)| inst.opcode == opcode)
+        .fold((0, 0), |(inside, outside), (index, _)| {
+            if (loop_start..=back_edge).contains(&index) {
+                (inside + 1, outside)
+            } else {
+                (inside, outside + 1)
+            }
+        })
+}
+
+fn run(vm: VmBytecode, options: RuntimeOptions) -> Value {
+    run_bytecode(Arc::new(vm), options).expect("run program")
+}
+
+#[test]
+fn invariant_product_is_computed_before_the_loop() {
+    let hoisted = compile(INVARIANT_PRODUCT, true, true);
+    let plain = compile(INVARIANT_PRODUCT, false, true);
+
+    assert_eq!(count_in_and_out_of_loop(&hoisted, Opcode::Mul), (0, 1));
+    assert_eq!(count_in_and_out_of_loop(&plain, Opcode::Mul), (1, 0));
+    assert_eq!(run(hoisted, RuntimeOptions::default()), Value::Integer(420));
+    assert_eq!(run(plain, RuntimeOptions::default()), Value::Integer(420));
+}
+
+#[test]
+fn unfolded_constant_subexpression_is_evaluated_once() {
+    let source = r#"
+fn main() {
+    let i = 0;
+    let total = 0;
+    while i < 5 {
+        total = total + 2 * 3;
+        i = i + 1;
+    }
+    return total;
+}
+"#;
+    let hoisted = compile(source, true, false);
+    let plain = compile(source, false, false);
+
+    assert_eq!(count_in_and_out_of_loop(&hoisted, Opcode::Mul), (0, 1));
+    assert_eq!(count_in_and_out_of_loop(&plain, Opcode::Mul), (1, 0));
+    assert_eq!(run(hoisted, RuntimeOptions::default()), Value::Integer(30));
+    assert_eq!(run(plain, RuntimeOptions::default()), Value::Integer(30));
+}
+
+#[test]
+fn expressions_over_loop_variables_and_calls_stay_in_the_loop() {
+    let source = r#"
+fn three() {
+    return 3;
+}
+
+fn main() {
+    let i = 0;
+    let total = 0;
+    while i < 4 {
+        total = total + i * 2 + three() * 5;
+        i = i + 1;
+    }
+    return total;
+}
+"#;
+    let hoisted = compile(source, true, true);
+
+    assert_eq!(count_in_and_out_of_loop(&hoisted, Opcode::Mul), (2, 0));
+    assert_eq!(run(hoisted, RuntimeOptions::default()), Value::Integer(72));
+}
+
+#[test]
+fn invariants_are_not_evaluated_when_the_loop_never_runs() {
+    let source = r#"
+fn main() {
+    let big = 9223372036854775807;
+    let n = 0;
+    let total = 1;
+    while n > 0 {
+        total = big * big;
+        n = n - 1;
+    }
+    return total;
+}
+"#;
+    let options = RuntimeOptions::default().with_integer_overflow(OverflowMode::Error);
+    assert_eq!(run(compile(source, true, true), options), Value::Integer(1));
+}
+
+#[test]
+fn invariants_in_a_branch_that_never_runs_are_not_hoisted() {
+    let source = r#"
+fn main() {
+    let big = 9223372036854775807;
+    let i = 0;
+    let t = 1;
+    while i < 3 {
+        if i > 5 {
+            t = big * big;
+        }
+        i = i + 1;
+    }
+    return t;
+}
+"#;
+    let options = || RuntimeOptions::default().with_integer_overflow(OverflowMode::Error);
+    let hoisted = compile(source, true, true);
+
+    assert_eq!(count_in_and_out_of_loop(&hoisted, Opcode::Mul), (1, 0));
+    assert_eq!(run(hoisted, options()), Value::Integer(1));
+    assert_eq!(
+        run(compile(source, false, true), options()),
+        Value::Integer(1)
+    );
+}
+
+#[test]
+fn short_circuited_operands_are_not_hoisted() {
+    let source = r#"
+fn main() {
+    let big = 9223372036854775807;
+    let i = 0;
+    let t = 1;
+    while i < 3 {
+        if i > 5 && big * big > 0 {
+            t = 2;
+        }
+        i = i + 1;
+    }
+    return t;
+}
+"#;
+    let options = RuntimeOptions::default().with_integer_overflow(OverflowMode::Error);
+    let hoisted = compile(source, true, true);
+
+    assert_eq!(count_in_and_out_of_loop(&hoisted, Opcode::Mul), (1, 0));
+    assert_eq!(run(hoisted, options), Value::Integer(1));
+}
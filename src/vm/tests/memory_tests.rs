@@ -1,15 +1,20 @@
 //=============================================
 // solvra_script/vm/tests/memory_tests.rs
 //=============================================
-// Purpose: Validate VM memory behavior, allocator reuse, and scope reclamation.
+// Purpose: Validate VM memory behavior, allocator reuse, scope reclamation,
+//          memory-pressure callbacks, finalizers, and allocation-free
+//          integer arithmetic.
 //=============================================
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 use crate::parser::Parser;
 use crate::tokenizer::Tokenizer;
 use crate::vm::compiler as vm_compiler;
-use crate::vm::runtime::{MemoryTracker, RuntimeOptions, run_bytecode};
+use crate::vm::runtime::{HostObject, MemoryPressure, MemoryTracker, RuntimeOptions, run_bytecode};
+use solvra_core::Value;
 use solvra_core::vm::bytecode::{VmBytecode, VmConstant};
 
 fn compile_program(source: &str) -> Arc<VmBytecode> {
@@ -120,3 +125,207 @@ fn main() {
         "expected recursive calls to grow stack: {stats:?}"
     );
 }
+
+#[test]
+fn transient_objects_are_collected() {
+    let program = compile_program(
+        r#"
+fn main() {
+    let kept = { total: 0 };
+    let i = 0;
+    while i < 200 {
+        let temp = { value: i };
+        kept.total = kept.total + temp.value;
+        i = i + 1;
+    }
+    return kept.total;
+}
+"#,
+    );
+
+    let tracker = MemoryTracker::new();
+    let options = RuntimeOptions::default()
+        .with_memory_tracker(tracker.clone())
+        .with_gc_threshold(16);
+    let result = run_bytecode(program, options).expect("run program");
+    assert_eq!(result, Value::Integer(19900));
+
+    let stats = tracker.snapshot();
+    assert!(stats.collections >= 1, "expected a collection: {stats:?}");
+    assert!(stats.objects_freed > 0, "expected freed objects: {stats:?}");
+    assert!(
+        stats.peak_objects < 200,
+        "live objects should stay bounded: {stats:?}"
+    );
+}
+
+#[test]
+fn memory_pressure_callback_reports_live_usage() {
+    let program = compile_program(
+        r#"
+fn main() {
+    let items = [];
+    let i = 0;
+    while i < 100 {
+        items = push(items, { value: i });
+        i = i + 1;
+    }
+    return i;
+}
+"#,
+    );
+
+    let reports: Arc<Mutex<Vec<MemoryPressure>>> = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&reports);
+    let options = RuntimeOptions::default()
+        .with_gc_threshold(16)
+        .on_memory_pressure(0.75, move |usage| {
+            sink.lock().expect("reports mutex poisoned").push(*usage);
+        });
+    let result = run_bytecode(program, options).expect("run program");
+    assert_eq!(result, Value::Integer(100));
+
+    let reports = reports.lock().expect("reports mutex poisoned");
+    let first = reports.first().expect("expected a memory pressure report");
+    assert_eq!(first.budget, 16);
+    assert!(first.live_objects >= 16, "unexpected usage: {first:?}");
+    assert!(first.ratio > 0.75);
+    assert!(
+        reports
+            .windows(2)
+            .all(|w| w[0].live_objects <= w[1].live_objects),
+        "usage should grow while every object stays reachable: {reports:?}"
+    );
+}
+
+#[test]
+fn memory_pressure_callback_is_quiet_when_garbage_is_freed() {
+    let program = compile_program(
+        r#"
+fn main() {
+    let i = 0;
+    while i < 200 {
+        let temp = { value: i };
+        i = temp.value + 1;
+    }
+    return i;
+}
+"#,
+    );
+
+    let fired = Arc::new(Mutex::new(0));
+    let counter = Arc::clone(&fired);
+    let tracker = MemoryTracker::new();
+    let options = RuntimeOptions::default()
+        .with_memory_tracker(tracker.clone())
+        .with_gc_threshold(16)
+        .on_memory_pressure(0.75, move |_| {
+            *counter.lock().expect("counter mutex poisoned") += 1;
+        });
+    run_bytecode(program, options).expect("run program");
+
+    assert!(tracker.snapshot().collections >= 1);
+    assert_eq!(*fired.lock().expect("counter mutex poisoned"), 0);
+}
+
+#[test]
+fn host_object_finalizer_runs_once_after_collection() {
+    let program = compile_program(
+        r#"
+fn main() {
+    let handle = open_resource(7);
+    let id = handle.id;
+    handle = null;
+    let i = 0;
+    while i < 20 {
+        let temp = { value: i };
+        i = i + 1;
+    }
+    return id * 10 + finalized();
+}
+"#,
+    );
+
+    let finalized = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&finalized);
+    let open_resource = Arc::new(move |args: &[Value]| {
+        let counter = Arc::clone(&counter);
+        HostObject {
+            fields: HashMap::from([("id".to_string(), args[0].clone())]),
+            finalizer: Some(Box::new(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            })),
+        }
+    });
+    let observed = Arc::clone(&finalized);
+    let options = RuntimeOptions::default()
+        .with_gc_threshold(4)
+        .with_host_constructor("open_resource", open_resource)
+        .with_native_function(
+            "finalized",
+            Arc::new(move |_: &[Value]| Value::Integer(observed.load(Ordering::SeqCst) as i64)),
+        );
+    let result = run_bytecode(program, options).expect("run program");
+
+    // The finalizer already ran while the script was still executing.
+    assert_eq!(result, Value::Integer(71));
+    // Tearing the runtime down does not run it again.
+    assert_eq!(finalized.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn integer_loop_takes_the_fast_path_without_allocating() {
+    let program = compile_program(
+        r#"
+fn main() {
+    let i = 0;
+    let total = 0;
+    while i < 10000 {
+        total = total + i * 2 - i % 3;
+        i = i + 1;
+    }
+    return total;
+}
+"#,
+    );
+
+    let tracker = MemoryTracker::new();
+    let options = RuntimeOptions::default().with_memory_tracker(tracker.clone());
+    let result = run_bytecode(program, options).expect("run program");
+
+    let expected: i64 = (0..10000).map(|i: i64| i * 2 - i % 3).sum();
+    assert_eq!(result, Value::Integer(expected));
+    let stats = tracker.snapshot();
+    // Five arithmetic instructions per iteration, all on integers.
+    assert_eq!(stats.integer_fast_path_ops, 5 * 10000);
+    assert_eq!(
+        stats.objects_allocated, 0,
+        "unexpected allocations: {stats:?}"
+    );
+    assert_eq!(stats.collections, 0);
+}
+
+#[test]
+fn float_arithmetic_skips_the_integer_fast_path() {
+    let program = compile_program(
+        r#"
+fn main() {
+    let i = 0;
+    let total = 0.5;
+    while i < 10 {
+        total = total + 1.5;
+        i = i + 1;
+    }
+    return total;
+}
+"#,
+    );
+
+    let tracker = MemoryTracker::new();
+    let options = RuntimeOptions::default().with_memory_tracker(tracker.clone());
+    let result = run_bytecode(program, options).expect("run program");
+
+    assert_eq!(result, Value::Float(15.5));
+    // Only `i + 1` is integer arithmetic.
+    assert_eq!(tracker.snapshot().integer_fast_path_ops, 10);
+}
@@ -6,6 +6,19 @@
 
 mod async_tests;
 mod async_timeout_tests;
+mod call_depth_tests;
+mod compile_cache_tests;
+mod compiler_options_tests;
+mod const_fold_tests;
 mod control_flow_tests;
+mod differential_tests;
+mod jit_cache_tests;
+mod jit_determinism_tests;
+mod licm_tests;
 mod memory_tests;
+mod overflow_tests;
 mod parity_tests;
+mod session_tests;
+mod svc_tests;
+mod telemetry_call_tests;
+mod trace_tests;
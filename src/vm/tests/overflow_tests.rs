@@ -0,0 +1,71 @@
+//=============================================
+// solvra_script/vm/tests/overflow_tests.rs
+//=============================================
+// Purpose: Validate integer overflow modes for VM arithmetic.
+//=============================================
+
+use std::sync::Arc;
+
+use crate::parser::Parser;
+use crate::tokenizer::Tokenizer;
+use crate::vm::compiler as vm_compiler;
+use crate::vm::runtime::{OverflowMode, RuntimeOptions, run_bytecode};
+use solvra_core::vm::bytecode::VmBytecode;
+use solvra_core::{SolvraError, Value};
+
+const MAX_PLUS_ONE: &str = r#"
+fn main() {
+    let big = 9223372036854775807;
+    return big + 1;
+}
+"#;
+
+fn compile_program(source: &str) -> Arc<VmBytecode> {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    let bytecode = vm_compiler::compile_program(&program).expect("compile program");
+    let vm_program = VmBytecode::decode(&bytecode[..]).expect("decode vm bytecode");
+    Arc::new(vm_program)
+}
+
+fn run_with(mode: OverflowMode) -> Result<Value, SolvraError> {
+    let options = RuntimeOptions::default().with_integer_overflow(mode);
+    run_bytecode(compile_program(MAX_PLUS_ONE), options)
+}
+
+#[test]
+fn wrap_mode_wraps_to_min() {
+    assert_eq!(
+        run_with(OverflowMode::Wrap).unwrap(),
+        Value::Integer(i64::MIN)
+    );
+}
+
+#[test]
+fn default_mode_is_wrap() {
+    let result = run_bytecode(compile_program(MAX_PLUS_ONE), RuntimeOptions::default());
+    assert_eq!(result.unwrap(), Value::Integer(i64::MIN));
+}
+
+#[test]
+fn saturate_mode_clamps_to_max() {
+    assert_eq!(
+        run_with(OverflowMode::Saturate).unwrap(),
+        Value::Integer(i64::MAX)
+    );
+}
+
+#[test]
+fn error_mode_raises_runtime_exception() {
+    match run_with(OverflowMode::Error) {
+        Err(SolvraError::RuntimeException { message, .. }) => {
+            assert!(
+                message.contains("integer overflow"),
+                "unexpected message: {message}"
+            );
+        }
+        other => panic!("expected overflow exception, got {other:?}"),
+    }
+}
@@ -0,0 +1,163 @@
+//=============================================
+// solvra_script/vm/tests/session_tests.rs
+//=============================================
+// Purpose: Validate stepping, breakpoints, and watch expressions in debug sessions.
+//=============================================
+
+use std::sync::Arc;
+
+use crate::parser::Parser;
+use crate::tokenizer::Tokenizer;
+use crate::vm::compiler::{self as vm_compiler, CompilerOptions};
+use crate::vm::runtime::RuntimeOptions;
+use crate::vm::session::{Breakpoint, StepResult, VmSession};
+use solvra_core::Value;
+use solvra_core::vm::bytecode::VmBytecode;
+
+const PROGRAM: &str = r#"
+fn triple(n) {
+    let tripled = n * 3;
+    return tripled;
+}
+
+fn main() {
+    let x = 4;
+    let y = triple(x);
+    return x + y;
+}
+"#;
+
+fn start_session(source: &str) -> VmSession {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    let compiled = vm_compiler::compile_program_with_options(&program, CompilerOptions::debug())
+        .expect("compile program");
+    let vm = VmBytecode::decode(&compiled.bytecode[..]).expect("decode vm bytecode");
+    let options =
+        RuntimeOptions::default().with_debug_info(Arc::new(compiled.debug_info.expect("debug")));
+    VmSession::new(Arc::new(vm), options).expect("start session")
+}
+
+#[test]
+fn stepping_runs_the_program_one_instruction_at_a_time() {
+    let mut session = start_session(PROGRAM);
+    let start = session.location().expect("paused at entry");
+    assert_eq!(start.function, "main");
+    assert_eq!(start.ip, 0);
+
+    let mut steps = 0;
+    let mut entered_triple = false;
+    let result = loop {
+        match session.step() {
+            StepResult::Paused(location) => {
+                steps += 1;
+                entered_triple |= location.function == "triple";
+            }
+            StepResult::Finished(value) => break value,
+            StepResult::Error(error) => panic!("step failed: {error:?}"),
+        }
+    };
+    assert!(steps > 5);
+    assert!(entered_triple);
+    assert_eq!(result, Value::Integer(16));
+    assert!(session.location().is_none());
+    assert!(matches!(
+        session.step(),
+        StepResult::Finished(Value::Integer(16))
+    ));
+}
+
+#[test]
+fn breakpoint_pauses_with_locals_of_the_paused_frame() {
+    let mut session = start_session(PROGRAM);
+    session.set_breakpoint(Breakpoint::Line(4));
+
+    let location = match session.resume() {
+        StepResult::Paused(location) => location,
+        other => panic!("expected breakpoint, got {other:?}"),
+    };
+    assert_eq!(location.function, "triple");
+    assert_eq!(location.line, Some(4));
+    let frames: Vec<String> = session
+        .call_stack()
+        .into_iter()
+        .map(|frame| frame.function)
+        .collect();
+    assert_eq!(frames, ["main", "triple"]);
+    // `n` is the parameter in slot 0 and `tripled` the local in slot 1.
+    assert_eq!(session.locals()[0], Value::Integer(4));
+    assert_eq!(session.locals()[1], Value::Integer(12));
+
+    assert!(matches!(
+        session.resume(),
+        StepResult::Finished(Value::Integer(16))
+    ));
+}
+
+#[test]
+fn instruction_breakpoint_is_hit_on_every_pass() {
+    let source = r#"
+fn main() {
+    let i = 0;
+    while i < 3 {
+        i = i + 1;
+    }
+    return i;
+}
+"#;
+    // Any instruction of the loop body runs once per iteration.
+    let mut session = start_session(source);
+    let mut breakpoint = None;
+    while let StepResult::Paused(location) = session.step() {
+        if location.line == Some(5) {
+            breakpoint = Some(location.ip);
+        }
+    }
+    let ip = breakpoint.expect("loop body instruction");
+
+    let mut session = start_session(source);
+    session.set_breakpoint(Breakpoint::Instruction {
+        function: "main".into(),
+        ip,
+    });
+    let mut hits = 0;
+    let result = loop {
+        match session.resume() {
+            StepResult::Paused(location) => {
+                assert_eq!(location.ip, ip);
+                hits += 1;
+            }
+            StepResult::Finished(value) => break value,
+            StepResult::Error(error) => panic!("resume failed: {error:?}"),
+        }
+    };
+    assert_eq!(hits, 3);
+    assert_eq!(result, Value::Integer(3));
+}
+
+#[test]
+fn watch_expressions_read_the_paused_frame() {
+    let mut session = start_session(PROGRAM);
+    session.set_breakpoint(Breakpoint::Line(10));
+    assert!(matches!(session.resume(), StepResult::Paused(_)));
+
+    assert_eq!(session.evaluate("x + 1").expect("watch"), Value::Integer(5));
+    assert_eq!(
+        session.evaluate("x * y").expect("watch"),
+        Value::Integer(48)
+    );
+    assert_eq!(
+        session.evaluate("[x, y > 10]").expect("watch"),
+        Value::Array(vec![Value::Integer(4), Value::Boolean(true)])
+    );
+    assert!(session.evaluate("triple(x)").is_err());
+    assert!(session.evaluate("missing + 1").is_err());
+
+    // Evaluating must not disturb the run.
+    assert!(matches!(
+        session.resume(),
+        StepResult::Finished(Value::Integer(16))
+    ));
+}
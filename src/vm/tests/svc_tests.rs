@@ -0,0 +1,74 @@
+//=============================================
+// solvra_script/vm/tests/svc_tests.rs
+//=============================================
+// Purpose: Validate that malformed .svc payloads are rejected without panics.
+//=============================================
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::parser::Parser;
+use crate::tokenizer::Tokenizer;
+use crate::vm::compiler as vm_compiler;
+use crate::vm::svc::{SvcError, decode_svc};
+use solvra_core::vm::bytecode::VmBytecode;
+use solvra_core::vm::instruction::Opcode;
+
+fn compile_svc(source: &str) -> VmBytecode {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    let bytecode = vm_compiler::compile_program(&program).expect("compile program");
+    VmBytecode::decode(&bytecode[..]).expect("decode vm bytecode")
+}
+
+const LOOP: &str = r#"
+fn main() {
+    let i = 0;
+    while i < 3 {
+        i = i + 1;
+    }
+    return i;
+}
+"#;
+
+#[test]
+fn compiled_programs_round_trip() {
+    let encoded = bincode::serialize(&compile_svc(LOOP)).expect("encode svc");
+    let decoded = decode_svc(&encoded).expect("decode svc");
+    assert_eq!(decoded.functions.len(), 1);
+}
+
+#[test]
+fn random_bytes_are_rejected_without_panicking() {
+    let mut rng = StdRng::seed_from_u64(0x5eed);
+    for _ in 0..2000 {
+        let mut bytes = vec![0u8; rng.gen_range(0..96)];
+        rng.fill(&mut bytes[..]);
+        assert!(decode_svc(&bytes).is_err());
+    }
+}
+
+#[test]
+fn huge_length_prefix_is_rejected() {
+    let mut bytes = 1_000_000_000u64.to_le_bytes().to_vec();
+    bytes.extend([0, 0]);
+    assert!(matches!(decode_svc(&bytes), Err(SvcError::Malformed(_))));
+}
+
+#[test]
+fn out_of_range_jump_is_rejected() {
+    let mut program = compile_svc(LOOP);
+    let jump = program.functions[0]
+        .instructions
+        .iter_mut()
+        .find(|instruction| instruction.opcode == Opcode::Jump)
+        .expect("loop back edge");
+    jump.operand_a = 999;
+    let encoded = bincode::serialize(&program).expect("encode svc");
+
+    let err = decode_svc(&encoded).expect_err("jump past the end");
+    assert!(matches!(err, SvcError::Invalid { .. }), "{err:?}");
+    assert!(err.to_string().contains("jump target 0999"), "{err}");
+}
@@ -0,0 +1,63 @@
+//=============================================
+// solvra_script/vm/tests/telemetry_call_tests.rs
+//=============================================
+// Purpose: Validate per-function call telemetry and its summary.
+//=============================================
+
+use std::sync::Arc;
+
+use crate::parser::Parser;
+use crate::tokenizer::Tokenizer;
+use crate::vm::compiler as vm_compiler;
+use crate::vm::runtime::{RuntimeOptions, run_bytecode};
+use crate::vm::{FunctionStat, TelemetryCollector};
+use solvra_core::Value;
+use solvra_core::vm::bytecode::VmBytecode;
+
+fn compile_program(source: &str) -> Arc<VmBytecode> {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    let bytecode = vm_compiler::compile_program(&program).expect("compile program");
+    let vm_program = VmBytecode::decode(&bytecode[..]).expect("decode vm bytecode");
+    Arc::new(vm_program)
+}
+
+fn stat<'a>(summary: &'a [FunctionStat], name: &str) -> &'a FunctionStat {
+    summary
+        .iter()
+        .find(|stat| stat.name == name)
+        .unwrap_or_else(|| panic!("no summary entry for {name}: {summary:?}"))
+}
+
+#[test]
+fn call_summary_counts_calls_per_function() {
+    let program = compile_program(
+        r#"
+fn square(x) {
+    return x * x;
+}
+
+fn add(a, b) {
+    return a + b;
+}
+
+fn main() {
+    return add(square(2), square(3));
+}
+"#,
+    );
+    let collector = TelemetryCollector::new();
+    let options = RuntimeOptions::default().with_telemetry_collector(collector.clone());
+    let result = run_bytecode(program, options).expect("run program");
+    assert_eq!(result, Value::Integer(13));
+
+    let summary = collector.call_summary();
+    assert_eq!(stat(&summary, "square").calls, 2);
+    assert_eq!(stat(&summary, "add").calls, 1);
+    assert_eq!(stat(&summary, "main").calls, 1);
+    // `main` encloses every other call, so it is never cheaper than them.
+    assert!(stat(&summary, "main").total_nanos >= stat(&summary, "square").total_nanos);
+    assert_eq!(summary[0].name, "main");
+}
@@ -0,0 +1,60 @@
+//=============================================
+// solvra_script/vm/tests/trace_tests.rs
+//=============================================
+// Purpose: Validate that trace filters scope opcode tracing by function.
+//=============================================
+
+use std::sync::{Arc, Mutex};
+
+use crate::parser::Parser;
+use crate::tokenizer::Tokenizer;
+use crate::vm::compiler as vm_compiler;
+use crate::vm::runtime::{RuntimeOptions, TraceFilter, run_bytecode};
+use solvra_core::Value;
+use solvra_core::vm::bytecode::VmBytecode;
+
+fn compile_program(source: &str) -> Arc<VmBytecode> {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize script");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    let bytecode = vm_compiler::compile_program(&program).expect("compile program");
+    let vm_program = VmBytecode::decode(&bytecode[..]).expect("decode vm bytecode");
+    Arc::new(vm_program)
+}
+
+#[test]
+fn trace_filter_records_only_matching_functions() {
+    let program = compile_program(
+        r#"
+fn helper(x) {
+    return x * 2;
+}
+
+fn main() {
+    return helper(3) + 1;
+}
+"#,
+    );
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    let sink = lines.clone();
+    let options = RuntimeOptions::default()
+        .with_trace_filter(TraceFilter::new("helper").expect("valid filter"))
+        .with_trace_hook(move |line| sink.lock().unwrap().push(line.to_string()));
+    let result = run_bytecode(program, options).expect("run program");
+    assert_eq!(result, Value::Integer(7));
+
+    let lines = lines.lock().unwrap();
+    assert!(!lines.is_empty(), "helper's opcodes should be traced");
+    for line in lines.iter() {
+        assert!(line.starts_with("[TRACE] [frame=helper]"), "{line}");
+    }
+}
+
+#[test]
+fn trace_filter_matches_whole_function_names() {
+    let filter = TraceFilter::new("help").expect("valid filter");
+    assert!(!filter.matches("helper"));
+    assert!(TraceFilter::new("help.*").unwrap().matches("helper"));
+    assert!(TraceFilter::new("(").is_err());
+}
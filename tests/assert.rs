@@ -0,0 +1,89 @@
+//==============================================
+// File: tests/assert.rs
+// Author: Codex
+// License: Duality Public License (DPL v1.0)
+// Goal: Exercise the assert builtins
+// Objective: Check failure messages, stack traces, and passing assertions
+//==============================================
+
+use solvrascript::{
+    interpreter::{Interpreter, RuntimeError, Value},
+    parser::Parser,
+    tokenizer::Tokenizer,
+};
+
+fn run_source(source: &str) -> Result<Value, RuntimeError> {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize source");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    let mut interpreter = Interpreter::new();
+    interpreter
+        .eval_program(&program)
+        .map(|value| value.unwrap_or(Value::Null))
+}
+
+fn assertion_failure(source: &str) -> (String, Vec<String>) {
+    match run_source(source) {
+        Err(RuntimeError::AssertionFailed { message, stack }) => (message, stack),
+        other => panic!("expected an assertion failure, got {other:?}"),
+    }
+}
+
+#[test]
+fn passing_assertions_return_null() {
+    assert_eq!(run_source("assert(1 < 2)").unwrap(), Value::Null);
+    assert_eq!(run_source(r#"assert_eq("a", "a")"#).unwrap(), Value::Null);
+}
+
+#[test]
+fn assert_eq_reports_both_values_and_the_callers() {
+    let source = r#"
+        fn check(value) {
+            assert_eq(value, "two");
+        }
+        fn run() {
+            check(1);
+        }
+        run();
+    "#;
+    let (message, stack) = assertion_failure(source);
+    assert_eq!(message, r#"1 != "two""#);
+    assert_eq!(stack, vec!["check", "run", "<main>"]);
+}
+
+#[test]
+fn assert_uses_the_supplied_message() {
+    let (message, stack) = assertion_failure(r#"assert(false, "totals must match")"#);
+    assert_eq!(message, "totals must match");
+    assert_eq!(stack, vec!["<main>"]);
+
+    let (message, _) = assertion_failure("assert(0)");
+    assert_eq!(message, "expected a truthy value, got 0");
+}
+
+#[test]
+fn assertion_failures_display_the_trace() {
+    let err = run_source("fn f() { assert_eq([1, 2], [1, 3], \"lists\"); } f();").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Assertion failed: lists: [1, 2] != [1, 3]\n    at f\n    at <main>"
+    );
+}
+
+#[test]
+fn assertion_failures_have_their_own_error_code() {
+    let err = run_source("assert(false)").unwrap_err();
+    assert_eq!(err.code(), "E006");
+    assert_ne!(err.code(), RuntimeError::StackOverflow.code());
+}
+
+#[test]
+fn an_explicit_main_is_reported_by_name() {
+    let (_, stack) = assertion_failure("fn main() { assert(false); } main();");
+    assert_eq!(stack, vec!["main"]);
+}
+
+//==============================================
+// End of file
+//==============================================
@@ -44,6 +44,177 @@ fn cli_compiles_and_runs_svc() {
     }
 }
 
+#[test]
+fn cli_disassembles_svc() {
+    let dir = tempdir().expect("tempdir");
+    let source_path = dir.path().join("main.svs");
+    let output_path = dir.path().join("main.svc");
+
+    fs::write(
+        &source_path,
+        "fn double(n) { return n * 2; }\nfn main() { return double(21); }\n",
+    )
+    .expect("write source");
+
+    let status = Command::new(env!("CARGO_BIN_EXE_solvrascript"))
+        .args([
+            "compile",
+            source_path.to_str().unwrap(),
+            "-o",
+            output_path.to_str().unwrap(),
+        ])
+        .status()
+        .expect("run compile");
+    assert!(status.success(), "compile command failed");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_solvrascript"))
+        .args(["disassemble", output_path.to_str().unwrap()])
+        .output()
+        .expect("run disassemble");
+    assert!(output.status.success(), "disassemble command failed");
+    let listing = String::from_utf8_lossy(&output.stdout);
+    assert!(listing.contains("fn main"), "listing: {listing}");
+    assert!(listing.contains("fn double"), "listing: {listing}");
+    assert!(listing.contains("Call"), "listing: {listing}");
+    assert!(listing.contains("constants"), "listing: {listing}");
+}
+
+#[test]
+fn cli_disassemble_reports_malformed_svc() {
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("broken.svc");
+    fs::write(&path, [0xff; 10]).expect("write broken svc");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_solvrascript"))
+        .args(["disassemble", path.to_str().unwrap()])
+        .output()
+        .expect("run disassemble");
+    assert!(!output.status.success(), "disassemble should fail");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("not a valid .svc file"), "stderr: {stderr}");
+    assert!(!stderr.contains("panicked"), "stderr: {stderr}");
+}
+
+#[test]
+fn cli_emits_cfg_as_dot() {
+    let dir = tempdir().expect("tempdir");
+    let source_path = dir.path().join("main.svs");
+    fs::write(
+        &source_path,
+        "fn pick(flag) { if flag { return 1; } return 2; }\nfn main() { return pick(true); }\n",
+    )
+    .expect("write source");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_solvrascript"))
+        .args(["run", source_path.to_str().unwrap(), "--emit-cfg"])
+        .output()
+        .expect("run --emit-cfg");
+    assert!(output.status.success(), "--emit-cfg failed");
+    let dot = String::from_utf8_lossy(&output.stdout);
+    assert!(dot.contains("digraph \"pick\" {"), "dot: {dot}");
+    assert!(dot.contains("digraph \"main\" {"), "dot: {dot}");
+    assert!(dot.contains("bb0 [label=\"entry\"];"), "dot: {dot}");
+    assert!(dot.contains("bb0 -> bb1 [label=\"%"), "dot: {dot}");
+    assert!(dot.contains("bb0 -> bb2 [label=\"!%"), "dot: {dot}");
+}
+
+#[test]
+fn cli_jit_listings_are_identical_across_runs() {
+    let dir = tempdir().expect("tempdir");
+    let source_path = dir.path().join("main.svs");
+    fs::write(
+        &source_path,
+        r#"
+fn mix(n) {
+    let mut a = 1;
+    let mut b = 2;
+    let mut c = 3;
+    let mut i = 0;
+    while i < n {
+        a = a + b;
+        b = b + c;
+        c = c + a;
+        i = i + 1;
+    }
+    return a + b + c;
+}
+fn main() { return mix(4); }
+"#,
+    )
+    .expect("write source");
+
+    for flags in [
+        &["--emit-tier0"][..],
+        &["--emit-mir", "--emit-regalloc"][..],
+    ] {
+        let run = || {
+            let output = Command::new(env!("CARGO_BIN_EXE_solvrascript"))
+                .arg("run")
+                .arg(&source_path)
+                .args(flags)
+                .output()
+                .expect("run listing");
+            assert!(output.status.success(), "{flags:?} failed");
+            output.stdout
+        };
+        let first = run();
+        assert!(!first.is_empty(), "{flags:?} printed nothing");
+        for _ in 0..3 {
+            assert_eq!(run(), first, "{flags:?} listing changed between runs");
+        }
+    }
+}
+
+#[test]
+fn cli_reports_memory_stats_when_the_script_fails() {
+    let dir = tempdir().expect("tempdir");
+    let source_path = dir.path().join("main.svs");
+    fs::write(
+        &source_path,
+        "fn main() { let items = [1, 2, 3]; return magic_missing_builtin(items); }\n",
+    )
+    .expect("write source");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_solvrascript"))
+        .args(["run", source_path.to_str().unwrap(), "--memory-stats"])
+        .output()
+        .expect("run --memory-stats");
+    assert!(!output.status.success(), "script should fail");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\"memory_stats\""), "stdout: {stdout}");
+}
+
+#[test]
+fn cli_telemetry_lists_the_hottest_functions() {
+    let dir = tempdir().expect("tempdir");
+    let source_path = dir.path().join("main.svs");
+    fs::write(
+        &source_path,
+        "fn square(n) { return n * n; }\n\
+         fn main() { let mut i = 0; while i < 3 { square(i); i = i + 1; } return 0; }\n",
+    )
+    .expect("write source");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_solvrascript"))
+        .args(["run", source_path.to_str().unwrap(), "--telemetry"])
+        .output()
+        .expect("run --telemetry");
+    assert!(output.status.success(), "script should succeed");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\"call_summary\""), "stdout: {stdout}");
+    assert!(
+        stdout.contains(r#"{"calls":3,"name":"square""#)
+            || stdout.contains(r#""name":"square","calls":3"#),
+        "stdout: {stdout}"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("[telemetry] hottest functions:"),
+        "stderr: {stderr}"
+    );
+    assert!(stderr.contains("fn=square calls=3"), "stderr: {stderr}");
+}
+
 //=====================================================
 // End of file
 //=====================================================
@@ -0,0 +1,83 @@
+//==============================================
+// File: tests/coverage.rs
+// Author: Codex
+// License: Duality Public License (DPL v1.0)
+// Goal: Exercise interpreter line coverage
+// Objective: Check recorded hits and their LCOV serialization
+//==============================================
+
+use std::path::Path;
+
+use solvrascript::{interpreter::Interpreter, parser::Parser, tokenizer::Tokenizer};
+
+const SOURCE: &str = "fn used() {
+    return 1;
+}
+fn unused() {
+    return 2;
+}
+used();
+used();
+";
+
+#[test]
+fn small_report_serializes_to_lcov() {
+    let mut tokenizer = Tokenizer::new(SOURCE);
+    let tokens = tokenizer.tokenize().expect("tokenize source");
+    let program = Parser::new(tokens).parse().expect("parse program");
+    let mut interpreter = Interpreter::new();
+    interpreter.enable_coverage();
+    interpreter
+        .eval_program_with_origin(&program, Some(Path::new("sample.svs")))
+        .expect("execution");
+
+    let report = interpreter.take_coverage();
+    let file = report
+        .file(Path::new("sample.svs"))
+        .expect("sample.svs coverage");
+    assert_eq!(file.hit_count(), 5);
+    assert_eq!(file.lines.len(), 6);
+    assert_eq!(
+        report.to_lcov(),
+        "TN:\nSF:sample.svs\nDA:1,1\nDA:2,2\nDA:4,1\nDA:5,0\nDA:7,1\nDA:8,1\nLH:5\nLF:6\nend_of_record\n"
+    );
+    assert_eq!(
+        report.summary(),
+        "sample.svs: 5/6 lines (83.3%)\ntotal: 5/6 lines (83.3%)\n"
+    );
+}
+
+#[test]
+fn top_level_code_does_not_count_the_synthesized_main() {
+    let source = "// sample\n\nlet total = 1 + 2;\ntotal;\n";
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize source");
+    let program = Parser::new(tokens).parse().expect("parse program");
+    assert!(program.implicit_entry);
+    let mut interpreter = Interpreter::new();
+    interpreter.enable_coverage();
+    interpreter
+        .eval_program_with_origin(&program, Some(Path::new("top.svs")))
+        .expect("execution");
+
+    assert_eq!(
+        interpreter.take_coverage().to_lcov(),
+        "TN:\nSF:top.svs\nDA:3,1\nDA:4,1\nLH:2\nLF:2\nend_of_record\n"
+    );
+}
+
+#[test]
+fn coverage_is_empty_unless_enabled() {
+    let mut tokenizer = Tokenizer::new(SOURCE);
+    let tokens = tokenizer.tokenize().expect("tokenize source");
+    let program = Parser::new(tokens).parse().expect("parse program");
+    let mut interpreter = Interpreter::new();
+    interpreter
+        .eval_program_with_origin(&program, Some(Path::new("sample.svs")))
+        .expect("execution");
+    assert_eq!(interpreter.take_coverage().to_lcov(), "");
+}
+
+//==============================================
+// End of file
+//==============================================
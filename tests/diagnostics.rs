@@ -0,0 +1,168 @@
+//=====================================================
+// File: solvra_script/tests/diagnostics.rs
+//=====================================================
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Shared diagnostics regression tests
+// Objective: Ensure library and CLI diagnostics agree on codes and ranges
+//=====================================================
+
+use std::fs;
+use std::process::Command;
+
+use serde_json::Value as JsonValue;
+use solvrascript::ast::Span;
+use solvrascript::diagnostics::{self, Diagnostic, Severity};
+use solvrascript::tokenizer::Position;
+use tempfile::tempdir;
+
+const MALFORMED: &str = "fn main() {\n    let value = (1 + 2;\n}\n";
+
+fn cli_diagnostics(source: &str) -> JsonValue {
+    let dir = tempdir().expect("tempdir");
+    let script = dir.path().join("broken.svs");
+    fs::write(&script, source).expect("write source");
+    let output = Command::new(env!("CARGO_BIN_EXE_solvrascript"))
+        .args(["run", script.to_str().unwrap(), "--format", "json"])
+        .output()
+        .expect("run cli");
+    assert!(!output.status.success(), "malformed source should fail");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let line = stdout
+        .lines()
+        .find(|line| line.starts_with('{'))
+        .expect("json diagnostics on stdout");
+    serde_json::from_str(line).expect("valid json")
+}
+
+#[test]
+fn check_source_reports_parse_error() {
+    let diagnostics = diagnostics::check_source(MALFORMED);
+    assert_eq!(diagnostics.len(), 1);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.severity, Severity::Error);
+    assert_eq!(diagnostic.code, "E0001");
+    assert!(diagnostic.message.contains("RightParen"));
+}
+
+#[test]
+fn tokenizer_errors_carry_position() {
+    let diagnostics = diagnostics::check_source("let s = \"open");
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("Unterminated string"));
+    assert_eq!(diagnostics[0].range.start.line, 1);
+    assert!(diagnostics[0].range.start.column > 1);
+}
+
+#[test]
+fn missing_semicolon_offers_insert_fix() {
+    let diagnostics = diagnostics::check_source("let x = 1 let y = 2");
+    assert_eq!(diagnostics.len(), 1);
+    let fix = diagnostics[0].fixes.first().expect("quick fix");
+    assert_eq!(fix.title, "insert `;`");
+    assert_eq!(fix.edits.len(), 1);
+    let edit = &fix.edits[0];
+    assert_eq!(edit.new_text, ";");
+    assert_eq!(edit.range.start, edit.range.end);
+    assert_eq!(edit.range.start.line, 1);
+    // Directly after `1`, not at the start of the next `let`.
+    assert_eq!(edit.range.start.column, 10);
+}
+
+#[test]
+fn unclosed_brace_offers_insert_fix() {
+    let diagnostics = diagnostics::check_source("fn main() {\n    let a = 1;\n");
+    let fix = diagnostics[0].fixes.first().expect("quick fix");
+    assert_eq!(fix.edits[0].new_text, "}");
+}
+
+#[test]
+fn unrelated_errors_have_no_fix() {
+    let diagnostics = diagnostics::check_source("let = 3;");
+    assert!(diagnostics[0].fixes.is_empty());
+}
+
+#[test]
+fn cli_and_library_agree_on_codes_and_ranges() {
+    let library = diagnostics::to_json(&diagnostics::check_source(MALFORMED));
+    let cli = cli_diagnostics(MALFORMED);
+    assert_eq!(library["diagnostics"], cli["diagnostics"]);
+}
+
+#[test]
+fn columns_after_multibyte_characters_point_at_the_character() {
+    let source = "let s = \"äöü\"; let = 1;";
+    let diagnostics = diagnostics::check_source(source);
+    let start = &diagnostics[0].range.start;
+    assert_eq!(start.column, 20);
+    let byte = start.byte_offset(source);
+    assert_eq!(&source[byte..byte + 1], "=");
+}
+
+#[test]
+fn cap_errors_keeps_the_first_errors_and_every_warning() {
+    let range = || Span::new(Position::new(1, 1, 0), Position::new(1, 1, 0));
+    let mut items: Vec<Diagnostic> = (0..30)
+        .map(|index| Diagnostic::error("E001", format!("error {index}"), range()))
+        .collect();
+    items.insert(3, Diagnostic::warning("W001", "unused", range()));
+
+    let omitted = diagnostics::cap_errors(&mut items, 5);
+    assert_eq!(omitted, 25);
+    assert_eq!(items.len(), 6);
+    assert_eq!(items.iter().filter(|item| item.is_error()).count(), 5);
+    assert_eq!(
+        items.last().map(|item| item.message.as_str()),
+        Some("error 4")
+    );
+}
+
+#[test]
+fn recovery_reports_every_broken_statement() {
+    let source = "let = 1;\n".repeat(30);
+    let diagnostics = diagnostics::check_source(&source);
+    assert_eq!(diagnostics.len(), 30);
+    assert!(diagnostics.iter().all(|diagnostic| diagnostic.is_error()));
+}
+
+#[test]
+fn max_errors_caps_cli_output_with_summary() {
+    let dir = tempdir().expect("tempdir");
+    let script = dir.path().join("many.svs");
+    fs::write(&script, "let = 1;\n".repeat(30)).expect("write source");
+    let output = Command::new(env!("CARGO_BIN_EXE_solvrascript"))
+        .args(["run", script.to_str().unwrap(), "--max-errors", "5"])
+        .output()
+        .expect("run cli");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let reported = stderr
+        .lines()
+        .filter(|line| line.contains(": error["))
+        .count();
+    assert_eq!(reported, 5, "stderr: {stderr}");
+    assert!(stderr.contains("… and 25 more"), "stderr: {stderr}");
+    assert!(
+        stderr.contains("aborting due to 30 errors"),
+        "stderr: {stderr}"
+    );
+}
+
+#[test]
+fn max_errors_rejects_zero() {
+    let dir = tempdir().expect("tempdir");
+    let script = dir.path().join("broken.svs");
+    fs::write(&script, "let = 1;\n").expect("write source");
+    let output = Command::new(env!("CARGO_BIN_EXE_solvrascript"))
+        .args(["run", script.to_str().unwrap(), "--max-errors", "0"])
+        .output()
+        .expect("run cli");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--max-errors"), "stderr: {stderr}");
+    assert!(!stderr.contains("aborting due to"), "stderr: {stderr}");
+}
+
+//=====================================================
+// End of file
+//=====================================================
@@ -0,0 +1,40 @@
+use solvra_core::Value;
+use solvrascript::core_bridge::Embedder;
+
+#[test]
+fn scripts_call_registered_native_functions() {
+    let mut embedder = Embedder::new();
+    embedder.register_native_fn(
+        "host_add",
+        Box::new(|args: &[Value]| match args {
+            [Value::Integer(a), Value::Integer(b)] => Value::Integer(a + b),
+            _ => Value::Null,
+        }),
+    );
+
+    let program = embedder
+        .compile(
+            r#"
+fn main() {
+    let total = host_add(40, 2);
+    return host_add(total, 100);
+}
+"#,
+        )
+        .expect("compile script");
+    assert_eq!(
+        embedder.run(&program).expect("run script"),
+        Value::Integer(142)
+    );
+    // A compiled program can be run again.
+    assert_eq!(
+        embedder.run(&program).expect("run script"),
+        Value::Integer(142)
+    );
+}
+
+#[test]
+fn invalid_source_fails_to_compile() {
+    let embedder = Embedder::new();
+    assert!(embedder.compile("fn main( {").is_err());
+}
@@ -0,0 +1,80 @@
+//==============================================
+// File: tests/encoding.rs
+// Author: Codex
+// License: Duality Public License (DPL v1.0)
+// Goal: Exercise the base64 and hex builtins
+// Objective: Round-trip text and byte arrays and reject malformed input
+//==============================================
+
+use solvrascript::{
+    interpreter::{Interpreter, RuntimeError, Value},
+    parser::Parser,
+    tokenizer::Tokenizer,
+};
+
+fn run_source(source: &str) -> Result<Value, RuntimeError> {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize source");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    let mut interpreter = Interpreter::new();
+    interpreter
+        .eval_program(&program)
+        .map(|value| value.unwrap_or(Value::Null))
+}
+
+fn eval_source(source: &str) -> Value {
+    run_source(source).expect("execution")
+}
+
+#[test]
+fn base64_matches_rfc_vectors_and_round_trips() {
+    let source = r#"
+        base64_encode("") == ""
+            && base64_encode("f") == "Zg=="
+            && base64_encode("fo") == "Zm8="
+            && base64_encode("foobar") == "Zm9vYmFy"
+            && base64_decode(base64_encode("hello, world")) == "hello, world"
+    "#;
+    assert_eq!(eval_source(source), Value::Bool(true));
+}
+
+#[test]
+fn hex_round_trips_text_and_byte_arrays() {
+    let source = r#"
+        let bytes = hex_decode_bytes("DEADbeef");
+        hex_encode("foo") == "666f6f"
+            && hex_decode(hex_encode("hello")) == "hello"
+            && hex_encode([222, 173, 190, 239]) == "deadbeef"
+            && bytes[0] == 222 && bytes[3] == 239
+    "#;
+    assert_eq!(eval_source(source), Value::Bool(true));
+}
+
+#[test]
+fn base64_decode_bytes_keeps_binary_data() {
+    let source = r#"
+        let bytes = base64_decode_bytes(base64_encode([0, 255, 128]));
+        len(bytes) == 3 && bytes[0] == 0 && bytes[1] == 255 && bytes[2] == 128
+    "#;
+    assert_eq!(eval_source(source), Value::Bool(true));
+}
+
+#[test]
+fn malformed_input_raises_runtime_errors() {
+    for source in [
+        r#"base64_decode("Zm9v!A==")"#,
+        r#"base64_decode("abc")"#,
+        r#"base64_decode("Zg==Zg==")"#,
+        r#"hex_decode("abc")"#,
+        r#"hex_decode("zz")"#,
+        r#"hex_encode([256])"#,
+        r#"base64_decode(base64_encode([255]))"#,
+    ] {
+        assert!(run_source(source).is_err(), "{source} should fail");
+    }
+}
+
+//==============================================
+// End of file
+//==============================================
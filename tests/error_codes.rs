@@ -1,3 +1,4 @@
+use solvrascript::diagnostics;
 use solvrascript::interpreter::{RuntimeError, ScriptError};
 use solvrascript::modules::ModuleError;
 use solvrascript::parser::Parser;
@@ -29,3 +30,39 @@ fn runtime_type_error_maps_to_e003() {
     assert_eq!(script_err.code_str(), "E003");
     assert_eq!(err.code(), "E003");
 }
+
+#[test]
+fn unexpected_token_maps_to_e0001() {
+    let diagnostics = diagnostics::check_source("let value = (1 + 2;");
+    assert_eq!(diagnostics[0].code, "E0001");
+}
+
+#[test]
+fn unterminated_string_maps_to_e0002() {
+    let diagnostics = diagnostics::check_source("let s = \"open");
+    assert_eq!(diagnostics[0].code, "E0002");
+}
+
+#[test]
+fn parse_error_message_includes_code() {
+    let mut tokenizer = Tokenizer::new("fn demo(");
+    let tokens = tokenizer.tokenize().expect("tokenize");
+    let err = Parser::new(tokens).parse().expect_err("should fail");
+    assert_eq!(err.code(), "E0001");
+    assert!(err.to_string().starts_with("[E0001] "));
+}
+
+#[test]
+fn unresolved_identifier_maps_to_e0100() {
+    let diagnostics = diagnostics::check_source("fn main() { return missing; }");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "E0100");
+}
+
+#[test]
+fn explain_describes_known_codes() {
+    let text = diagnostics::explain("E0002").expect("explanation");
+    assert!(text.contains("string"));
+    assert!(diagnostics::explain("E9999").is_none());
+    assert!(diagnostics::codes::all().all(|code| diagnostics::explain(code).is_some()));
+}
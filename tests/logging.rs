@@ -0,0 +1,154 @@
+//==============================================
+// File: tests/logging.rs
+// Author: Codex
+// License: Duality Public License (DPL v1.0)
+// Goal: Exercise the log_* builtins
+// Objective: Confirm script logs become tracing events and honor output capture
+//==============================================
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use solvrascript::{
+    interpreter::{Interpreter, SCRIPT_LOG_TARGET, Value},
+    parser::Parser,
+    tokenizer::Tokenizer,
+};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Captured {
+    level: Level,
+    target: String,
+    function: String,
+    message: String,
+}
+
+#[derive(Clone, Default)]
+struct CapturingSubscriber {
+    events: Arc<Mutex<Vec<Captured>>>,
+}
+
+#[derive(Default)]
+struct FieldVisitor {
+    function: String,
+    message: String,
+}
+
+impl Visit for FieldVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "function" => self.function = format!("{value:?}"),
+            "message" => self.message = format!("{value:?}"),
+            _ => {}
+        }
+    }
+}
+
+impl Subscriber for CapturingSubscriber {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        self.events.lock().unwrap().push(Captured {
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_string(),
+            function: visitor.function,
+            message: visitor.message,
+        });
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+fn run(interpreter: &mut Interpreter, source: &str) -> Value {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize source");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    interpreter
+        .eval_program(&program)
+        .expect("execution")
+        .unwrap_or(Value::Null)
+}
+
+#[test]
+fn log_error_emits_an_error_level_event() {
+    let subscriber = CapturingSubscriber::default();
+    let events = subscriber.events.clone();
+    tracing::subscriber::with_default(subscriber, || {
+        let mut interpreter = Interpreter::new();
+        run(&mut interpreter, r#"log_error("x");"#);
+    });
+    let events = events.lock().unwrap();
+    assert_eq!(
+        *events,
+        vec![Captured {
+            level: Level::ERROR,
+            target: SCRIPT_LOG_TARGET.to_string(),
+            function: "<main>".to_string(),
+            message: "x".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn log_events_name_the_calling_script_function() {
+    let subscriber = CapturingSubscriber::default();
+    let events = subscriber.events.clone();
+    tracing::subscriber::with_default(subscriber, || {
+        let mut interpreter = Interpreter::new();
+        run(
+            &mut interpreter,
+            r#"
+                fn check_disk() {
+                    log_warn("low disk");
+                }
+                check_disk();
+            "#,
+        );
+    });
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].function, "check_disk");
+    assert_eq!(events[0].message, "low disk");
+}
+
+#[test]
+fn captured_output_buffers_log_lines_instead() {
+    let subscriber = CapturingSubscriber::default();
+    let events = subscriber.events.clone();
+    let output = tracing::subscriber::with_default(subscriber, || {
+        let mut interpreter = Interpreter::new();
+        interpreter.capture_output();
+        run(
+            &mut interpreter,
+            r#"
+                log_info("starting");
+                log_warn("low disk");
+            "#,
+        );
+        interpreter.take_output()
+    });
+    assert!(events.lock().unwrap().is_empty());
+    assert_eq!(output, "[info] starting\n[warn] low disk\n");
+}
+
+//==============================================
+// End of file
+//==============================================
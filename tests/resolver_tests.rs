@@ -1,5 +1,5 @@
 use solvrascript::{
-    ast::{self, Expr, Stmt},
+    ast::{self, Expr, Stmt, Type},
     parser::Parser,
     resolver::{self, Diagnostics},
     tokenizer::Tokenizer,
@@ -95,3 +95,145 @@ fn resolver_tracks_for_loop_variable() {
     };
     assert!(resolutions.map.contains_key(&use_id));
 }
+
+#[test]
+fn loop_variable_declaration_span_covers_the_identifier() {
+    let program = parse_program("for item in [1, 2] { item; }");
+    let mut diagnostics = Diagnostics::new();
+    let resolutions = resolver::resolve_module(&program, &mut diagnostics);
+
+    let main_body = match &program.statements[0] {
+        Stmt::FunctionDecl { decl } => &decl.body,
+        other => panic!("expected implicit main function, found {other:?}"),
+    };
+    let loop_id = match &main_body[0] {
+        Stmt::For { node_id, .. } => *node_id,
+        other => panic!("expected for loop, found {other:?}"),
+    };
+    let info = resolutions
+        .symbols
+        .declaration(loop_id)
+        .expect("loop variable declared");
+    assert_eq!(info.declaration_span.start.column, 5);
+    assert_eq!(info.declaration_span.end.column, 9);
+}
+
+#[test]
+fn lambda_parameter_declaration_span_covers_the_identifier() {
+    let program = parse_program("fn f() { let g = lambda |a, bb| -> a + bb; return g; }");
+    let mut diagnostics = Diagnostics::new();
+    let resolutions = resolver::resolve_module(&program, &mut diagnostics);
+
+    let body = match &program.statements[0] {
+        Stmt::FunctionDecl { decl } => &decl.body,
+        other => panic!("expected function declaration, found {other:?}"),
+    };
+    let use_id = match &body[0] {
+        Stmt::VariableDecl { decl } => match &decl.initializer {
+            Some(Expr::Lambda { body, .. }) => match body.as_ref() {
+                Expr::Binary { right, .. } => match right.as_ref() {
+                    Expr::Identifier { node_id, .. } => *node_id,
+                    other => panic!("expected identifier operand, found {other:?}"),
+                },
+                other => panic!("expected binary lambda body, found {other:?}"),
+            },
+            other => panic!("expected lambda initializer, found {other:?}"),
+        },
+        other => panic!("expected variable declaration, found {other:?}"),
+    };
+    let def_id = resolutions.map[&use_id];
+    let info = resolutions
+        .symbols
+        .declaration(def_id)
+        .expect("lambda parameter declared");
+    assert_eq!(info.declaration_span.start.column, 29);
+    assert_eq!(info.declaration_span.end.column, 31);
+}
+
+#[test]
+fn resolver_flags_literal_annotation_mismatch() {
+    let program = parse_program("let x: int = \"str\";");
+    let mut diagnostics = Diagnostics::new();
+    resolver::resolve_module(&program, &mut diagnostics);
+    assert!(diagnostics.has_errors());
+
+    let mismatches = diagnostics.type_mismatches();
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].expected, Type::Int);
+    assert_eq!(mismatches[0].found, Type::String);
+    assert_eq!(mismatches[0].span.start.column, 14);
+}
+
+#[test]
+fn resolver_flags_string_arithmetic() {
+    let program = parse_program("let y = \"a\" - 1;");
+    let mut diagnostics = Diagnostics::new();
+    resolver::resolve_module(&program, &mut diagnostics);
+
+    let mismatches = diagnostics.type_mismatches();
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].expected, Type::Int);
+    assert_eq!(mismatches[0].found, Type::String);
+}
+
+#[test]
+fn resolver_accepts_well_typed_declarations() {
+    let program = parse_program(
+        "let count: int = 3; let ratio: float = 2; let name: string = \"solvra\"; \
+         let ok: bool = !false; let total = count - 1; let label = name + 1;",
+    );
+    let mut diagnostics = Diagnostics::new();
+    resolver::resolve_module(&program, &mut diagnostics);
+    assert!(diagnostics.type_mismatches().is_empty());
+    assert!(!diagnostics.has_errors());
+}
+
+fn return_identifier_id(body: &[Stmt]) -> ast::NodeId {
+    match body.last() {
+        Some(Stmt::Return {
+            value: Some(Expr::Identifier { node_id, .. }),
+            ..
+        }) => *node_id,
+        other => panic!("expected `return <identifier>`, found {other:?}"),
+    }
+}
+
+#[test]
+fn resolver_assigns_slots_to_function_locals() {
+    let program = parse_program(
+        "fn sum(n) { let total = 0; let i = 0; \
+         while i < n { total = total + i; i = i + 1; } return total; }",
+    );
+    let mut diagnostics = Diagnostics::new();
+    let resolutions = resolver::resolve_module(&program, &mut diagnostics);
+
+    let body = match &program.statements[0] {
+        Stmt::FunctionDecl { decl } => &decl.body,
+        other => panic!("expected function declaration, found {other:?}"),
+    };
+    let total_decl = match &body[0] {
+        Stmt::VariableDecl { decl } => decl.node_id,
+        other => panic!("expected variable declaration, found {other:?}"),
+    };
+    let slot = resolutions
+        .slots
+        .slot(total_decl)
+        .expect("total is slotted");
+    assert_eq!(
+        resolutions.slots.slot(return_identifier_id(body)),
+        Some(slot)
+    );
+}
+
+#[test]
+fn resolver_keeps_captured_locals_name_based() {
+    let program = parse_program("fn outer() { let x = 1; let f = lambda |y| -> x + y; return x; }");
+    let mut diagnostics = Diagnostics::new();
+    let resolutions = resolver::resolve_module(&program, &mut diagnostics);
+
+    let body = match &program.statements[0] {
+        Stmt::FunctionDecl { decl } => &decl.body,
+        other => panic!("expected function declaration, found {other:?}"),
+    };
+    assert_eq!(resolutions.slots.slot(return_identifier_id(body)), None);
+}
@@ -316,3 +316,85 @@ fn test_else_if_chain() {
         other => panic!("expected if statement, found {other:?}"),
     }
 }
+
+#[test]
+fn test_binary_expression_span_covers_operands() {
+    let program = tokenize_and_parse("let total = alpha + beta;").unwrap();
+    let decl = match &program_body(&program)[0] {
+        Stmt::VariableDecl { decl } => decl,
+        other => panic!("expected variable declaration, found {other:?}"),
+    };
+    let initializer = decl.initializer.as_ref().expect("expected initializer");
+    assert!(matches!(initializer, Expr::Binary { .. }));
+
+    // `alpha` starts at column 13 and `beta` ends just before column 25.
+    let span = initializer.span();
+    assert_eq!((span.start.line, span.start.column), (1, 13));
+    assert_eq!((span.end.line, span.end.column), (1, 25));
+    assert_eq!(initializer.position(), &span.start);
+
+    // The declaration statement runs from `let` through the `;`.
+    assert_eq!(decl.span.start.column, 1);
+    assert_eq!(decl.span.end.column, 26);
+}
+
+#[test]
+fn test_parse_recovering_reports_each_syntax_error() {
+    let source = "let first = 1;\nlet = 2;\nlet second = first + 1;\nfn broken( { return 3; }\nlet third = 4;\n";
+    let tokens = tokenizer::Tokenizer::new(source).tokenize().unwrap();
+    let (program, errors) = parser::Parser::new(tokens).parse_recovering();
+
+    assert_eq!(errors.len(), 2, "errors: {errors:?}");
+    let lines: Vec<usize> = errors.iter().map(|error| error.span().start.line).collect();
+    assert_eq!(lines, vec![2, 4]);
+
+    let program = program.expect("valid statements are kept");
+    let names: Vec<&str> = program_body(&program)
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Stmt::VariableDecl { decl } => Some(decl.name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(names, vec!["first", "second", "third"]);
+}
+
+#[test]
+fn test_parse_recovering_skips_the_rest_of_a_broken_function_body() {
+    let source = "fn broken() {\n    let = 1;\n    if true { return 2; }\n    return 3;\n}\nlet after = 4;\n";
+    let tokens = tokenizer::Tokenizer::new(source).tokenize().unwrap();
+    let (program, errors) = parser::Parser::new(tokens).parse_recovering();
+
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    let program = program.expect("the trailing statement is kept");
+    let body = program_body(&program);
+    assert_eq!(body.len(), 1, "body: {body:?}");
+    assert!(matches!(
+        &body[0],
+        Stmt::VariableDecl { decl } if decl.name.as_str() == "after"
+    ));
+}
+
+#[test]
+fn test_parse_recovering_stops_at_the_error_cap() {
+    let source = "let = 1;\n".repeat(30);
+    let tokens = tokenizer::Tokenizer::new(&source).tokenize().unwrap();
+    let mut parser = parser::Parser::new(tokens).with_max_errors(3);
+    let (_, errors) = parser.parse_recovering();
+    assert_eq!(errors.len(), 3);
+    assert_eq!(parser.omitted_errors(), 27);
+
+    let tokens = tokenizer::Tokenizer::new("let = 1;\n").tokenize().unwrap();
+    let mut parser = parser::Parser::new(tokens).with_max_errors(1);
+    let (_, errors) = parser.parse_recovering();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(parser.omitted_errors(), 0, "nothing was past the cap");
+}
+
+#[test]
+fn test_parse_recovering_without_valid_statements_yields_no_program() {
+    let tokens = tokenizer::Tokenizer::new("let = 1;").tokenize().unwrap();
+    let (program, errors) = parser::Parser::new(tokens).parse_recovering();
+    assert!(program.is_none());
+    assert_eq!(errors.len(), 1);
+}
@@ -0,0 +1,58 @@
+//==============================================
+// File: tests/svs_harness.rs
+// Author: ZobieLabs
+// License: Duality Public License (DPL v1.0)
+// Goal: Self-test the run_svs_test fixture harness
+// Objective: Check expected-output diffs and .actual file generation
+//==============================================
+
+use solvrascript::runtime::run_svs_test;
+use std::any::Any;
+use std::fs;
+use std::panic;
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .unwrap_or_default(),
+    }
+}
+
+#[test]
+fn mismatched_output_reports_unified_diff() {
+    let result = panic::catch_unwind(|| {
+        run_svs_test("stdx_tests/fixtures/harness_mismatch.svs");
+    });
+    let message = panic_message(result.expect_err("mismatched fixture should fail"));
+    assert!(message.contains("harness_mismatch.svs"), "{message}");
+    assert!(message.contains(" alpha\n"), "{message}");
+    assert!(message.contains("-BETA\n"), "{message}");
+    assert!(message.contains("+beta\n"), "{message}");
+    assert!(message.contains(" gamma\n"), "{message}");
+}
+
+#[test]
+fn missing_expected_output_writes_actual_file() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let fixture = dir.path().join("fresh.svs");
+    fs::write(
+        &fixture,
+        "fn run() {\n    println(\"hello\");\n    return true;\n}\n\nrun();\n",
+    )
+    .expect("write fixture");
+
+    let fixture_path = fixture.to_str().expect("utf-8 temp path").to_string();
+    let result = panic::catch_unwind(|| run_svs_test(&fixture_path));
+    let message = panic_message(result.expect_err("fixture without .expected should fail"));
+    assert!(message.contains("fresh.expected"), "{message}");
+
+    let actual = fs::read_to_string(dir.path().join("fresh.actual")).expect("read .actual");
+    assert_eq!(actual, "hello\n");
+}
+
+//==============================================
+// End of file
+//==============================================
@@ -0,0 +1,130 @@
+//==============================================
+// File: tests/test_runner.rs
+// Author: Codex
+// License: Duality Public License (DPL v1.0)
+// Goal: Self-test the `solvrascript test` runner
+// Objective: Check test discovery, isolation, coverage, and the pass/fail summary
+//==============================================
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::process::Command;
+
+use solvrascript::interpreter::CoverageReport;
+use solvrascript::runtime::{TestOutcome, TestRunOptions, run_test_dir, run_test_dir_with};
+
+fn fixture_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("stdx_tests/fixtures/test_runner")
+}
+
+fn globals_fixture_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("stdx_tests/fixtures/test_runner_globals")
+}
+
+fn coverage_fixture_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("stdx_tests/fixtures/test_runner_coverage")
+}
+
+#[test]
+fn runner_discovers_test_functions_and_counts_outcomes() {
+    let summary = run_test_dir(&fixture_dir()).expect("run fixture dir");
+    let names: Vec<&str> = summary
+        .results
+        .iter()
+        .map(|result| result.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["test_addition_passes", "test_addition_fails"]);
+    assert_eq!(summary.passed(), 1);
+    assert_eq!(summary.failed(), 1);
+    match &summary.results[1].outcome {
+        TestOutcome::Failed(message) => {
+            assert!(message.contains("deliberate failure: 4 != 5"), "{message}")
+        }
+        other => panic!("expected a failure, got {other:?}"),
+    }
+}
+
+#[test]
+fn parallel_tests_do_not_share_globals() {
+    for _ in 0..10 {
+        let options = TestRunOptions {
+            jobs: 2,
+            coverage: false,
+        };
+        let summary = run_test_dir_with(&globals_fixture_dir(), &options).expect("run fixture dir");
+        let names: Vec<&str> = summary
+            .results
+            .iter()
+            .map(|result| result.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "test_first_bump_sees_a_fresh_counter",
+                "test_second_bump_sees_a_fresh_counter"
+            ]
+        );
+        assert_eq!(summary.failed(), 0, "{:?}", summary.results);
+    }
+}
+
+#[test]
+fn merged_coverage_is_the_union_of_each_test() {
+    let options = TestRunOptions {
+        jobs: 2,
+        coverage: true,
+    };
+    let summary = run_test_dir_with(&coverage_fixture_dir(), &options).expect("run fixture dir");
+    assert_eq!(summary.failed(), 0, "{:?}", summary.results);
+    let file = coverage_fixture_dir().join("coverage_tests.svs");
+    let hit_lines = |report: &CoverageReport| -> BTreeSet<usize> {
+        report
+            .file(&file)
+            .expect("file coverage")
+            .hit_lines()
+            .collect()
+    };
+    let double = hit_lines(summary.results[0].coverage.as_ref().unwrap());
+    let negate = hit_lines(summary.results[1].coverage.as_ref().unwrap());
+    // `return x * 2;` is line 8 and `return 0 - x;` is line 12.
+    assert!(double.contains(&8) && !double.contains(&12), "{double:?}");
+    assert!(negate.contains(&12) && !negate.contains(&8), "{negate:?}");
+
+    let merged = summary.coverage().expect("merged coverage");
+    let union: BTreeSet<usize> = double.union(&negate).copied().collect();
+    assert_eq!(hit_lines(&merged), union);
+    let lcov = merged.to_lcov();
+    assert!(
+        lcov.contains("DA:8,1") && lcov.contains("DA:12,1"),
+        "{lcov}"
+    );
+}
+
+#[test]
+fn cli_test_prints_summary_and_fails_on_failures() {
+    let output = Command::new(env!("CARGO_BIN_EXE_solvrascript"))
+        .args(["test", fixture_dir().to_str().unwrap()])
+        .output()
+        .expect("run test subcommand");
+    assert!(
+        !output.status.success(),
+        "a failing test should fail the run"
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("ok   arithmetic_tests.svs::test_addition_passes"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("FAIL arithmetic_tests.svs::test_addition_fails"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("test result: 1 passed; 1 failed"),
+        "{stdout}"
+    );
+}
+
+//==============================================
+// End of file
+//==============================================
@@ -0,0 +1,87 @@
+//==============================================
+// File: tests/time.rs
+// Author: Codex
+// License: Duality Public License (DPL v1.0)
+// Goal: Exercise the time stdlib namespace
+// Objective: Read an injected clock, format fixed epochs, and round-trip parse
+//==============================================
+
+use solvrascript::{
+    interpreter::{Interpreter, RuntimeError, Value},
+    parser::Parser,
+    tokenizer::Tokenizer,
+};
+
+const FIXED_EPOCH_MS: i64 = 1_700_000_000_123;
+
+fn run_source(source: &str) -> Result<Value, RuntimeError> {
+    let mut tokenizer = Tokenizer::new(source);
+    let tokens = tokenizer.tokenize().expect("tokenize source");
+    let mut parser = Parser::new(tokens);
+    let program = parser.parse().expect("parse program");
+    let mut interpreter = Interpreter::new();
+    interpreter.set_clock(|| FIXED_EPOCH_MS);
+    interpreter
+        .eval_program(&program)
+        .map(|value| value.unwrap_or(Value::Null))
+}
+
+fn eval_source(source: &str) -> Value {
+    run_source(source).expect("execution")
+}
+
+#[test]
+fn now_reads_the_injected_clock() {
+    let source = r#"
+        import <time>;
+        time.now()
+    "#;
+    assert_eq!(eval_source(source), Value::Int(FIXED_EPOCH_MS));
+}
+
+#[test]
+fn format_renders_a_fixed_epoch_in_utc() {
+    let source = r#"
+        import <time>;
+        time.format(time.now(), "%Y-%m-%dT%H:%M:%S%.3fZ")
+    "#;
+    assert_eq!(
+        eval_source(source),
+        Value::String("2023-11-14T22:13:20.123Z".to_string())
+    );
+    assert_eq!(
+        eval_source(r#"time_format(0, "%Y-%m-%d %H:%M")"#),
+        Value::String("1970-01-01 00:00".to_string())
+    );
+}
+
+#[test]
+fn parse_round_trips_formatted_timestamps() {
+    let source = r#"
+        import <time>;
+        let pattern = "%Y-%m-%d %H:%M:%S%.3f";
+        let text = time.format(time.now(), pattern);
+        time.parse(text, pattern) == time.now()
+            && time.parse("1970-01-02", "%Y-%m-%d") == 86400000
+            && time.parse("1970-01-01 01:00:00 +0100", "%Y-%m-%d %H:%M:%S %z") == 0
+    "#;
+    assert_eq!(eval_source(source), Value::Bool(true));
+}
+
+#[test]
+fn invalid_patterns_and_input_raise_runtime_errors() {
+    for source in [
+        r#"time_format(0, "%Q")"#,
+        r#"time_format("0", "%Y")"#,
+        r#"time_parse("2023-13-01", "%Y-%m-%d")"#,
+        r#"time_parse("yesterday", "%Y-%m-%d")"#,
+        r#"time_parse("2023-11-14 22", "%Y-%m-%d %H")"#,
+        r#"time_parse("12:00", "%H:%M")"#,
+    ] {
+        assert!(run_source(source).is_err(), "{source} should fail");
+    }
+}
+
+//==============================================
+// End of file
+//==============================================